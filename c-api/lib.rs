@@ -293,6 +293,46 @@ pub extern "C" fn ttfp_get_name_record_string(
     }
 }
 
+/// @brief Returns a face's family name as a null-terminated UTF-8 string.
+///
+/// Prefers the *Typographic Family* name and falls back to the *Family* name.
+/// Only Unicode name records are used.
+///
+/// @param name A string buffer that will be filled with the family name.
+/// @param len The size of a string buffer, including the trailing '\0'.
+/// @return `false` when the name is not present or the buffer is too small.
+#[no_mangle]
+pub extern "C" fn ttfp_get_family_name(
+    face: *const ttfp_face,
+    name: *mut c_char,
+    len: usize,
+) -> bool {
+    let find = |name_id| {
+        face_from_ptr(face)
+            .names()
+            .into_iter()
+            .filter(|r| r.name_id == name_id)
+            .find_map(|r| r.to_string())
+    };
+
+    let family = match find(ttf_parser::name_id::TYPOGRAPHIC_FAMILY)
+        .or_else(|| find(ttf_parser::name_id::FAMILY))
+    {
+        Some(v) => v,
+        None => return false,
+    };
+
+    if family.len() >= len {
+        return false;
+    }
+
+    let name = unsafe { std::slice::from_raw_parts_mut(name as *mut u8, len) };
+    name[..family.len()].copy_from_slice(family.as_bytes());
+    name[family.len()] = 0;
+
+    true
+}
+
 /// @brief Checks that face is marked as *Regular*.
 ///
 /// @return `false` when OS/2 table is not present.
@@ -705,6 +745,24 @@ pub extern "C" fn ttfp_get_glyph_name(
     }
 }
 
+/// @brief Resolves a Glyph ID for a glyph name.
+///
+/// Uses the `post` and `CFF` tables as sources.
+///
+/// @param name A null-terminated UTF-8 string.
+/// @return Returns 0 when glyph is not present or parsing is failed.
+#[no_mangle]
+pub extern "C" fn ttfp_get_glyph_index_by_name(face: *const ttfp_face, name: *const c_char) -> u16 {
+    let name = unsafe { std::ffi::CStr::from_ptr(name) };
+    match name.to_str() {
+        Ok(name) => face_from_ptr(face)
+            .glyph_index_by_name(name)
+            .map(|gid| gid.0)
+            .unwrap_or(0),
+        Err(_) => 0,
+    }
+}
+
 /// @brief Outlines a glyph and returns its tight bounding box.
 ///
 /// **Warning**: since `ttf-parser` is a pull parser,
@@ -784,7 +842,7 @@ pub extern "C" fn ttfp_get_global_bounding_box(
 /// Note that this method will return an encoded image. It should be decoded
 /// by the caller. We don't validate or preprocess it in any way.
 ///
-/// Also, a font can contain both: images and outlines. So when this method returns `None`
+/// Also, a font can contain both: images and outlines. So when this method returns `false`
 /// you should also try `ttfp_outline_glyph()` afterwards.
 ///
 /// There are multiple ways an image can be stored in a TrueType font
@@ -936,11 +994,28 @@ pub extern "C" fn ttfp_set_variation(face: *mut ttfp_face, axis: Tag, value: f32
 
 /// @brief Returns the current normalized variation coordinates.
 ///
-/// Values represented as f2.16
+/// Values represented as f2.14.
+/// Coordinates are stored in the same order as variation axes.
+///
+/// @param coordinates A buffer that will be filled with coordinates. Can be NULL.
+/// @param len The size of a buffer. Extra coordinates will be ignored.
+/// @return The total number of coordinates, which is 0 for a non-variable face.
 #[cfg(feature = "variable-fonts")]
 #[no_mangle]
-pub extern "C" fn ttfp_get_variation_coordinates(face: *const ttfp_face) -> *const i16 {
-    face_from_ptr(face).variation_coordinates().as_ptr() as _
+pub extern "C" fn ttfp_get_variation_coordinates(
+    face: *const ttfp_face,
+    coordinates: *mut i16,
+    len: usize,
+) -> usize {
+    let coords = face_from_ptr(face).variation_coordinates();
+    if !coordinates.is_null() {
+        let coordinates = unsafe { std::slice::from_raw_parts_mut(coordinates, len) };
+        for (to, from) in coordinates.iter_mut().zip(coords) {
+            *to = from.get();
+        }
+    }
+
+    coords.len()
 }
 
 /// @brief Checks that face has non-default variation coordinates.
@@ -76,6 +76,9 @@ int main() {
 
     char family_name[30];
     assert(ttfp_get_name_record_string(face, 1, family_name, 30));
+    assert(ttfp_get_family_name(face, family_name, 30));
+    assert(strcmp(family_name, "Source Sans Pro") == 0);
+    assert(ttfp_get_family_name(face, family_name, 1) == false);
 
     assert(ttfp_get_units_per_em(face) == 1000);
     assert(ttfp_get_ascender(face) == 984);
@@ -88,6 +91,13 @@ int main() {
     assert(ttfp_is_oblique(face) == false);
     assert(ttfp_get_weight(face) == 400);
     assert(ttfp_get_width(face) == 5);
+
+#if defined(TTFP_VARIABLE_FONTS)
+    int16_t coordinates[4];
+    assert(ttfp_get_variation_coordinates(face, coordinates, 4) == 0);
+    assert(ttfp_get_variation_coordinates(face, NULL, 0) == 0);
+    assert(ttfp_has_non_default_variation_coordinates(face) == false);
+#endif
     assert(ttfp_get_x_height(face) == 486);
     assert(ttfp_get_number_of_glyphs(face) == 1974);
 
@@ -146,8 +156,50 @@ int main() {
     char glyph_name[256];
     assert(ttfp_get_glyph_name(face, a_gid, glyph_name));
     assert(strcmp(glyph_name, "A") == 0);
+    assert(ttfp_get_glyph_index_by_name(face, "A") == a_gid);
+    assert(ttfp_get_glyph_index_by_name(face, "missing") == 0);
+
+    ttfp_glyph_raster_image raster_image;
+    assert(ttfp_get_glyph_raster_image(face, a_gid, 32, &raster_image) == false);
+
+    const char *svg_data = NULL;
+    uint32_t svg_len = 0;
+    assert(ttfp_get_glyph_svg_image(face, a_gid, &svg_data, &svg_len) == false);
+
+    free(font_data);
+
+#if defined(TTFP_VARIABLE_FONTS)
+    file = fopen("../benches/fonts/SourceSansVariable-Roman.ttf", "rb");
+    if (file == NULL) {
+        return -1;
+    }
+
+    fseek(file, 0, SEEK_END);
+    fsize = ftell(file);
+    fseek(file, 0, SEEK_SET);
+
+    font_data = (char*)malloc(fsize + 1);
+    fread(font_data, 1, fsize, file);
+    fclose(file);
+
+    ttfp_face *var_face = (ttfp_face*)alloca(ttfp_face_size_of());
+    assert(ttfp_face_init(font_data, fsize, 0, var_face));
+    assert(ttfp_get_variation_axes_count(var_face) == 1);
+    assert(ttfp_set_variation(var_face, TTFP_TAG('w','g','h','t'), 900));
+    assert(ttfp_has_non_default_variation_coordinates(var_face) == true);
+
+    assert(ttfp_get_variation_coordinates(var_face, NULL, 0) == 1);
+    // A buffer smaller than the axes count must not be written past its end.
+    coordinates[0] = 0;
+    assert(ttfp_get_variation_coordinates(var_face, coordinates, 0) == 1);
+    assert(coordinates[0] == 0);
+    coordinates[1] = 0;
+    assert(ttfp_get_variation_coordinates(var_face, coordinates, 4) == 1);
+    assert(coordinates[0] == 16384);
+    assert(coordinates[1] == 0);
 
     free(font_data);
+#endif
 
     return 0;
 }
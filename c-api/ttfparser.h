@@ -289,6 +289,18 @@ bool ttfp_get_name_record(const ttfp_face *face, uint16_t index, ttfp_name_recor
  */
 bool ttfp_get_name_record_string(const ttfp_face *face, uint16_t index, char *name, uintptr_t len);
 
+/**
+ * @brief Returns a face's family name as a null-terminated UTF-8 string.
+ *
+ * Prefers the *Typographic Family* name and falls back to the *Family* name.
+ * Only Unicode name records are used.
+ *
+ * @param name A string buffer that will be filled with the family name.
+ * @param len The size of a string buffer, including the trailing '\0'.
+ * @return `false` when the name is not present or the buffer is too small.
+ */
+bool ttfp_get_family_name(const ttfp_face *face, char *name, uintptr_t len);
+
 /**
  * @brief Checks that face is marked as *Regular*.
  *
@@ -591,6 +603,16 @@ int16_t ttfp_get_glyph_y_origin(const ttfp_face *face, uint16_t glyph_id);
  */
 bool ttfp_get_glyph_name(const ttfp_face *face, uint16_t glyph_id, char *name);
 
+/**
+ * @brief Resolves a Glyph ID for a glyph name.
+ *
+ * Uses the `post` and `CFF` tables as sources.
+ *
+ * @param name A null-terminated UTF-8 string.
+ * @return Returns 0 when glyph is not present or parsing is failed.
+ */
+uint16_t ttfp_get_glyph_index_by_name(const ttfp_face *face, const char *name);
+
 /**
  * @brief Outlines a glyph and returns its tight bounding box.
  *
@@ -641,7 +663,7 @@ ttfp_rect ttfp_get_global_bounding_box(const ttfp_face *face);
  * Note that this method will return an encoded image. It should be decoded
  * by the caller. We don't validate or preprocess it in any way.
  *
- * Also, a font can contain both: images and outlines. So when this method returns `None`
+ * Also, a font can contain both: images and outlines. So when this method returns `false`
  * you should also try `ttfp_outline_glyph()` afterwards.
  *
  * There are multiple ways an image can be stored in a TrueType font
@@ -714,9 +736,14 @@ bool ttfp_set_variation(ttfp_face *face, ttfp_tag axis, float value);
 /**
  * @brief Returns the current normalized variation coordinates.
  *
- * Values represented as f2.16
+ * Values represented as f2.14.
+ * Coordinates are stored in the same order as variation axes.
+ *
+ * @param coordinates A buffer that will be filled with coordinates. Can be NULL.
+ * @param len The size of a buffer. Extra coordinates will be ignored.
+ * @return The total number of coordinates, which is 0 for a non-variable face.
  */
-const int16_t *ttfp_get_variation_coordinates(const ttfp_face *face);
+uintptr_t ttfp_get_variation_coordinates(const ttfp_face *face, int16_t *coordinates, uintptr_t len);
 #endif
 
 #if defined(TTFP_VARIABLE_FONTS)
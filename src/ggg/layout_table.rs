@@ -7,6 +7,8 @@ use super::LookupList;
 #[cfg(feature = "variable-fonts")]
 use crate::parser::Offset32;
 use crate::parser::{FromData, LazyArray16, Offset, Offset16, Stream};
+#[cfg(feature = "variable-fonts")]
+use crate::NormalizedCoordinate;
 use crate::Tag;
 
 /// A [Layout Table](https://docs.microsoft.com/en-us/typography/opentype/spec/chapter2#table-organization).
@@ -17,6 +19,9 @@ pub struct LayoutTable<'a> {
     /// A list of all supported features.
     pub features: FeatureList<'a>,
     /// A list of all lookups.
+    ///
+    /// Each [`Lookup`](super::Lookup) exposes its flags and subtables.
+    /// The lookup type is available via [`LookupSubtables::kind`](super::LookupSubtables::kind).
     pub lookups: LookupList<'a>,
     /// Used to substitute an alternate set of lookup tables
     /// to use for any given feature under specified conditions.
@@ -71,6 +76,32 @@ impl<'a> LayoutTable<'a> {
     }
 }
 
+#[cfg(feature = "variable-fonts")]
+impl<'a> LayoutTable<'a> {
+    /// Returns feature's lookup indices with [`FeatureVariations`] applied.
+    ///
+    /// When the first condition set matching `coords` has a substitute for this feature,
+    /// its lookups are returned instead of the default ones.
+    pub fn feature_lookups(
+        &self,
+        feature_index: FeatureIndex,
+        coords: &[NormalizedCoordinate],
+    ) -> Option<LazyArray16<'a, LookupIndex>> {
+        let substitute = self.variations.and_then(|variations| {
+            let index = variations.find_index(coords)?;
+            variations.find_substitute(feature_index, index)
+        });
+
+        match substitute {
+            Some(feature) => Some(feature.lookup_indices),
+            None => self
+                .features
+                .get(feature_index)
+                .map(|feature| feature.lookup_indices),
+        }
+    }
+}
+
 /// An index in [`ScriptList`].
 pub type ScriptIndex = u16;
 /// An index in [`LanguageSystemList`].
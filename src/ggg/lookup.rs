@@ -64,6 +64,18 @@ impl core::fmt::Debug for LookupSubtables<'_> {
 }
 
 impl<'a> LookupSubtables<'a> {
+    /// Returns the lookup type.
+    ///
+    /// This is the type stored in the lookup table, which can be an extension type.
+    /// Parsed subtables have extensions resolved,
+    /// so use [`SubstitutionSubtable::kind`](crate::gsub::SubstitutionSubtable::kind)
+    /// or [`PositioningSubtable::kind`](crate::gpos::PositioningSubtable::kind)
+    /// to get the actual type.
+    #[inline]
+    pub fn kind(&self) -> u16 {
+        self.kind
+    }
+
     /// Returns a number of items in the LookupSubtables.
     #[inline]
     pub fn len(&self) -> u16 {
@@ -77,6 +89,8 @@ impl<'a> LookupSubtables<'a> {
 
     /// Parses a subtable at index.
     ///
+    /// Extension subtables are resolved to the subtables they wrap.
+    ///
     /// Accepts either
     /// [`PositioningSubtable`](crate::gpos::PositioningSubtable)
     /// or [`SubstitutionSubtable`](crate::gsub::SubstitutionSubtable).
@@ -123,19 +137,28 @@ impl<'a, T: LookupSubtable<'a>> Iterator for LookupSubtablesIter<'a, T> {
 }
 
 /// Lookup table flags.
-#[allow(missing_docs)]
+///
+/// Together with GDEF glyph classes, these flags define which glyphs a lookup must skip.
 #[derive(Clone, Copy, Debug)]
 pub struct LookupFlags(pub u16);
 
 #[rustfmt::skip]
-#[allow(missing_docs)]
 impl LookupFlags {
+    /// Checks that the cursive attachment is right-to-left.
     #[inline] pub fn right_to_left(self) -> bool { self.0 & 0x0001 != 0 }
+    /// Checks that base glyphs must be skipped.
     #[inline] pub fn ignore_base_glyphs(self) -> bool { self.0 & 0x0002 != 0 }
+    /// Checks that ligatures must be skipped.
     #[inline] pub fn ignore_ligatures(self) -> bool { self.0 & 0x0004 != 0 }
+    /// Checks that all marks must be skipped.
     #[inline] pub fn ignore_marks(self) -> bool { self.0 & 0x0008 != 0 }
+    /// Checks that any of the `ignore_*` flags is set.
     #[inline] pub fn ignore_flags(self) -> bool { self.0 & 0x000E != 0 }
+    /// Checks that [`Lookup::mark_filtering_set`] is present.
     #[inline] pub fn use_mark_filtering_set(self) -> bool { self.0 & 0x0010 != 0 }
+    /// Returns a GDEF mark attachment class.
+    ///
+    /// When non-zero, marks of other attachment classes must be skipped.
     #[inline] pub fn mark_attachment_type(self) -> u8 { ((self.0 & 0xFF00) >> 8) as u8 }
 }
 
@@ -150,6 +173,7 @@ impl FromData for LookupFlags {
 
 pub(crate) fn parse_extension_lookup<'a, T: 'a>(
     data: &'a [u8],
+    extension_kind: u16,
     parse: impl FnOnce(&'a [u8], u16) -> Option<T>,
 ) -> Option<T> {
     let mut s = Stream::new(data);
@@ -157,6 +181,12 @@ pub(crate) fn parse_extension_lookup<'a, T: 'a>(
     match format {
         1 => {
             let kind = s.read::<u16>()?;
+            // An extension cannot reference another extension.
+            // Otherwise a malformed font can cause an infinite recursion.
+            if kind == extension_kind {
+                return None;
+            }
+
             let offset = s.read::<Offset32>()?.to_usize();
             parse(data.get(offset..)?, kind)
         }
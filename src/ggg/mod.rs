@@ -114,6 +114,57 @@ impl<'a> Coverage<'a> {
             }
         }
     }
+
+    /// Returns an iterator over all covered glyphs.
+    ///
+    /// Glyphs are returned in coverage index order.
+    pub fn glyphs(&self) -> CoverageGlyphs<'a> {
+        CoverageGlyphs {
+            coverage: *self,
+            index: 0,
+            next_glyph: None,
+        }
+    }
+}
+
+/// An iterator over [`Coverage`] glyphs.
+#[derive(Clone, Copy, Debug)]
+pub struct CoverageGlyphs<'a> {
+    coverage: Coverage<'a>,
+    // An index of a glyph or a range record.
+    index: u16,
+    // The next glyph inside the current range record.
+    next_glyph: Option<GlyphId>,
+}
+
+impl Iterator for CoverageGlyphs<'_> {
+    type Item = GlyphId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.coverage {
+            Coverage::Format1 { glyphs } => {
+                let glyph = glyphs.get(self.index)?;
+                self.index = self.index.checked_add(1)?;
+                Some(glyph)
+            }
+            Coverage::Format2 { records } => loop {
+                let record = records.get(self.index)?;
+                let glyph = self.next_glyph.unwrap_or(record.start);
+                if glyph <= record.end {
+                    self.next_glyph = glyph.0.checked_add(1).map(GlyphId);
+                    if self.next_glyph.is_none() {
+                        // The last possible glyph. Move to the next record.
+                        self.index = self.index.checked_add(1)?;
+                    }
+
+                    return Some(glyph);
+                }
+
+                self.index = self.index.checked_add(1)?;
+                self.next_glyph = None;
+            },
+        }
+    }
 }
 
 /// A value of [Class Definition Table](
@@ -167,4 +218,59 @@ impl<'a> ClassDefinition<'a> {
         }
         .unwrap_or(0)
     }
+
+    /// Returns an iterator over all glyphs with a non-zero class.
+    ///
+    /// Walks the table ranges directly, without probing each glyph ID.
+    pub fn classes(&self) -> ClassDefinitionIter<'a> {
+        ClassDefinitionIter {
+            class_def: *self,
+            index: 0,
+            next_glyph: None,
+        }
+    }
+}
+
+/// An iterator over [`ClassDefinition`] glyphs and their classes.
+#[derive(Clone, Copy, Debug)]
+pub struct ClassDefinitionIter<'a> {
+    class_def: ClassDefinition<'a>,
+    // An index of a class or a range record.
+    index: u16,
+    // The next glyph inside the current range record.
+    next_glyph: Option<GlyphId>,
+}
+
+impl Iterator for ClassDefinitionIter<'_> {
+    type Item = (GlyphId, Class);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.class_def {
+            ClassDefinition::Format1 { start, classes } => loop {
+                let class = classes.get(self.index)?;
+                let glyph = start.0.checked_add(self.index)?;
+                self.index = self.index.checked_add(1)?;
+                if class != 0 {
+                    return Some((GlyphId(glyph), class));
+                }
+            },
+            ClassDefinition::Format2 { records } => loop {
+                let record = records.get(self.index)?;
+                let glyph = self.next_glyph.unwrap_or(record.start);
+                if record.value != 0 && glyph <= record.end {
+                    self.next_glyph = glyph.0.checked_add(1).map(GlyphId);
+                    if self.next_glyph.is_none() {
+                        // The last possible glyph. Move to the next record.
+                        self.index = self.index.checked_add(1)?;
+                    }
+
+                    return Some((glyph, record.value));
+                }
+
+                self.index = self.index.checked_add(1)?;
+                self.next_glyph = None;
+            },
+            ClassDefinition::Empty => None,
+        }
+    }
 }
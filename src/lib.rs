@@ -57,38 +57,59 @@ use core_maths::CoreFloat;
 
 #[cfg(feature = "apple-layout")]
 mod aat;
+#[cfg(feature = "glyph-names")]
+mod agl;
 #[cfg(feature = "variable-fonts")]
 mod delta_set;
 #[cfg(feature = "opentype-layout")]
 mod ggg;
 mod language;
+mod outline;
 mod parser;
+#[cfg(any(feature = "woff", feature = "woff2"))]
+mod sfnt;
 mod tables;
+pub mod tags;
 #[cfg(feature = "variable-fonts")]
 mod var_store;
+#[cfg(feature = "woff")]
+pub mod woff;
+#[cfg(feature = "woff2")]
+pub mod woff2;
 
 use head::IndexToLocationFormat;
-pub use parser::{Fixed, FromData, LazyArray16, LazyArray32, LazyArrayIter16, LazyArrayIter32};
-use parser::{NumFrom, Offset, Offset32, Stream, TryNumFrom};
+pub use parser::{
+    Fixed, FromData, LazyArray16, LazyArray32, LazyArrayIter16, LazyArrayIter32, Stream, F2DOT14,
+};
+use parser::{NumFrom, Offset, Offset32, TryNumFrom};
 
 #[cfg(feature = "variable-fonts")]
 pub use fvar::VariationAxis;
 
 pub use language::Language;
 pub use name::{name_id, PlatformId};
-pub use os2::{Permissions, ScriptMetrics, Style, UnicodeRanges, Weight, Width};
+pub use os2::{ParseClassError, Permissions, ScriptMetrics, Style, UnicodeRanges, Weight, Width};
+#[cfg(feature = "std")]
+pub use outline::PathSegment;
+#[cfg(feature = "std")]
+use outline::PathSegmentsBuilder;
+pub use outline::{
+    ContourBuilder, FlatteningBuilder, GlyphPointCounts, OutlineLimits, PathStats,
+    QuadToCubicBuilder,
+};
+use outline::{DummyOutline, IndexedBuilder, LimitedBuilder, PreciseBBoxBuilder};
 pub use tables::CFFError;
 #[cfg(feature = "apple-layout")]
 pub use tables::{ankr, feat, kerx, morx, trak};
 #[cfg(feature = "variable-fonts")]
 pub use tables::{avar, cff2, fvar, gvar, hvar, mvar, vvar};
+#[cfg(feature = "opentype-layout")]
+pub use tables::{base, gdef, gpos, gsub, math};
 pub use tables::{cbdt, cblc, cff1 as cff, vhea};
 pub use tables::{
-    cmap, colr, cpal, glyf, head, hhea, hmtx, kern, loca, maxp, name, os2, post, sbix, stat, svg,
-    vorg,
+    cmap, colr, cpal, dsig, gasp, glyf, head, hhea, hmtx, kern, loca, maxp, meta, name, os2, post,
+    sbix, stat, svg, vorg,
 };
-#[cfg(feature = "opentype-layout")]
-pub use tables::{gdef, gpos, gsub, math};
 
 #[cfg(feature = "opentype-layout")]
 pub mod opentype_layout {
@@ -203,6 +224,7 @@ pub struct Variation {
 /// A 4-byte tag.
 #[repr(transparent)]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tag(pub u32);
 
 impl Tag {
@@ -300,6 +322,26 @@ impl core::fmt::Display for Tag {
     }
 }
 
+/// Creates a `Tag` from a string.
+///
+/// # Example
+///
+/// ```rust
+/// use ttf_parser::Tag;
+///
+/// assert_eq!("wght".parse(), Ok(Tag::from_bytes(b"wght")));
+/// assert_eq!("SVG".parse(), Ok(Tag::from_bytes(b"SVG ")));
+/// ```
+impl core::str::FromStr for Tag {
+    type Err = core::convert::Infallible;
+
+    /// Behaves like [`Tag::from_bytes_lossy`], so never fails.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Tag::from_bytes_lossy(s.as_bytes()))
+    }
+}
+
 impl FromData for Tag {
     const SIZE: usize = 4;
 
@@ -314,6 +356,7 @@ impl FromData for Tag {
 /// Used for underline and strikeout.
 #[repr(C)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LineMetrics {
     /// Line position.
     pub position: i16,
@@ -322,12 +365,35 @@ pub struct LineMetrics {
     pub thickness: i16,
 }
 
+/// Glyph metrics.
+///
+/// See [`Face::glyph_metrics`] for details.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlyphMetrics {
+    /// Horizontal advance.
+    pub hor_advance: Option<u16>,
+
+    /// Horizontal side bearing.
+    pub hor_side_bearing: Option<i16>,
+
+    /// Vertical advance.
+    pub ver_advance: Option<u16>,
+
+    /// Vertical side bearing.
+    pub ver_side_bearing: Option<i16>,
+
+    /// Outline bounding box.
+    pub bounding_box: Option<Rect>,
+}
+
 /// A rectangle.
 ///
 /// Doesn't guarantee that `x_min` <= `x_max` and/or `y_min` <= `y_max`.
 #[repr(C)]
 #[allow(missing_docs)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rect {
     pub x_min: i16,
     pub y_min: i16,
@@ -523,7 +589,7 @@ impl core::fmt::Debug for Transform {
 }
 
 /// A float point.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub struct PointF {
     /// The X-axis coordinate.
     pub x: f32,
@@ -595,19 +661,14 @@ pub trait OutlineBuilder {
     fn close(&mut self);
 }
 
-struct DummyOutline;
-impl OutlineBuilder for DummyOutline {
-    fn move_to(&mut self, _: f32, _: f32) {}
-    fn line_to(&mut self, _: f32, _: f32) {}
-    fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
-    fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
-    fn close(&mut self) {}
-}
-
 /// A glyph raster image format.
 #[allow(missing_docs)]
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum RasterImageFormat {
+    /// A PNG image.
+    ///
+    /// Must be decoded by the caller. Width and height are stored in the PNG itself,
+    /// while [`RasterGlyphImage`] dimensions may be unset.
     PNG,
 
     /// A monochrome bitmap.
@@ -667,10 +728,41 @@ pub enum RasterImageFormat {
     BitmapPremulBgra32,
 }
 
+impl RasterImageFormat {
+    /// Returns the number of bits per pixel.
+    ///
+    /// Returns `None` for compressed formats, i.e. PNG.
+    #[inline]
+    pub fn bits_per_pixel(self) -> Option<u8> {
+        match self {
+            RasterImageFormat::PNG => None,
+            RasterImageFormat::BitmapMono | RasterImageFormat::BitmapMonoPacked => Some(1),
+            RasterImageFormat::BitmapGray2 | RasterImageFormat::BitmapGray2Packed => Some(2),
+            RasterImageFormat::BitmapGray4 | RasterImageFormat::BitmapGray4Packed => Some(4),
+            RasterImageFormat::BitmapGray8 => Some(8),
+            RasterImageFormat::BitmapPremulBgra32 => Some(32),
+        }
+    }
+
+    /// Checks that rows are padded to a byte boundary.
+    ///
+    /// Returns `false` for PNG and tightly packed bitmaps.
+    #[inline]
+    pub fn is_byte_aligned(self) -> bool {
+        !matches!(
+            self,
+            RasterImageFormat::PNG
+                | RasterImageFormat::BitmapMonoPacked
+                | RasterImageFormat::BitmapGray2Packed
+                | RasterImageFormat::BitmapGray4Packed
+        )
+    }
+}
+
 /// A glyph's raster image.
 ///
 /// Note, that glyph metrics are in pixels and not in font units.
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct RasterGlyphImage<'a> {
     /// Horizontal offset.
     pub x: i16,
@@ -696,6 +788,81 @@ pub struct RasterGlyphImage<'a> {
 
     /// A raw image data. It's up to the caller to decode it.
     pub data: &'a [u8],
+
+    /// A `data` offset from the start of the table the image was parsed from.
+    ///
+    /// Use [`Face::glyph_raster_image_data_range`] to get a position in the font data.
+    pub data_offset: u32,
+}
+
+impl RasterGlyphImage<'_> {
+    /// Returns a `data` range from the start of the table the image was parsed from.
+    #[inline]
+    pub fn data_range(&self) -> core::ops::Range<usize> {
+        let start = usize::num_from(self.data_offset);
+        start..start + self.data.len()
+    }
+
+    /// Checks that the image has no pixels or no data.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0 || self.data.is_empty()
+    }
+
+    /// Returns the number of bytes per row.
+    ///
+    /// Returns `None` for PNG and tightly packed bitmaps, since their rows
+    /// do not start at a byte boundary.
+    #[inline]
+    pub fn stride(&self) -> Option<usize> {
+        if !self.format.is_byte_aligned() {
+            return None;
+        }
+
+        let bits = usize::from(self.width) * usize::from(self.format.bits_per_pixel()?);
+        Some(bits_to_bytes(bits))
+    }
+
+    /// Returns the expected `data` length in bytes, based on `width`, `height` and `format`.
+    ///
+    /// Returns `None` for PNG, since it's compressed, or when the length overflows `usize`.
+    ///
+    /// Since `width` and `height` are not guaranteed to match `data`,
+    /// the caller should check that `data` is at least this long before decoding.
+    pub fn expected_data_len(&self) -> Option<usize> {
+        let height = usize::from(self.height);
+        match self.stride() {
+            Some(stride) => stride.checked_mul(height),
+            None => {
+                let bits_per_pixel = usize::from(self.format.bits_per_pixel()?);
+                let bits = usize::from(self.width)
+                    .checked_mul(height)?
+                    .checked_mul(bits_per_pixel)?;
+                Some(bits_to_bytes(bits))
+            }
+        }
+    }
+}
+
+#[inline]
+fn bits_to_bytes(bits: usize) -> usize {
+    (bits >> 3) + usize::from(bits & 7 != 0)
+}
+
+/// Returns a table that must be present to parse the specified one.
+#[cfg(feature = "std")]
+fn table_dependency(tag: Tag) -> Option<Tag> {
+    let dependency = match &tag.to_bytes() {
+        b"bdat" => b"bloc",
+        b"CBDT" => b"CBLC",
+        b"COLR" => b"CPAL",
+        b"EBDT" => b"EBLC",
+        b"glyf" => b"loca",
+        b"vmtx" => b"vhea",
+        _ => return None,
+    };
+
+    Some(Tag::from_bytes(dependency))
 }
 
 /// A raw table record.
@@ -798,6 +965,75 @@ impl core::fmt::Display for FaceParsingError {
 #[cfg(feature = "std")]
 impl std::error::Error for FaceParsingError {}
 
+/// A glyph outlines format.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum OutlineFormat {
+    /// Quadratic outlines from the `glyf` table.
+    TrueType,
+    /// Cubic outlines from the `CFF ` table.
+    CompactFontFormat,
+    /// Cubic outlines from the `CFF2` table.
+    CompactFontFormat2,
+}
+
+/// A glyph representation selected by [`Face::glyph_rendering`].
+#[derive(Clone, Debug)]
+pub enum GlyphRendering<'a> {
+    /// An SVG document from the `SVG ` table.
+    Svg(svg::SvgDocument<'a>),
+    /// A color glyph from the `COLR` table.
+    ///
+    /// Should be drawn using [`Face::paint_color_glyph`].
+    Color,
+    /// A raster image.
+    Raster(RasterGlyphImage<'a>),
+    /// An outline.
+    ///
+    /// Should be drawn using [`Face::outline_glyph`].
+    Outline(OutlineFormat),
+}
+
+/// A non-fatal face parsing issue.
+///
+/// Returned by [`Face::parse_with_warnings`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FaceWarning {
+    /// A table the issue is related to.
+    pub tag: Tag,
+    /// An issue kind.
+    pub kind: FaceWarningKind,
+}
+
+impl core::fmt::Display for FaceWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.kind {
+            FaceWarningKind::OutOfBounds => {
+                write!(f, "the {} table is out of bounds", self.tag)
+            }
+            FaceWarningKind::Malformed => write!(f, "the {} table is malformed", self.tag),
+            FaceWarningKind::MissingDependency(dependency) => write!(
+                f,
+                "the {} table is ignored because the {} table is missing",
+                self.tag, dependency
+            ),
+        }
+    }
+}
+
+/// A [`FaceWarning`] kind.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FaceWarningKind {
+    /// The table record points outside the font data.
+    OutOfBounds,
+    /// The table is malformed or not supported.
+    ///
+    /// For tables that depend on other ones, like `glyf` on `loca`,
+    /// it's also possible that the dependency is malformed instead.
+    Malformed,
+    /// The table requires another table that is not present. Like `loca` for `glyf`.
+    MissingDependency(Tag),
+}
+
 /// A raw font face.
 ///
 /// You are probably looking for [`Face`]. This is a low-level type.
@@ -837,42 +1073,53 @@ impl<'a> RawFace<'a> {
     pub fn parse(data: &'a [u8], index: u32) -> Result<Self, FaceParsingError> {
         // https://docs.microsoft.com/en-us/typography/opentype/spec/otff#organization-of-an-opentype-font
 
-        let mut s = Stream::new(data);
-
         // Read **font** magic.
-        let magic = s.read::<Magic>().ok_or(FaceParsingError::UnknownMagic)?;
+        let magic = Stream::new(data)
+            .read::<Magic>()
+            .ok_or(FaceParsingError::UnknownMagic)?;
         if magic == Magic::FontCollection {
-            s.skip::<u32>(); // version
-            let number_of_faces = s.read::<u32>().ok_or(FaceParsingError::MalformedFont)?;
-            let offsets = s
-                .read_array32::<Offset32>(number_of_faces)
-                .ok_or(FaceParsingError::MalformedFont)?;
-
+            let offsets = parse_collection_offsets(data)?;
             let face_offset = offsets
                 .get(index)
                 .ok_or(FaceParsingError::FaceIndexOutOfBounds)?;
-            // Face offset is from the start of the font data,
-            // so we have to adjust it to the current parser offset.
-            let face_offset = face_offset
-                .to_usize()
-                .checked_sub(s.offset())
-                .ok_or(FaceParsingError::MalformedFont)?;
-            s.advance_checked(face_offset)
-                .ok_or(FaceParsingError::MalformedFont)?;
-
-            // Read **face** magic.
-            // Each face in a font collection also starts with a magic.
-            let magic = s.read::<Magic>().ok_or(FaceParsingError::UnknownMagic)?;
-            // And face in a font collection can't be another collection.
-            if magic == Magic::FontCollection {
-                return Err(FaceParsingError::UnknownMagic);
-            }
+            Self::parse_collection_face(data, offsets, face_offset)
         } else {
             // When reading from a regular font (not a collection) disallow index to be non-zero
             // Basically treat the font as a one-element collection
             if index != 0 {
                 return Err(FaceParsingError::FaceIndexOutOfBounds);
             }
+
+            Self::parse_face(data, 0)
+        }
+    }
+
+    /// Parses a face stored in a font collection.
+    fn parse_collection_face(
+        data: &'a [u8],
+        offsets: LazyArray32<'a, Offset32>,
+        face_offset: Offset32,
+    ) -> Result<Self, FaceParsingError> {
+        // Face offset is from the start of the font data,
+        // but faces cannot overlap with the collection header.
+        let header_len = 12 + usize::num_from(offsets.len()) * Offset32::SIZE;
+        if face_offset.to_usize() < header_len {
+            return Err(FaceParsingError::MalformedFont);
+        }
+
+        Self::parse_face(data, face_offset.to_usize())
+    }
+
+    /// Parses a face starting at the specified offset.
+    fn parse_face(data: &'a [u8], offset: usize) -> Result<Self, FaceParsingError> {
+        let mut s = Stream::new_at(data, offset).ok_or(FaceParsingError::MalformedFont)?;
+
+        // Read **face** magic.
+        // Each face in a font collection also starts with a magic.
+        let magic = s.read::<Magic>().ok_or(FaceParsingError::UnknownMagic)?;
+        // And face in a font collection can't be another collection.
+        if magic == Magic::FontCollection {
+            return Err(FaceParsingError::UnknownMagic);
         }
 
         let num_tables = s.read::<u16>().ok_or(FaceParsingError::MalformedFont)?;
@@ -887,6 +1134,14 @@ impl<'a> RawFace<'a> {
         })
     }
 
+    /// Returns the offset of a selected table in the font data.
+    fn table_offset(&self, tag: Tag) -> Option<usize> {
+        let (_, table) = self
+            .table_records
+            .binary_search_by(|record| record.tag.cmp(&tag))?;
+        Some(usize::num_from(table.offset))
+    }
+
     /// Returns the raw data of a selected table.
     pub fn table(&self, tag: Tag) -> Option<&'a [u8]> {
         let (_, table) = self
@@ -929,12 +1184,15 @@ pub struct RawFaceTables<'a> {
     pub cmap: Option<&'a [u8]>,
     pub colr: Option<&'a [u8]>,
     pub cpal: Option<&'a [u8]>,
+    pub dsig: Option<&'a [u8]>,
     pub ebdt: Option<&'a [u8]>,
     pub eblc: Option<&'a [u8]>,
+    pub gasp: Option<&'a [u8]>,
     pub glyf: Option<&'a [u8]>,
     pub hmtx: Option<&'a [u8]>,
     pub kern: Option<&'a [u8]>,
     pub loca: Option<&'a [u8]>,
+    pub meta: Option<&'a [u8]>,
     pub name: Option<&'a [u8]>,
     pub os2: Option<&'a [u8]>,
     pub post: Option<&'a [u8]>,
@@ -945,6 +1203,8 @@ pub struct RawFaceTables<'a> {
     pub vmtx: Option<&'a [u8]>,
     pub vorg: Option<&'a [u8]>,
 
+    #[cfg(feature = "opentype-layout")]
+    pub base: Option<&'a [u8]>,
     #[cfg(feature = "opentype-layout")]
     pub gdef: Option<&'a [u8]>,
     #[cfg(feature = "opentype-layout")]
@@ -1002,10 +1262,13 @@ pub struct FaceTables<'a> {
     pub cff: Option<cff::Table<'a>>,
     pub cmap: Option<cmap::Table<'a>>,
     pub colr: Option<colr::Table<'a>>,
+    pub dsig: Option<dsig::Table<'a>>,
     pub ebdt: Option<cbdt::Table<'a>>,
+    pub gasp: Option<gasp::Table<'a>>,
     pub glyf: Option<glyf::Table<'a>>,
     pub hmtx: Option<hmtx::Table<'a>>,
     pub kern: Option<kern::Table<'a>>,
+    pub meta: Option<meta::Table<'a>>,
     pub name: Option<name::Table<'a>>,
     pub os2: Option<os2::Table<'a>>,
     pub post: Option<post::Table<'a>>,
@@ -1016,6 +1279,8 @@ pub struct FaceTables<'a> {
     pub vmtx: Option<hmtx::Table<'a>>,
     pub vorg: Option<vorg::Table<'a>>,
 
+    #[cfg(feature = "opentype-layout")]
+    pub base: Option<base::Table<'a>>,
     #[cfg(feature = "opentype-layout")]
     pub gdef: Option<gdef::Table<'a>>,
     #[cfg(feature = "opentype-layout")]
@@ -1052,6 +1317,28 @@ pub struct FaceTables<'a> {
     pub vvar: Option<vvar::Table<'a>>,
 }
 
+/// A summary produced by [`Face::exercise_all`].
+#[cfg(feature = "fuzzing")]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct ExerciseSummary {
+    /// The number of glyphs with an outline.
+    pub outlines: u16,
+    /// The number of glyphs with a name.
+    pub glyph_names: u16,
+    /// The number of glyphs with a raster or an SVG image.
+    pub images: u16,
+    /// The number of glyphs defined in the `COLR` table.
+    pub color_glyphs: u16,
+    /// The number of code points mapped by all `cmap` subtables.
+    pub code_points: u32,
+    /// The number of non-zero `kern` values between adjacent glyph IDs.
+    pub kerning_pairs: u32,
+    /// The number of `GSUB` and `GPOS` lookup subtables.
+    pub lookup_subtables: u32,
+    /// The number of `name` table records.
+    pub names: u16,
+}
+
 /// A font face.
 ///
 /// Provides a high-level API for working with TrueType fonts.
@@ -1066,6 +1353,12 @@ pub struct FaceTables<'a> {
 /// If you still want to store `Face` - checkout
 /// [owned_ttf_parser](https://crates.io/crates/owned_ttf_parser). Requires `unsafe`.
 ///
+/// Methods taking a [`GlyphId`] do not panic on out of range IDs.
+/// But the ID is checked only by the queried table and some tables, like `post`, `SVG `,
+/// `CFF` and `CBDT`, have their own glyph count that can differ from
+/// [`number_of_glyphs`](Face::number_of_glyphs).
+/// Use [`Face::is_valid_glyph`] to reject IDs that are not in the face.
+///
 /// While `Face` is technically copyable, we disallow it because it's almost 2KB big.
 #[derive(Clone)]
 pub struct Face<'a> {
@@ -1105,8 +1398,38 @@ impl<'a> Face<'a> {
     /// Required tables: `head`, `hhea` and `maxp`.
     ///
     /// If an optional table has invalid data it will be skipped.
+    ///
+    /// If only a couple of tables are needed, use [`RawFace::parse`] instead
+    /// and parse the required tables directly via [`RawFace::table`].
     pub fn parse(data: &'a [u8], index: u32) -> Result<Self, FaceParsingError> {
-        let raw_face = RawFace::parse(data, index)?;
+        Self::from_raw_face(RawFace::parse(data, index)?)
+    }
+
+    /// Returns an iterator over all font faces in a font collection.
+    ///
+    /// Unlike calling [`Face::parse`] for each index, parses the collection header only once.
+    /// For a regular font, yields exactly one face.
+    ///
+    /// When the data is neither a font nor a font collection, yields a single error.
+    #[inline]
+    pub fn collection(data: &'a [u8]) -> FaceCollectionIter<'a> {
+        let faces = match Stream::new(data).read::<Magic>() {
+            Some(Magic::FontCollection) => match parse_collection_offsets(data) {
+                Ok(offsets) => CollectionFaces::Collection(offsets),
+                Err(e) => CollectionFaces::Error(e),
+            },
+            Some(_) => CollectionFaces::Single,
+            None => CollectionFaces::Error(FaceParsingError::UnknownMagic),
+        };
+
+        FaceCollectionIter {
+            data,
+            faces,
+            index: 0,
+        }
+    }
+
+    fn from_raw_face(raw_face: RawFace<'a>) -> Result<Self, FaceParsingError> {
         let raw_tables = Self::collect_tables(raw_face);
 
         #[allow(unused_mut)]
@@ -1127,6 +1450,159 @@ impl<'a> Face<'a> {
         Ok(face)
     }
 
+    /// Creates a new [`Face`] from WOFF data.
+    ///
+    /// The font is decompressed into `buffer`, which the returned face borrows.
+    /// WOFF doesn't support font collections, so there is no face index.
+    ///
+    /// Unlike all other methods, allocates.
+    /// See [`woff::decode`] for details.
+    #[cfg(feature = "woff")]
+    pub fn from_woff(
+        data: &[u8],
+        buffer: &'a mut std::vec::Vec<u8>,
+    ) -> Result<Self, FaceParsingError> {
+        *buffer = woff::decode(data)?;
+        Self::parse(buffer, 0)
+    }
+
+    /// Creates a new [`Face`] from WOFF2 data.
+    ///
+    /// The font is decompressed into `buffer`, which the returned face borrows.
+    /// `index` indicates the specific font face in a font collection.
+    /// Set to 0 if unsure.
+    ///
+    /// Unlike all other methods, allocates.
+    /// See [`woff2::decode`] for details.
+    #[cfg(feature = "woff2")]
+    pub fn from_woff2(
+        data: &[u8],
+        index: u32,
+        buffer: &'a mut std::vec::Vec<u8>,
+    ) -> Result<Self, FaceParsingError> {
+        *buffer = woff2::decode(data, index)?;
+        Self::parse(buffer, 0)
+    }
+
+    /// Creates a new [`Face`] from a raw data, and reports tables that were ignored.
+    ///
+    /// [`Face::parse`] silently ignores tables that are out of bounds or malformed,
+    /// so a face without a `cmap` could have a broken one instead of none at all.
+    /// This method reports such tables as [`FaceWarning`]s.
+    ///
+    /// Warnings are reported even when parsing fails, as long as table records are readable.
+    /// Only tables supported by the crate are checked.
+    #[cfg(feature = "std")]
+    pub fn parse_with_warnings(
+        data: &'a [u8],
+        index: u32,
+    ) -> (Result<Self, FaceParsingError>, std::vec::Vec<FaceWarning>) {
+        let mut warnings = std::vec::Vec::new();
+        let face = Self::parse(data, index);
+
+        if let Ok(raw_face) = RawFace::parse(data, index) {
+            for record in raw_face.table_records {
+                let start = usize::num_from(record.offset);
+                let in_bounds = start
+                    .checked_add(usize::num_from(record.length))
+                    .map(|end| end <= data.len())
+                    .unwrap_or(false);
+
+                let kind = if !in_bounds {
+                    FaceWarningKind::OutOfBounds
+                } else if let Ok(ref face) = face {
+                    match face.is_table_parsed(record.tag) {
+                        Some(false) => match table_dependency(record.tag) {
+                            Some(dependency) if raw_face.table(dependency).is_none() => {
+                                FaceWarningKind::MissingDependency(dependency)
+                            }
+                            _ => FaceWarningKind::Malformed,
+                        },
+                        _ => continue,
+                    }
+                } else {
+                    continue;
+                };
+
+                warnings.push(FaceWarning {
+                    tag: record.tag,
+                    kind,
+                });
+            }
+        }
+
+        (face, warnings)
+    }
+
+    /// Checks that a table was parsed.
+    ///
+    /// Returns `None` for unsupported tables and tables that are not stored separately,
+    /// like `loca`.
+    #[cfg(feature = "std")]
+    fn is_table_parsed(&self, tag: Tag) -> Option<bool> {
+        let t = &self.tables;
+        let is_parsed = match &tag.to_bytes() {
+            b"bdat" => t.bdat.is_some(),
+            b"CBDT" => t.cbdt.is_some(),
+            b"CFF " => t.cff.is_some(),
+            #[cfg(feature = "variable-fonts")]
+            b"CFF2" => t.cff2.is_some(),
+            b"COLR" => t.colr.is_some(),
+            b"DSIG" => t.dsig.is_some(),
+            b"EBDT" => t.ebdt.is_some(),
+            #[cfg(feature = "opentype-layout")]
+            b"BASE" => t.base.is_some(),
+            #[cfg(feature = "opentype-layout")]
+            b"GDEF" => t.gdef.is_some(),
+            #[cfg(feature = "opentype-layout")]
+            b"GPOS" => t.gpos.is_some(),
+            #[cfg(feature = "opentype-layout")]
+            b"GSUB" => t.gsub.is_some(),
+            #[cfg(feature = "opentype-layout")]
+            b"MATH" => t.math.is_some(),
+            #[cfg(feature = "variable-fonts")]
+            b"HVAR" => t.hvar.is_some(),
+            #[cfg(feature = "variable-fonts")]
+            b"MVAR" => t.mvar.is_some(),
+            b"OS/2" => t.os2.is_some(),
+            b"SVG " => t.svg.is_some(),
+            b"STAT" => t.stat.is_some(),
+            b"VORG" => t.vorg.is_some(),
+            #[cfg(feature = "variable-fonts")]
+            b"VVAR" => t.vvar.is_some(),
+            #[cfg(feature = "apple-layout")]
+            b"ankr" => t.ankr.is_some(),
+            #[cfg(feature = "variable-fonts")]
+            b"avar" => t.avar.is_some(),
+            b"cmap" => t.cmap.is_some(),
+            #[cfg(feature = "apple-layout")]
+            b"feat" => t.feat.is_some(),
+            #[cfg(feature = "variable-fonts")]
+            b"fvar" => t.fvar.is_some(),
+            b"gasp" => t.gasp.is_some(),
+            b"glyf" => t.glyf.is_some(),
+            #[cfg(feature = "variable-fonts")]
+            b"gvar" => t.gvar.is_some(),
+            b"hmtx" => t.hmtx.is_some(),
+            b"kern" => t.kern.is_some(),
+            #[cfg(feature = "apple-layout")]
+            b"kerx" => t.kerx.is_some(),
+            b"meta" => t.meta.is_some(),
+            #[cfg(feature = "apple-layout")]
+            b"morx" => t.morx.is_some(),
+            b"name" => t.name.is_some(),
+            b"post" => t.post.is_some(),
+            b"sbix" => t.sbix.is_some(),
+            #[cfg(feature = "apple-layout")]
+            b"trak" => t.trak.is_some(),
+            b"vhea" => t.vhea.is_some(),
+            b"vmtx" => t.vmtx.is_some(),
+            _ => return None,
+        };
+
+        Some(is_parsed)
+    }
+
     fn collect_tables(raw_face: RawFace<'a>) -> RawFaceTables<'a> {
         let mut tables = RawFaceTables::default();
 
@@ -1148,9 +1624,12 @@ impl<'a> Face<'a> {
                 b"CFF2" => tables.cff2 = table_data,
                 b"COLR" => tables.colr = table_data,
                 b"CPAL" => tables.cpal = table_data,
+                b"DSIG" => tables.dsig = table_data,
                 b"EBDT" => tables.ebdt = table_data,
                 b"EBLC" => tables.eblc = table_data,
                 #[cfg(feature = "opentype-layout")]
+                b"BASE" => tables.base = table_data,
+                #[cfg(feature = "opentype-layout")]
                 b"GDEF" => tables.gdef = table_data,
                 #[cfg(feature = "opentype-layout")]
                 b"GPOS" => tables.gpos = table_data,
@@ -1176,6 +1655,7 @@ impl<'a> Face<'a> {
                 b"feat" => tables.feat = table_data,
                 #[cfg(feature = "variable-fonts")]
                 b"fvar" => tables.fvar = table_data,
+                b"gasp" => tables.gasp = table_data,
                 b"glyf" => tables.glyf = table_data,
                 #[cfg(feature = "variable-fonts")]
                 b"gvar" => tables.gvar = table_data,
@@ -1187,6 +1667,7 @@ impl<'a> Face<'a> {
                 b"kerx" => tables.kerx = table_data,
                 b"loca" => tables.loca = table_data,
                 b"maxp" => tables.maxp = table_data.unwrap_or_default(),
+                b"meta" => tables.meta = table_data,
                 #[cfg(feature = "apple-layout")]
                 b"morx" => tables.morx = table_data,
                 b"name" => tables.name = table_data,
@@ -1299,10 +1780,13 @@ impl<'a> Face<'a> {
             cff: raw_tables.cff.and_then(cff::Table::parse),
             cmap: raw_tables.cmap.and_then(cmap::Table::parse),
             colr,
+            dsig: raw_tables.dsig.and_then(dsig::Table::parse),
             ebdt,
+            gasp: raw_tables.gasp.and_then(gasp::Table::parse),
             glyf,
             hmtx,
             kern: raw_tables.kern.and_then(kern::Table::parse),
+            meta: raw_tables.meta.and_then(meta::Table::parse),
             name: raw_tables.name.and_then(name::Table::parse),
             os2: raw_tables.os2.and_then(os2::Table::parse),
             post: raw_tables.post.and_then(post::Table::parse),
@@ -1315,6 +1799,8 @@ impl<'a> Face<'a> {
             vmtx,
             vorg: raw_tables.vorg.and_then(vorg::Table::parse),
 
+            #[cfg(feature = "opentype-layout")]
+            base: raw_tables.base.and_then(base::Table::parse),
             #[cfg(feature = "opentype-layout")]
             gdef: raw_tables.gdef.and_then(gdef::Table::parse),
             #[cfg(feature = "opentype-layout")]
@@ -1378,6 +1864,74 @@ impl<'a> Face<'a> {
         &self.raw_face
     }
 
+    /// Checks that the `head` table `checkSumAdjustment` matches the font data.
+    ///
+    /// The expected value is `0xB1B0AFBA` minus the checksum of the whole font,
+    /// calculated with `checkSumAdjustment` set to zero.
+    ///
+    /// Returns `None` for font collections, faces not created using
+    /// [`Face::parse()`](struct.Face.html#method.parse)
+    /// and when the `head` table is not 4-byte aligned.
+    pub fn verify_head_checksum_adjustment(&self) -> Option<bool> {
+        if self.is_collection_member() {
+            return None;
+        }
+
+        let data = self.raw_face.data;
+
+        let head_offset = self.raw_face.table_offset(Tag::from_bytes(b"head"))?;
+        if head_offset % 4 != 0 {
+            return None;
+        }
+
+        let adjustment = Stream::read_at::<u32>(data, head_offset + 8)?;
+
+        let mut sum = 0u32;
+        for chunk in data.chunks(4) {
+            let mut bytes = [0; 4];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            sum = sum.wrapping_add(u32::from_be_bytes(bytes));
+        }
+
+        // Exclude `checkSumAdjustment` itself.
+        sum = sum.wrapping_sub(adjustment);
+
+        Some(0xB1B0AFBA_u32.wrapping_sub(sum) == adjustment)
+    }
+
+    /// Checks that the face has at least one digital signature.
+    ///
+    /// An empty `DSIG` table, often used as a placeholder, doesn't count.
+    /// No verification is performed.
+    #[inline]
+    pub fn has_digital_signature(&self) -> bool {
+        self.tables.dsig.map(|t| !t.is_empty()).unwrap_or(false)
+    }
+
+    /// Returns an iterator over digital signatures from the `DSIG` table.
+    #[inline]
+    pub fn digital_signatures(&self) -> dsig::Signatures<'a> {
+        self.tables.dsig.map(|t| t.signatures()).unwrap_or_default()
+    }
+
+    /// Returns design languages from the `meta` table.
+    ///
+    /// A comma-separated list of [BCP 47](https://www.rfc-editor.org/info/bcp47)
+    /// language tags the font was primarily designed for.
+    #[inline]
+    pub fn design_languages(&self) -> Option<&'a str> {
+        self.tables.meta?.design_languages()
+    }
+
+    /// Returns supported languages from the `meta` table.
+    ///
+    /// A comma-separated list of [BCP 47](https://www.rfc-editor.org/info/bcp47)
+    /// language tags the font is capable of supporting.
+    #[inline]
+    pub fn supported_languages(&self) -> Option<&'a str> {
+        self.tables.meta?.supported_languages()
+    }
+
     /// Returns the raw data of a selected table.
     ///
     /// Useful if you want to parse the data manually.
@@ -1420,6 +1974,21 @@ impl<'a> Face<'a> {
         self.tables.os2.map(|os2| os2.is_bold()).unwrap_or(false)
     }
 
+    /// Checks that typographic metrics should be used.
+    ///
+    /// When set, [`ascender`](Face::ascender), [`descender`](Face::descender)
+    /// and [`line_gap`](Face::line_gap) are taken from the `OS/2` table
+    /// instead of `hhea`.
+    ///
+    /// Returns `false` when OS/2 table is not present or when its version is < 4.
+    #[inline]
+    pub fn use_typographic_metrics(&self) -> bool {
+        self.tables
+            .os2
+            .map(|os2| os2.use_typographic_metrics())
+            .unwrap_or(false)
+    }
+
     /// Checks that face is marked as *Oblique*.
     ///
     /// Returns `false` when OS/2 table is not present or when its version is < 4.
@@ -1437,6 +2006,7 @@ impl<'a> Face<'a> {
     /// Checks that face is marked as *Monospaced*.
     ///
     /// Returns `false` when `post` table is not present.
+    /// Use [`is_monospaced_by_metrics`](Face::is_monospaced_by_metrics) in this case.
     #[inline]
     pub fn is_monospaced(&self) -> bool {
         self.tables
@@ -1445,6 +2015,55 @@ impl<'a> Face<'a> {
             .unwrap_or(false)
     }
 
+    /// Checks that face is *Monospaced* using horizontal metrics.
+    ///
+    /// Unlike [`is_monospaced`](Face::is_monospaced), doesn't rely on the `post` table flag,
+    /// but checks that all non-zero advances in the `hmtx` table are equal.
+    /// Stops at the first different advance. Variations are ignored.
+    ///
+    /// Returns `false` when `hmtx` table is not present.
+    #[inline]
+    pub fn is_monospaced_by_metrics(&self) -> bool {
+        self.tables
+            .hmtx
+            .map(|hmtx| hmtx.is_monospaced())
+            .unwrap_or(false)
+    }
+
+    /// Returns the format used by [`outline_glyph`](Face::outline_glyph).
+    ///
+    /// When multiple outline tables are present, returns the one that has priority.
+    /// `CFF2` requires the `variable-fonts` feature.
+    ///
+    /// Returns `None` when there are no supported outline tables. Like in bitmap-only fonts.
+    #[inline]
+    pub fn outline_format(&self) -> Option<OutlineFormat> {
+        if self.tables.glyf.is_some() {
+            return Some(OutlineFormat::TrueType);
+        }
+
+        if self.tables.cff.is_some() {
+            return Some(OutlineFormat::CompactFontFormat);
+        }
+
+        #[cfg(feature = "variable-fonts")]
+        {
+            if self.tables.cff2.is_some() {
+                return Some(OutlineFormat::CompactFontFormat2);
+            }
+        }
+
+        None
+    }
+
+    /// Checks that face was parsed from a font collection.
+    ///
+    /// Always returns `false` for faces created using [`Face::from_raw_tables`].
+    #[inline]
+    pub fn is_collection_member(&self) -> bool {
+        Stream::new(self.raw_face.data).read::<Magic>() == Some(Magic::FontCollection)
+    }
+
     /// Checks that face is variable.
     ///
     /// Simply checks the presence of a `fvar` table.
@@ -1489,6 +2108,20 @@ impl<'a> Face<'a> {
             .unwrap_or(0.0)
     }
 
+    /// Returns face's style attributes.
+    ///
+    /// Each attribute is an axis value from the `STAT` table
+    /// with a resolved axis tag, like `wght` 700 named "Bold".
+    ///
+    /// Returns an empty iterator when `STAT` table is not present.
+    #[inline]
+    pub fn style_attributes(&self) -> impl Iterator<Item = stat::StyleAttribute> + 'a {
+        self.tables
+            .stat
+            .into_iter()
+            .flat_map(|table| table.style_attributes())
+    }
+
     // Read https://github.com/freetype/freetype/blob/49270c17011491227ec7bd3fb73ede4f674aa065/src/sfnt/sfobjs.c#L1279
     // to learn more about the logic behind the following functions.
 
@@ -1632,6 +2265,22 @@ impl<'a> Face<'a> {
         })
     }
 
+    /// Checks that the face has vertical metrics.
+    ///
+    /// Both `vhea` and `vmtx` tables must be present.
+    #[inline]
+    pub fn has_vertical_metrics(&self) -> bool {
+        self.tables.vmtx.is_some()
+    }
+
+    /// Checks that the face has vertical metrics, but not horizontal ones.
+    ///
+    /// `hhea` is a mandatory table, therefore only the `hmtx` table presence is checked.
+    #[inline]
+    pub fn is_vertical_only(&self) -> bool {
+        self.has_vertical_metrics() && self.tables.hmtx.is_none()
+    }
+
     /// Returns a vertical face ascender.
     ///
     /// This method is affected by variation axes.
@@ -1741,14 +2390,48 @@ impl<'a> Face<'a> {
         Some(metrics)
     }
 
-    /// Returns face's subscript metrics.
+    /// Returns face's underline metrics or computed ones when `post` table is not present.
     ///
-    /// This method is affected by variation axes.
+    /// The fallback position is half of the [`descender`](Face::descender)
+    /// and the thickness is 1/14 of the units per em, but at least 1.
     ///
-    /// Returns `None` when OS/2 table is not present.
+    /// This method is affected by variation axes.
     #[inline]
-    pub fn subscript_metrics(&self) -> Option<ScriptMetrics> {
-        let mut metrics = self.tables.os2?.subscript_metrics();
+    pub fn underline_metrics_or_default(&self) -> LineMetrics {
+        self.underline_metrics().unwrap_or_else(|| LineMetrics {
+            position: self.descender() / 2,
+            thickness: self.default_line_thickness(),
+        })
+    }
+
+    /// Returns face's strikeout metrics or computed ones when OS/2 table is not present.
+    ///
+    /// The fallback position is a third of the [`ascender`](Face::ascender)
+    /// and the thickness is 1/14 of the units per em, but at least 1.
+    ///
+    /// This method is affected by variation axes.
+    #[inline]
+    pub fn strikeout_metrics_or_default(&self) -> LineMetrics {
+        self.strikeout_metrics().unwrap_or_else(|| LineMetrics {
+            position: self.ascender() / 3,
+            thickness: self.default_line_thickness(),
+        })
+    }
+
+    #[inline]
+    fn default_line_thickness(&self) -> i16 {
+        // `units_per_em` is at most 16384, so the result always fits.
+        (self.units_per_em() / 14).max(1) as i16
+    }
+
+    /// Returns face's subscript metrics.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present.
+    #[inline]
+    pub fn subscript_metrics(&self) -> Option<ScriptMetrics> {
+        let mut metrics = self.tables.os2?.subscript_metrics();
 
         if self.is_variable() {
             self.apply_metrics_variation_to(Tag::from_bytes(b"sbxs"), &mut metrics.x_size);
@@ -1779,6 +2462,253 @@ impl<'a> Face<'a> {
         Some(metrics)
     }
 
+    /// Returns a transform for a synthesized subscript glyph.
+    ///
+    /// Scales a glyph outline by the subscript size and moves it down by the subscript offset.
+    /// The result is in font units, just like the original outline.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present.
+    #[inline]
+    pub fn subscript_transform(&self) -> Option<Transform> {
+        let metrics = self.subscript_metrics()?;
+        Some(self.script_transform(metrics, -f32::from(metrics.y_offset)))
+    }
+
+    /// Returns a transform for a synthesized superscript glyph.
+    ///
+    /// Scales a glyph outline by the superscript size and moves it up by the superscript offset.
+    /// The result is in font units, just like the original outline.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns `None` when OS/2 table is not present.
+    #[inline]
+    pub fn superscript_transform(&self) -> Option<Transform> {
+        let metrics = self.superscript_metrics()?;
+        Some(self.script_transform(metrics, f32::from(metrics.y_offset)))
+    }
+
+    /// Returns a baseline coordinate for a script from the `BASE` table.
+    ///
+    /// Uses the horizontal axis and falls back to the `DFLT` script
+    /// when `script` is not present.
+    /// `baseline_tag` is a baseline tag, like `romn`, `ideo`, `hang` or `math`.
+    ///
+    /// This method is affected by variation axes.
+    ///
+    /// Returns `None` when `BASE` table is not present or doesn't have such baseline.
+    #[cfg(feature = "opentype-layout")]
+    pub fn baseline(&self, script: Tag, baseline_tag: Tag) -> Option<i16> {
+        let base = self.tables.base?;
+        let coord = base.horizontal_axis?.baseline(script, baseline_tag)?;
+
+        #[cfg(feature = "variable-fonts")]
+        {
+            if let Some(gpos::Device::Variation(device)) = coord.device {
+                if self.is_variable() {
+                    let delta = base
+                        .variation_delta(device.outer_index, device.inner_index, self.coords())
+                        .unwrap_or(0.0);
+                    // We can't use `round()` in `no_std`, so this is the next best thing.
+                    return i16::try_num_from(f32::from(coord.coordinate) + delta + 0.5);
+                }
+            }
+        }
+
+        Some(coord.coordinate)
+    }
+
+    /// Returns an iterator over all glyphs with a class
+    /// in the `GDEF` glyph class definition table.
+    ///
+    /// Returns an empty iterator when `GDEF` table is not present.
+    #[cfg(feature = "opentype-layout")]
+    #[inline]
+    pub fn glyph_classes(&self) -> gdef::GlyphClasses<'a> {
+        self.tables.gdef.unwrap_or_default().glyph_classes()
+    }
+
+    /// Checks that glyph has the `Mark` class in the `GDEF` glyph class definition table.
+    ///
+    /// Unlike [`gdef::Table::is_mark_glyph`], which checks mark glyph sets,
+    /// this is the class used to skip marks during shaping.
+    ///
+    /// Returns `false` when `GDEF` table is not present.
+    #[cfg(feature = "opentype-layout")]
+    #[inline]
+    pub fn is_mark(&self, glyph_id: GlyphId) -> bool {
+        self.tables.gdef.and_then(|gdef| gdef.glyph_class(glyph_id)) == Some(gdef::GlyphClass::Mark)
+    }
+
+    /// Returns an iterator over `GDEF` mark glyph sets.
+    ///
+    /// Returns an empty iterator when `GDEF` table is not present.
+    #[cfg(feature = "opentype-layout")]
+    #[inline]
+    pub fn mark_glyph_sets(&self) -> gdef::MarkGlyphSets<'a> {
+        self.tables.gdef.unwrap_or_default().mark_glyph_sets()
+    }
+
+    /// Returns GSUB feature's lookup indices for the current variation coordinates.
+    ///
+    /// Applies lookup substitutions from the GSUB `FeatureVariations` table,
+    /// like the ones used by the `rvrn` feature.
+    /// The feature index should be taken from a script's language system,
+    /// since a feature tag can be present multiple times.
+    /// Use [`LayoutTable::feature_lookups`](opentype_layout::LayoutTable::feature_lookups)
+    /// for GPOS.
+    ///
+    /// Returns an empty iterator when GSUB table is not present or doesn't have such feature.
+    #[cfg(all(feature = "opentype-layout", feature = "variable-fonts"))]
+    #[inline]
+    pub fn active_feature_variation_lookups(
+        &self,
+        feature_index: opentype_layout::FeatureIndex,
+    ) -> LazyArrayIter16<'a, opentype_layout::LookupIndex> {
+        self.tables
+            .gsub
+            .and_then(|table| self.resolve_feature_lookups(table, feature_index))
+            .unwrap_or_default()
+            .into_iter()
+    }
+
+    /// Returns feature's lookup indices, with feature variations applied when supported.
+    #[cfg(feature = "opentype-layout")]
+    #[inline]
+    fn resolve_feature_lookups(
+        &self,
+        table: opentype_layout::LayoutTable<'a>,
+        feature_index: opentype_layout::FeatureIndex,
+    ) -> Option<LazyArray16<'a, opentype_layout::LookupIndex>> {
+        #[cfg(feature = "variable-fonts")]
+        {
+            table.feature_lookups(feature_index, self.coords())
+        }
+
+        #[cfg(not(feature = "variable-fonts"))]
+        {
+            table
+                .features
+                .get(feature_index)
+                .map(|feature| feature.lookup_indices)
+        }
+    }
+
+    /// Returns glyph's entry and exit anchors from the GPOS cursive attachment subtables.
+    ///
+    /// Uses the first cursive attachment subtable that covers the glyph,
+    /// regardless of the features it is referenced by.
+    /// Either anchor can be `None`, when the glyph has no entry or exit point.
+    ///
+    /// Returns `None` when the glyph is not covered by any cursive attachment subtable.
+    #[cfg(feature = "opentype-layout")]
+    pub fn cursive_anchors(
+        &self,
+        glyph_id: GlyphId,
+    ) -> Option<(Option<gpos::Anchor<'a>>, Option<gpos::Anchor<'a>>)> {
+        for lookup in self.tables.gpos?.lookups {
+            for subtable in lookup.subtables.into_iter::<gpos::PositioningSubtable>() {
+                if let gpos::PositioningSubtable::Cursive(subtable) = subtable {
+                    if let Some(anchors) = subtable.anchors(glyph_id) {
+                        return Some(anchors);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Applies variation deltas to a GPOS value record.
+    ///
+    /// Variation device tables are resolved using the `GDEF` item variation store
+    /// and the current variation coordinates, after which they are removed from the record.
+    /// Hinting device tables are kept as is, since they depend on the pixels per em.
+    #[cfg(all(feature = "opentype-layout", feature = "variable-fonts"))]
+    pub fn resolve_value_record(&self, mut record: gpos::ValueRecord<'a>) -> gpos::ValueRecord<'a> {
+        let resolve = |value: &mut i16, device: &mut Option<gpos::Device<'a>>| {
+            if let Some(gpos::Device::Variation(variation)) = *device {
+                *device = None;
+                if !self.is_variable() {
+                    return;
+                }
+
+                let delta = self
+                    .tables
+                    .gdef
+                    .and_then(|gdef| {
+                        gdef.glyph_variation_delta(
+                            variation.outer_index,
+                            variation.inner_index,
+                            self.coords(),
+                        )
+                    })
+                    .unwrap_or(0.0);
+                // We can't use `round()` in `no_std`, so this is the next best thing.
+                if let Some(v) = i16::try_num_from(f32::from(*value) + delta + 0.5) {
+                    *value = v;
+                }
+            }
+        };
+
+        resolve(&mut record.x_placement, &mut record.x_placement_device);
+        resolve(&mut record.y_placement, &mut record.y_placement_device);
+        resolve(&mut record.x_advance, &mut record.x_advance_device);
+        resolve(&mut record.y_advance, &mut record.y_advance_device);
+        record
+    }
+
+    /// Returns glyph's single adjustment from the GPOS feature lookups.
+    ///
+    /// Uses the first single adjustment subtable that covers the glyph among
+    /// the feature's lookups, with feature variations applied.
+    /// The feature index should be taken from a script's language system,
+    /// since a feature tag can be present multiple times.
+    ///
+    /// Returns `None` when GPOS table is not present, doesn't have such feature
+    /// or the glyph is not covered by its single adjustment subtables.
+    ///
+    /// Variation device tables are not applied.
+    /// Use [`resolve_value_record`](Face::resolve_value_record) for that.
+    #[cfg(feature = "opentype-layout")]
+    pub fn single_adjustment(
+        &self,
+        glyph_id: GlyphId,
+        feature_index: opentype_layout::FeatureIndex,
+    ) -> Option<gpos::ValueRecord<'a>> {
+        let gpos = self.tables.gpos?;
+        for lookup_index in self.resolve_feature_lookups(gpos, feature_index)? {
+            let lookup = match gpos.lookups.get(lookup_index) {
+                Some(v) => v,
+                None => continue,
+            };
+
+            for subtable in lookup.subtables.into_iter::<gpos::PositioningSubtable>() {
+                if let gpos::PositioningSubtable::Single(subtable) = subtable {
+                    if let Some(value) = subtable.value(glyph_id) {
+                        return Some(value);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn script_transform(&self, metrics: ScriptMetrics, y_offset: f32) -> Transform {
+        let units_per_em = f32::from(self.units_per_em());
+        Transform::new(
+            f32::from(metrics.x_size) / units_per_em,
+            0.0,
+            0.0,
+            f32::from(metrics.y_size) / units_per_em,
+            f32::from(metrics.x_offset),
+            y_offset,
+        )
+    }
+
     /// Returns face permissions.
     ///
     /// Returns `None` in case of a malformed value.
@@ -1818,6 +2748,56 @@ impl<'a> Face<'a> {
             .unwrap_or_default()
     }
 
+    /// Returns [Unicode Ranges](https://docs.microsoft.com/en-us/typography/opentype/spec/os2#ur)
+    /// actually covered by the `cmap` table.
+    ///
+    /// Unlike [`unicode_ranges`](Face::unicode_ranges), which are set by the font author
+    /// and are often incorrect, this method checks the mapped code points.
+    /// A range is considered covered when at least one of its code points is mapped.
+    ///
+    /// Uses the same subtable as [`referenced_glyphs`](Face::referenced_glyphs).
+    /// Format 12 and 13 groups are intersected with Unicode Ranges directly,
+    /// without enumerating their code points.
+    pub fn covered_unicode_ranges(&self) -> UnicodeRanges {
+        let mut ranges = UnicodeRanges::default();
+        let subtable = match self.preferred_unicode_subtable() {
+            Some(v) => v,
+            None => return ranges,
+        };
+
+        let mut add_mapped_range = |first: u32, last: u32| {
+            for (_, _, index) in os2::unicode_ranges_within(first, last) {
+                ranges.0 |= 1u128 << index;
+            }
+        };
+
+        match subtable.format {
+            cmap::Format::SegmentedCoverage(ref table) => table.mapped_ranges(add_mapped_range),
+            cmap::Format::ManyToOneRangeMappings(ref table) => {
+                table.mapped_ranges(add_mapped_range)
+            }
+            // Other formats are limited either to 16-bit code points or by their data size.
+            _ => {
+                // The last covered range. Starts empty.
+                let mut last = (1, 0);
+                subtable.codepoints(|code_point| {
+                    if last.0 <= code_point && code_point <= last.1 {
+                        return;
+                    }
+
+                    if let Some((first, end, _)) = os2::unicode_range(code_point) {
+                        if subtable.glyph_index(code_point).is_some() {
+                            add_mapped_range(code_point, code_point);
+                            last = (first, end);
+                        }
+                    }
+                });
+            }
+        }
+
+        ranges
+    }
+
     /// Returns a total number of glyphs in the face.
     ///
     /// Never zero.
@@ -1828,6 +2808,38 @@ impl<'a> Face<'a> {
         self.tables.maxp.number_of_glyphs.get()
     }
 
+    /// Returns the number of horizontal metrics in the `hmtx` table
+    /// as stored in the `hhea` table.
+    ///
+    /// The value was already parsed, so this function doesn't involve any parsing.
+    #[inline]
+    pub fn number_of_h_metrics(&self) -> u16 {
+        self.tables.hhea.number_of_metrics
+    }
+
+    /// Returns the number of vertical metrics in the `vmtx` table
+    /// as stored in the `vhea` table.
+    ///
+    /// Returns `None` when `vhea` table is not present.
+    #[inline]
+    pub fn number_of_v_metrics(&self) -> Option<u16> {
+        self.tables.vhea.map(|vhea| vhea.number_of_metrics)
+    }
+
+    /// Returns an iterator over all glyph IDs in the face.
+    ///
+    /// Valid glyph IDs are in a `0..number_of_glyphs` range.
+    #[inline]
+    pub fn glyphs(&self) -> impl ExactSizeIterator<Item = GlyphId> {
+        (0..self.number_of_glyphs()).map(GlyphId)
+    }
+
+    /// Checks that the glyph ID is in a `0..number_of_glyphs` range.
+    #[inline]
+    pub fn is_valid_glyph(&self, glyph_id: GlyphId) -> bool {
+        glyph_id.0 < self.number_of_glyphs()
+    }
+
     /// Resolves a Glyph ID for a code point.
     ///
     /// Returns `None` instead of `0` when glyph is not found.
@@ -1850,6 +2862,114 @@ impl<'a> Face<'a> {
         None
     }
 
+    /// Calls `f` for each distinct glyph referenced by the `cmap` table.
+    ///
+    /// Uses the subtable returned by [`cmap::Subtables::best_unicode`].
+    /// Each glyph is reported only once, in the code points order.
+    ///
+    /// Useful for subsetting, as the initial set of glyphs to keep.
+    pub fn referenced_glyphs<F: FnMut(GlyphId)>(&self, mut f: F) {
+        let subtable = match self.preferred_unicode_subtable() {
+            Some(v) => v,
+            None => return,
+        };
+
+        // A bitset for the whole GID space. 8 KiB on the stack.
+        let mut seen = [0u64; 1024];
+        subtable.codepoints(|code_point| {
+            if let Some(id) = subtable.glyph_index(code_point) {
+                let idx = usize::from(id.0);
+                let bit = 1u64 << (idx % 64);
+                if seen[idx / 64] & bit == 0 {
+                    seen[idx / 64] |= bit;
+                    f(id);
+                }
+            }
+        });
+    }
+
+    fn preferred_unicode_subtable(&self) -> Option<cmap::Subtable<'a>> {
+        self.tables.cmap?.subtables.best_unicode()
+    }
+
+    /// Resolves a Glyph ID for a Mac Roman encoded byte.
+    ///
+    /// Uses the first `cmap` subtable with the Macintosh platform and the Roman encoding.
+    /// Useful for legacy documents, where text is stored as Mac Roman instead of Unicode.
+    ///
+    /// Returns `None` instead of `0` when glyph is not found
+    /// and when there is no Mac Roman subtable.
+    #[inline]
+    pub fn glyph_index_mac_roman(&self, byte: u8) -> Option<GlyphId> {
+        // https://docs.microsoft.com/en-us/typography/opentype/spec/name#macintosh-encoding-ids-script-manager-codes
+        const MACINTOSH_ROMAN_ENCODING_ID: u16 = 0;
+
+        let subtable = self.tables.cmap?.subtables.into_iter().find(|subtable| {
+            subtable.platform_id == PlatformId::Macintosh
+                && subtable.encoding_id == MACINTOSH_ROMAN_ENCODING_ID
+        })?;
+
+        subtable.glyph_index(u32::from(byte))
+    }
+
+    /// Resolves a Glyph ID for a raw code using the specified `cmap` subtable.
+    ///
+    /// Unlike [`Face::glyph_index`], doesn't check the subtable encoding,
+    /// so `code` must be in the subtable encoding already.
+    ///
+    /// Returns `None` instead of `0` when glyph is not found.
+    #[inline]
+    pub fn glyph_index_via(&self, subtable: cmap::Subtable, code: u32) -> Option<GlyphId> {
+        subtable.glyph_index(code)
+    }
+
+    /// Resolves a Glyph ID for a code point using any suitable `cmap` subtable.
+    ///
+    /// Tries Unicode subtables first, like [`Face::glyph_index`].
+    /// Then a Mac Roman subtable, when a code point can be encoded as Mac Roman.
+    /// And finally Windows Symbol subtables, which usually map `0x20..=0xFF`
+    /// to the `0xF020..=0xF0FF` range.
+    ///
+    /// Useful for legacy fonts without a Unicode `cmap` subtable.
+    ///
+    /// Returns `None` instead of `0` when glyph is not found.
+    pub fn glyph_index_any(&self, code_point: char) -> Option<GlyphId> {
+        // https://docs.microsoft.com/en-us/typography/opentype/spec/name#windows-encoding-ids
+        const WINDOWS_SYMBOL_ENCODING_ID: u16 = 0;
+
+        if let Some(id) = self.glyph_index(code_point) {
+            return Some(id);
+        }
+
+        if let Some(id) =
+            cmap::mac_roman_from_char(code_point).and_then(|b| self.glyph_index_mac_roman(b))
+        {
+            return Some(id);
+        }
+
+        let code = u32::from(code_point);
+        if code > 0xFF {
+            return None;
+        }
+
+        for subtable in self.tables.cmap?.subtables {
+            if subtable.platform_id != PlatformId::Windows
+                || subtable.encoding_id != WINDOWS_SYMBOL_ENCODING_ID
+            {
+                continue;
+            }
+
+            if let Some(id) = subtable
+                .glyph_index(0xF000 | code)
+                .or_else(|| subtable.glyph_index(code))
+            {
+                return Some(id);
+            }
+        }
+
+        None
+    }
+
     /// Resolves a Glyph ID for a glyph name.
     ///
     /// Uses the `post` and `CFF` tables as sources.
@@ -1899,6 +3019,58 @@ impl<'a> Face<'a> {
         None
     }
 
+    /// Returns an iterator over variation selectors supported by the face.
+    ///
+    /// Uses the same `cmap` format 14 subtable as
+    /// [`glyph_variation_index`](Face::glyph_variation_index).
+    /// Ordered by code point.
+    #[inline]
+    pub fn variation_selectors(&self) -> impl Iterator<Item = char> + 'a {
+        let subtable = self.tables.cmap.and_then(|cmap| {
+            cmap.subtables
+                .into_iter()
+                .find_map(|subtable| match subtable.format {
+                    cmap::Format::UnicodeVariationSequences(table) => Some(table),
+                    _ => None,
+                })
+        });
+
+        subtable
+            .into_iter()
+            .flat_map(|table| table.variation_selectors())
+            .filter_map(char::from_u32)
+    }
+
+    /// Returns a vertical kerning value between two glyphs from the `kern` table.
+    ///
+    /// Only vertical subtables without cross-stream values are used.
+    /// Values from multiple subtables are accumulated.
+    /// Subtables with a state machine are ignored.
+    ///
+    /// Returns `None` when no vertical subtable contains the pair.
+    pub fn glyphs_vertical_kerning(&self, left: GlyphId, right: GlyphId) -> Option<i16> {
+        let mut value = None;
+        for subtable in self.tables.kern?.subtables {
+            if subtable.horizontal || subtable.has_cross_stream {
+                continue;
+            }
+
+            if let Some(v) = subtable.glyphs_kerning(left, right) {
+                value = Some(value.unwrap_or(0i16).saturating_add(v));
+            }
+        }
+
+        value
+    }
+
+    /// Returns a rasterizer behavior for the specified size from the `gasp` table.
+    ///
+    /// See [`gasp::Table::find`] for details.
+    #[inline]
+    pub fn rasterizer_behavior(&self, ppem: u16) -> Option<gasp::Range> {
+        self.tables.gasp?.find(ppem)
+    }
+
     /// Returns glyph's horizontal advance.
     ///
     /// This method is affected by variation axes.
@@ -1930,6 +3102,74 @@ impl<'a> Face<'a> {
         }
     }
 
+    /// Returns glyph's unrounded horizontal advance.
+    ///
+    /// Unlike [`glyph_hor_advance`](Face::glyph_hor_advance), variation offsets
+    /// are not rounded, which avoids accumulating rounding errors during layout.
+    ///
+    /// This method is affected by variation axes.
+    #[inline]
+    pub fn glyph_hor_advance_f32(&self, glyph_id: GlyphId) -> Option<f32> {
+        #[allow(unused_mut)]
+        let mut advance = f32::from(self.tables.hmtx?.advance(glyph_id)?);
+
+        #[cfg(feature = "variable-fonts")]
+        {
+            if self.is_variable() {
+                // Ignore variation offset when `hvar` is not set.
+                if let Some(hvar) = self.tables.hvar {
+                    if let Some(offset) = hvar.advance_offset(glyph_id, self.coords()) {
+                        advance += offset;
+                    }
+                } else if let Some(points) = self.glyph_phantom_points(glyph_id) {
+                    advance += points.right.x;
+                }
+            }
+        }
+
+        Some(advance)
+    }
+
+    /// Fills `advances` with unrounded horizontal advances for `glyphs`.
+    ///
+    /// Unlike [`glyph_hor_advance`](Face::glyph_hor_advance), variation offsets
+    /// are not rounded, which is preferable for shaping at high resolutions.
+    ///
+    /// Current variation coordinates are applied.
+    ///
+    /// Stops at the first glyph without an advance or when either slice is exhausted.
+    /// Returns the number of written advances.
+    pub fn glyph_hor_advances_f32(&self, glyphs: &[GlyphId], advances: &mut [f32]) -> usize {
+        let mut count = 0;
+        for (glyph_id, advance) in glyphs.iter().zip(advances.iter_mut()) {
+            *advance = match self.glyph_hor_advance_f32(*glyph_id) {
+                Some(v) => v,
+                None => break,
+            };
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Fills `advances` with horizontal advances of all glyphs, indexed by glyph ID.
+    ///
+    /// Current variation coordinates are applied, same as in
+    /// [`glyph_hor_advance`](Face::glyph_hor_advance).
+    /// Glyphs without an advance are set to 0.
+    ///
+    /// The slice should be sized to [`number_of_glyphs`](Face::number_of_glyphs).
+    /// Returns the number of written advances.
+    pub fn build_advance_cache(&self, advances: &mut [u16]) -> usize {
+        let count = advances.len().min(usize::from(self.number_of_glyphs()));
+        for (i, advance) in advances[..count].iter_mut().enumerate() {
+            // `count` is bounded by `number_of_glyphs`, so `i` always fits into `u16`.
+            *advance = self.glyph_hor_advance(GlyphId(i as u16)).unwrap_or(0);
+        }
+
+        count
+    }
+
     /// Returns glyph's vertical advance.
     ///
     /// This method is affected by variation axes.
@@ -1961,6 +3201,34 @@ impl<'a> Face<'a> {
         }
     }
 
+    /// Returns glyph's unrounded vertical advance.
+    ///
+    /// Unlike [`glyph_ver_advance`](Face::glyph_ver_advance), variation offsets
+    /// are not rounded, which avoids accumulating rounding errors during layout.
+    ///
+    /// This method is affected by variation axes.
+    #[inline]
+    pub fn glyph_ver_advance_f32(&self, glyph_id: GlyphId) -> Option<f32> {
+        #[allow(unused_mut)]
+        let mut advance = f32::from(self.tables.vmtx?.advance(glyph_id)?);
+
+        #[cfg(feature = "variable-fonts")]
+        {
+            if self.is_variable() {
+                // Ignore variation offset when `vvar` is not set.
+                if let Some(vvar) = self.tables.vvar {
+                    if let Some(offset) = vvar.advance_offset(glyph_id, self.coords()) {
+                        advance += offset;
+                    }
+                } else if let Some(points) = self.glyph_phantom_points(glyph_id) {
+                    advance += points.bottom.y;
+                }
+            }
+        }
+
+        Some(advance)
+    }
+
     /// Returns glyph's horizontal side bearing.
     ///
     /// This method is affected by variation axes.
@@ -2017,6 +3285,31 @@ impl<'a> Face<'a> {
         }
     }
 
+    /// Returns all glyph metrics at once.
+    ///
+    /// Each field is the same as returned by
+    /// [`glyph_hor_advance`](Face::glyph_hor_advance),
+    /// [`glyph_hor_side_bearing`](Face::glyph_hor_side_bearing),
+    /// [`glyph_ver_advance`](Face::glyph_ver_advance),
+    /// [`glyph_ver_side_bearing`](Face::glyph_ver_side_bearing) and
+    /// [`glyph_bounding_box`](Face::glyph_bounding_box),
+    /// using the current variation coordinates.
+    ///
+    /// Returns `None` for out of range glyph IDs.
+    pub fn glyph_metrics(&self, glyph_id: GlyphId) -> Option<GlyphMetrics> {
+        if !self.is_valid_glyph(glyph_id) {
+            return None;
+        }
+
+        Some(GlyphMetrics {
+            hor_advance: self.glyph_hor_advance(glyph_id),
+            hor_side_bearing: self.glyph_hor_side_bearing(glyph_id),
+            ver_advance: self.glyph_ver_advance(glyph_id),
+            ver_side_bearing: self.glyph_ver_side_bearing(glyph_id),
+            bounding_box: self.glyph_bounding_box(glyph_id),
+        })
+    }
+
     /// Returns glyph's vertical origin according to
     /// [Vertical Origin Table](https://docs.microsoft.com/en-us/typography/opentype/spec/vorg).
     ///
@@ -2048,6 +3341,7 @@ impl<'a> Face<'a> {
     /// Returns glyph's name.
     ///
     /// Uses the `post` and `CFF` tables as sources.
+    /// CID-keyed fonts have no glyph names, see [`glyph_cid`](Face::glyph_cid) instead.
     ///
     /// Returns `None` when no name is associated with a `glyph`.
     #[cfg(feature = "glyph-names")]
@@ -2069,6 +3363,34 @@ impl<'a> Face<'a> {
         None
     }
 
+    /// Returns a Unicode code point derived from glyph's name.
+    ///
+    /// Parses `uniXXXX` and `uXXXX[XX]` names, as well as standard Macintosh glyph names
+    /// from the [Adobe Glyph List](https://github.com/adobe-type-tools/agl-specification),
+    /// like `Aacute`. A suffix like in `a.sc` is ignored.
+    ///
+    /// Useful for fonts without a usable `cmap` table, which is common for PDF subsets.
+    /// The `cmap` table itself is not used.
+    ///
+    /// Returns `None` when a glyph has no name or it cannot be mapped to a single code point.
+    #[cfg(feature = "glyph-names")]
+    #[inline]
+    pub fn glyph_name_to_unicode(&self, glyph_id: GlyphId) -> Option<char> {
+        agl::name_to_char(self.glyph_name(glyph_id)?)
+    }
+
+    /// Returns glyph's CID from a CID-keyed `CFF` table.
+    ///
+    /// CID-keyed fonts have no glyph names, therefore [`glyph_name`](Face::glyph_name)
+    /// returns `None` for them. Other tools usually format CIDs as `cid00042` instead.
+    ///
+    /// Returns `None` when there is no CID-keyed `CFF` table.
+    #[cfg(feature = "glyph-names")]
+    #[inline]
+    pub fn glyph_cid(&self, glyph_id: GlyphId) -> Option<u16> {
+        self.tables.cff.as_ref()?.glyph_cid(glyph_id)
+    }
+
     /// Outlines a glyph and returns its tight bounding box.
     ///
     /// **Warning**: since `ttf-parser` is a pull parser,
@@ -2122,36 +3444,224 @@ impl<'a> Face<'a> {
     /// assert_eq!(bbox, ttf_parser::Rect { x_min: 6, y_min: 0, x_max: 541, y_max: 656 });
     /// ```
     #[inline]
-    pub fn outline_glyph(
+    pub fn outline_glyph(
+        &self,
+        glyph_id: GlyphId,
+        builder: &mut dyn OutlineBuilder,
+    ) -> Option<Rect> {
+        #[cfg(feature = "variable-fonts")]
+        {
+            if let Some(ref gvar) = self.tables.gvar {
+                return gvar.outline(self.tables.glyf?, self.coords(), glyph_id, builder);
+            }
+        }
+
+        if let Some(table) = self.tables.glyf {
+            return table.outline(glyph_id, builder);
+        }
+
+        if let Some(ref cff) = self.tables.cff {
+            return cff.outline(glyph_id, builder).ok();
+        }
+
+        #[cfg(feature = "variable-fonts")]
+        {
+            if let Some(ref cff2) = self.tables.cff2 {
+                return cff2.outline(self.coords(), glyph_id, builder).ok();
+            }
+        }
+
+        None
+    }
+
+    /// Checks that the glyph has no outline, like a space glyph.
+    ///
+    /// Unlike [`outline_glyph`](Face::outline_glyph) returning `None`, allows distinguishing
+    /// a glyph without an outline from a malformed one.
+    /// For `glyf` it's a cheap `loca` lookup, while for `CFF` the charstring still
+    /// has to be parsed.
+    ///
+    /// Returns `false` when the glyph is not present or the face has no outlines.
+    pub fn glyph_is_empty(&self, glyph_id: GlyphId) -> bool {
+        if !self.is_valid_glyph(glyph_id) {
+            return false;
+        }
+
+        if let Some(table) = self.tables.glyf {
+            return table.is_empty_glyph(glyph_id);
+        }
+
+        if let Some(ref cff) = self.tables.cff {
+            return matches!(
+                cff.outline(glyph_id, &mut DummyOutline),
+                Err(CFFError::ZeroBBox)
+            );
+        }
+
+        #[cfg(feature = "variable-fonts")]
+        {
+            if let Some(ref cff2) = self.tables.cff2 {
+                return matches!(
+                    cff2.outline(self.coords(), glyph_id, &mut DummyOutline),
+                    Err(CFFError::ZeroBBox)
+                );
+            }
+        }
+
+        false
+    }
+
+    /// Returns the number of glyphs with an outline.
+    ///
+    /// Unlike [`number_of_glyphs`](Face::number_of_glyphs), doesn't count glyphs
+    /// without an outline, like a space glyph.
+    /// For `glyf` only glyph headers are read, so malformed glyphs with a non-zero
+    /// number of contours are counted. For `CFF` all charstrings have to be parsed,
+    /// so malformed glyphs are not counted.
+    ///
+    /// Returns 0 when the face has no outlines.
+    pub fn outlined_glyph_count(&self) -> u16 {
+        let glyphs = (0..self.number_of_glyphs()).map(GlyphId);
+
+        if let Some(table) = self.tables.glyf {
+            return glyphs
+                .filter(|id| matches!(table.number_of_contours(*id), Some(n) if n != 0))
+                .count() as u16;
+        }
+
+        if let Some(ref cff) = self.tables.cff {
+            return glyphs
+                .filter(|id| cff.outline(*id, &mut DummyOutline).is_ok())
+                .count() as u16;
+        }
+
+        #[cfg(feature = "variable-fonts")]
+        {
+            if let Some(ref cff2) = self.tables.cff2 {
+                return glyphs
+                    .filter(|id| cff2.outline(self.coords(), *id, &mut DummyOutline).is_ok())
+                    .count() as u16;
+            }
+        }
+
+        0
+    }
+
+    /// Counts contours and points of a glyph outline.
+    ///
+    /// Returns `None` when [`outline_glyph`](Face::outline_glyph) does,
+    /// including glyphs without an outline.
+    #[inline]
+    pub fn glyph_point_counts(&self, glyph_id: GlyphId) -> Option<GlyphPointCounts> {
+        let mut counts = GlyphPointCounts::default();
+        self.outline_glyph(glyph_id, &mut counts)?;
+        Some(counts)
+    }
+
+    /// Outlines a glyph and reports contour boundaries.
+    ///
+    /// Works exactly like [`outline_glyph`](Face::outline_glyph), but also calls
+    /// [`ContourBuilder::begin_contour`] and [`ContourBuilder::end_contour`]
+    /// around each contour's segments.
+    ///
+    /// Contours are indexed from zero in the emitted order.
+    /// For composite glyphs, indices continue across components.
+    #[inline]
+    pub fn outline_glyph_indexed(
+        &self,
+        glyph_id: GlyphId,
+        builder: &mut dyn ContourBuilder,
+    ) -> Option<Rect> {
+        let mut builder = IndexedBuilder::new(builder);
+        let bbox = self.outline_glyph(glyph_id, &mut builder);
+        builder.end_contour();
+        bbox
+    }
+
+    /// Outlines a glyph using caller-provided [`OutlineLimits`].
+    ///
+    /// Useful for processing untrusted fonts, when the built-in limits are too permissive.
+    ///
+    /// Returns `None` when any of the limits was exceeded. In which case the `builder` output
+    /// is incomplete and must be discarded. Otherwise, works exactly like
+    /// [`outline_glyph`](Face::outline_glyph).
+    ///
+    /// Only `max_depth` reduces the parsing work. The points and segments limits
+    /// stop forwarding to the `builder`, but the rest of the glyph is still parsed.
+    pub fn outline_glyph_with_limits(
+        &self,
+        glyph_id: GlyphId,
+        builder: &mut dyn OutlineBuilder,
+        limits: OutlineLimits,
+    ) -> Option<Rect> {
+        let mut builder = LimitedBuilder::new(builder, limits);
+        let bbox = self.outline_glyph_with_depth(glyph_id, limits.max_depth, &mut builder)?;
+        if builder.exceeded {
+            return None;
+        }
+
+        Some(bbox)
+    }
+
+    fn outline_glyph_with_depth(
         &self,
         glyph_id: GlyphId,
+        max_depth: u8,
         builder: &mut dyn OutlineBuilder,
     ) -> Option<Rect> {
         #[cfg(feature = "variable-fonts")]
         {
             if let Some(ref gvar) = self.tables.gvar {
-                return gvar.outline(self.tables.glyf?, self.coords(), glyph_id, builder);
+                let glyf = self.tables.glyf?;
+                return gvar.outline_with_depth(glyf, self.coords(), glyph_id, max_depth, builder);
             }
         }
 
         if let Some(table) = self.tables.glyf {
-            return table.outline(glyph_id, builder);
+            return table.outline_with_depth(glyph_id, max_depth, builder);
         }
 
         if let Some(ref cff) = self.tables.cff {
-            return cff.outline(glyph_id, builder).ok();
+            return cff.outline_with_depth(glyph_id, max_depth, builder).ok();
         }
 
         #[cfg(feature = "variable-fonts")]
         {
             if let Some(ref cff2) = self.tables.cff2 {
-                return cff2.outline(self.coords(), glyph_id, builder).ok();
+                return cff2
+                    .outline_with_depth(self.coords(), glyph_id, max_depth, builder)
+                    .ok();
             }
         }
 
         None
     }
 
+    /// Outlines a glyph and returns its segments.
+    ///
+    /// A convenience wrapper around [`outline_glyph`](Face::outline_glyph),
+    /// which allocates. Prefer `outline_glyph` when performance matters.
+    ///
+    /// Returns `None` when glyph has no outline or on error.
+    #[cfg(feature = "std")]
+    pub fn glyph_outline(&self, glyph_id: GlyphId) -> Option<std::vec::Vec<PathSegment>> {
+        let mut builder = PathSegmentsBuilder(std::vec::Vec::new());
+        self.outline_glyph(glyph_id, &mut builder)?;
+        Some(builder.0)
+    }
+
+    /// Returns glyph outline metrics, like length and contours orientation.
+    ///
+    /// Returns `None` when glyph has no outline or on error.
+    #[inline]
+    pub fn glyph_path_stats(&self, glyph_id: GlyphId) -> Option<PathStats> {
+        let mut stats = PathStats::new();
+        self.outline_glyph(glyph_id, &mut stats)?;
+        // Just in case the last contour wasn't closed.
+        stats.finish();
+        Some(stats)
+    }
+
     /// Returns a tight glyph bounding box.
     ///
     /// This is just a shorthand for `outline_glyph()` since only the `glyf` table stores
@@ -2173,12 +3683,118 @@ impl<'a> Face<'a> {
         self.outline_glyph(glyph_id, &mut DummyOutline)
     }
 
+    /// Returns a precise glyph bounding box.
+    ///
+    /// Unlike [`glyph_bounding_box`](Face::glyph_bounding_box), which includes
+    /// off-curve control points, calculates the actual curves extrema.
+    /// Which is slower, but produces a tight bounding box for curved glyphs.
+    ///
+    /// Minimums are rounded down and maximums are rounded up.
+    ///
+    /// This method is affected by variation axes.
+    pub fn glyph_bounding_box_precise(&self, glyph_id: GlyphId) -> Option<Rect> {
+        let mut builder = PreciseBBoxBuilder::new();
+        self.outline_glyph(glyph_id, &mut builder)?;
+        builder.to_rect()
+    }
+
+    /// Returns a union of glyphs precise bounding boxes.
+    ///
+    /// See [`glyph_bounding_box_precise`](Face::glyph_bounding_box_precise) for details.
+    /// Glyphs are not positioned in any way, so all bounding boxes share the same origin.
+    /// Glyphs without an outline are skipped.
+    ///
+    /// Returns `None` when none of the glyphs has an outline.
+    ///
+    /// This method is affected by variation axes.
+    pub fn glyphs_bounding_box(&self, glyphs: impl IntoIterator<Item = GlyphId>) -> Option<Rect> {
+        glyphs
+            .into_iter()
+            .filter_map(|glyph_id| self.glyph_bounding_box_precise(glyph_id))
+            .reduce(|a, b| Rect {
+                x_min: a.x_min.min(b.x_min),
+                y_min: a.y_min.min(b.y_min),
+                x_max: a.x_max.max(b.x_max),
+                y_max: a.y_max.max(b.y_max),
+            })
+    }
+
+    /// Returns glyph's extents in font units.
+    ///
+    /// Unlike [`glyph_bounding_box`](Face::glyph_bounding_box), also supports glyphs
+    /// defined by a raster image. In which case the extents are calculated from
+    /// the image offset and size in the largest strike, scaled from pixels to font units.
+    /// Minimums are rounded down and maximums are rounded up.
+    ///
+    /// SVG images are not supported, since their size is not known without rendering.
+    ///
+    /// This method is affected by variation axes.
+    pub fn glyph_extents(&self, glyph_id: GlyphId) -> Option<Rect> {
+        use core::convert::TryFrom;
+
+        if let Some(bbox) = self.glyph_bounding_box(glyph_id) {
+            return Some(bbox);
+        }
+
+        let (image, _) = self.glyph_raster_image_impl(glyph_id, u16::MAX)?;
+        if image.pixels_per_em == 0 {
+            return None;
+        }
+
+        let units_per_em = i32::from(self.units_per_em());
+        let pixels_per_em = i32::from(image.pixels_per_em);
+        let floor = |v: i32| i16::try_from((v * units_per_em).div_euclid(pixels_per_em)).ok();
+        let ceil = |v: i32| i16::try_from(-(-v * units_per_em).div_euclid(pixels_per_em)).ok();
+
+        let x = i32::from(image.x);
+        let y = i32::from(image.y);
+        Some(Rect {
+            x_min: floor(x)?,
+            y_min: floor(y)?,
+            x_max: ceil(x + i32::from(image.width))?,
+            y_max: ceil(y + i32::from(image.height))?,
+        })
+    }
+
     /// Returns a bounding box that large enough to enclose any glyph from the face.
     #[inline]
     pub fn global_bounding_box(&self) -> Rect {
         self.tables.head.global_bbox
     }
 
+    /// Selects the preferred representation of a glyph.
+    ///
+    /// Images take priority over outlines and are checked in the following order:
+    /// [`glyph_svg_image`](Face::glyph_svg_image),
+    /// [`is_color_glyph`](Face::is_color_glyph) and
+    /// [`glyph_raster_image`](Face::glyph_raster_image), which uses `pixels_per_em`.
+    /// Otherwise, an outline is returned when the face has one, even for an empty glyph.
+    ///
+    /// Returns `None` for out of range glyph IDs and when nothing is available.
+    pub fn glyph_rendering(
+        &self,
+        glyph_id: GlyphId,
+        pixels_per_em: u16,
+    ) -> Option<GlyphRendering<'_>> {
+        if !self.is_valid_glyph(glyph_id) {
+            return None;
+        }
+
+        if let Some(document) = self.glyph_svg_image(glyph_id) {
+            return Some(GlyphRendering::Svg(document));
+        }
+
+        if self.is_color_glyph(glyph_id) {
+            return Some(GlyphRendering::Color);
+        }
+
+        if let Some(image) = self.glyph_raster_image(glyph_id, pixels_per_em) {
+            return Some(GlyphRendering::Raster(image));
+        }
+
+        self.outline_format().map(GlyphRendering::Outline)
+    }
+
     /// Returns a reference to a glyph's raster image.
     ///
     /// A font can define a glyph using a raster or a vector image instead of a simple outline.
@@ -2205,26 +3821,142 @@ impl<'a> Face<'a> {
         glyph_id: GlyphId,
         pixels_per_em: u16,
     ) -> Option<RasterGlyphImage> {
+        self.glyph_raster_image_impl(glyph_id, pixels_per_em)
+            .map(|(image, _)| image)
+    }
+
+    /// Returns a position of a glyph's raster image data in the font data.
+    ///
+    /// The image is resolved exactly like in [`glyph_raster_image`](Face::glyph_raster_image).
+    ///
+    /// Allows accessing image data without copying, for example from a memory-mapped file.
+    ///
+    /// Returns `None` for faces created using [`Face::from_raw_tables`].
+    #[inline]
+    pub fn glyph_raster_image_data_range(
+        &self,
+        glyph_id: GlyphId,
+        pixels_per_em: u16,
+    ) -> Option<core::ops::Range<usize>> {
+        let (image, tag) = self.glyph_raster_image_impl(glyph_id, pixels_per_em)?;
+        let offset = self.raw_face.table_offset(Tag::from_bytes(tag))?;
+        let range = image.data_range();
+        Some(offset.checked_add(range.start)?..offset.checked_add(range.end)?)
+    }
+
+    /// Returns an iterator over pixels per em of the embedded bitmap strikes.
+    ///
+    /// Uses the same `bdat`, `EBDT` and `CBDT` table priority as
+    /// [`glyph_raster_image`](Face::glyph_raster_image).
+    /// `sbix` strikes are available via [`sbix::Table::strikes`].
+    ///
+    /// Returns an empty iterator when there are no such tables.
+    #[inline]
+    pub fn bitmap_strikes(&self) -> cblc::Strikes<'a> {
+        self.tables
+            .bdat
+            .or(self.tables.ebdt)
+            .or(self.tables.cbdt)
+            .map(|table| table.strikes())
+            .unwrap_or_default()
+    }
+
+    #[inline]
+    fn glyph_raster_image_impl(
+        &self,
+        glyph_id: GlyphId,
+        pixels_per_em: u16,
+    ) -> Option<(RasterGlyphImage<'a>, &'static [u8; 4])> {
         if let Some(table) = self.tables.sbix {
             if let Some(strike) = table.best_strike(pixels_per_em) {
-                return strike.get(glyph_id);
+                return strike.get(glyph_id).map(|image| (image, b"sbix"));
             }
         }
         if let Some(bdat) = self.tables.bdat {
-            return bdat.get(glyph_id, pixels_per_em);
+            return bdat
+                .get(glyph_id, pixels_per_em)
+                .map(|image| (image, b"bdat"));
         }
 
         if let Some(ebdt) = self.tables.ebdt {
-            return ebdt.get(glyph_id, pixels_per_em);
+            return ebdt
+                .get(glyph_id, pixels_per_em)
+                .map(|image| (image, b"EBDT"));
         }
 
         if let Some(cbdt) = self.tables.cbdt {
-            return cbdt.get(glyph_id, pixels_per_em);
+            return cbdt
+                .get(glyph_id, pixels_per_em)
+                .map(|image| (image, b"CBDT"));
         }
 
         None
     }
 
+    /// Returns an iterator over AAT features from the `feat` table.
+    ///
+    /// Each feature provides its type, a `name` table index
+    /// and a list of settings (aka selectors) with their own `name` table indices.
+    ///
+    /// Returns an empty iterator when there is no `feat` table.
+    #[cfg(feature = "apple-layout")]
+    #[inline]
+    pub fn apple_features(&self) -> impl Iterator<Item = feat::FeatureName<'a>> + 'a {
+        self.tables
+            .feat
+            .map(|table| table.names.into_iter())
+            .into_iter()
+            .flatten()
+    }
+
+    /// Returns a horizontal tracking value in font units from the `trak` table.
+    ///
+    /// See [`trak::TrackData::tracking`] for details.
+    ///
+    /// Returns `None` when there is no `trak` table or the track is not defined.
+    #[cfg(feature = "apple-layout")]
+    #[inline]
+    pub fn tracking(&self, point_size: f32, track: f32) -> Option<f32> {
+        self.tables.trak?.horizontal.tracking(point_size, track)
+    }
+
+    /// Returns an iterator over glyph's anchor points from the `ankr` table.
+    ///
+    /// Returns an empty iterator when there is no `ankr` table
+    /// or the glyph has no anchor points.
+    #[cfg(feature = "apple-layout")]
+    #[inline]
+    pub fn apple_anchor_points(&self, glyph_id: GlyphId) -> impl Iterator<Item = ankr::Point> + 'a {
+        self.tables
+            .ankr
+            .as_ref()
+            .and_then(|table| table.points(glyph_id))
+            .into_iter()
+            .flatten()
+    }
+
+    /// Substitutes a glyph using non-contextual `morx` subtables.
+    ///
+    /// `features` is a list of AAT feature type and setting (aka selector) pairs.
+    /// Features enabled by default are applied as well.
+    ///
+    /// This is a basic substitution for fonts without `GSUB`.
+    /// Contextual, ligature and insertion subtables are not applied.
+    ///
+    /// Returns `None` when the glyph wasn't substituted or when there is no `morx` table.
+    #[cfg(feature = "apple-layout")]
+    #[inline]
+    pub fn apple_substitution(
+        &self,
+        glyph_id: GlyphId,
+        features: &[(u16, u16)],
+    ) -> Option<GlyphId> {
+        self.tables
+            .morx
+            .as_ref()?
+            .substitute_non_contextual(glyph_id, features)
+    }
+
     /// Returns a reference to a glyph's SVG image.
     ///
     /// A font can define a glyph using a raster or a vector image instead of a simple outline.
@@ -2241,6 +3973,81 @@ impl<'a> Face<'a> {
         self.tables.svg.and_then(|svg| svg.documents.find(glyph_id))
     }
 
+    /// Returns glyph's TrueType instructions from the `glyf` table.
+    ///
+    /// The instructions are not validated or interpreted in any way.
+    ///
+    /// Returns `None` when `glyf` table is not present or glyph has no instructions.
+    #[inline]
+    pub fn glyph_instructions(&self, glyph_id: GlyphId) -> Option<&'a [u8]> {
+        self.tables.glyf?.glyph_instructions(glyph_id)
+    }
+
+    /// Returns glyph's data range in the `glyf` table.
+    ///
+    /// The range is relative to the start of the table and is empty for glyphs
+    /// without an outline, like a space glyph.
+    ///
+    /// Returns `None` when there is no `glyf` table or the glyph ID is out of bounds.
+    #[inline]
+    pub fn glyph_data_range(&self, glyph_id: GlyphId) -> Option<core::ops::Range<usize>> {
+        self.tables.glyf?.glyph_data_range(glyph_id)
+    }
+
+    /// Returns glyph's number of contours from the `glyf` table.
+    ///
+    /// Positive for simple glyphs, negative for composite glyphs
+    /// and zero for glyphs without an outline.
+    /// Much cheaper than outlining, since only the glyph header is read.
+    ///
+    /// Returns `None` when there is no `glyf` table or the glyph ID is out of bounds.
+    #[inline]
+    pub fn glyph_number_of_contours(&self, glyph_id: GlyphId) -> Option<i16> {
+        self.tables.glyf?.number_of_contours(glyph_id)
+    }
+
+    /// Returns values of the [Control Value Table](
+    /// https://docs.microsoft.com/en-us/typography/opentype/spec/cvt).
+    ///
+    /// Available only for faces created using [`Face::parse()`](struct.Face.html#method.parse).
+    #[inline]
+    pub fn cvt(&self) -> Option<LazyArray32<'a, i16>> {
+        self.raw_face
+            .table(Tag::from_bytes(b"cvt "))
+            .map(LazyArray32::new)
+    }
+
+    /// Returns the [Font Program](https://docs.microsoft.com/en-us/typography/opentype/spec/fpgm).
+    ///
+    /// Available only for faces created using [`Face::parse()`](struct.Face.html#method.parse).
+    #[inline]
+    pub fn font_program(&self) -> Option<&'a [u8]> {
+        self.raw_face.table(Tag::from_bytes(b"fpgm"))
+    }
+
+    /// Returns the [Control Value Program](
+    /// https://docs.microsoft.com/en-us/typography/opentype/spec/prep).
+    ///
+    /// Available only for faces created using [`Face::parse()`](struct.Face.html#method.parse).
+    #[inline]
+    pub fn prep_program(&self) -> Option<&'a [u8]> {
+        self.raw_face.table(Tag::from_bytes(b"prep"))
+    }
+
+    /// Checks that the face has [Graphite](https://graphite.sil.org) tables.
+    ///
+    /// Graphite itself is not supported, but the `Silf`, `Glat`, `Gloc` and `Feat` tables
+    /// can be accessed via [`RawFace::table`].
+    ///
+    /// Available only for faces created using [`Face::parse()`](struct.Face.html#method.parse).
+    #[inline]
+    pub fn has_graphite_tables(&self) -> bool {
+        // `Feat` and `Sill` are optional.
+        [b"Silf", b"Glat", b"Gloc"]
+            .iter()
+            .all(|tag| self.raw_face.table(Tag::from_bytes(tag)).is_some())
+    }
+
     /// Returns `true` if the glyph can be colored/painted using the `COLR`+`CPAL` tables.
     ///
     /// See [`paint_color_glyph`](Face::paint_color_glyph) for details.
@@ -2258,6 +4065,17 @@ impl<'a> Face<'a> {
         Some(self.tables().colr?.palettes.palettes())
     }
 
+    /// Returns an iterator over color glyph layers with colors from the first palette.
+    ///
+    /// A simpler alternative to [`paint_color_glyph`](Face::paint_color_glyph)
+    /// for `COLR` version 0 glyphs. Use [`colr::Table::layers`] to pick another palette.
+    ///
+    /// Returns `None` when the glyph has no `COLR` version 0 definition.
+    #[inline]
+    pub fn glyph_color_layers(&self, glyph_id: GlyphId) -> Option<colr::ColorLayers<'a>> {
+        self.tables.colr?.layers(glyph_id, 0)
+    }
+
     /// Paints a color glyph from the `COLR` table.
     ///
     /// A font can have multiple palettes, which you can check via
@@ -2272,6 +4090,11 @@ impl<'a> Face<'a> {
     /// when this method returns `None` you should also try
     /// [`outline_glyph`](Face::outline_glyph) afterwards.
     ///
+    /// For `COLR` version 1, the whole paint graph is traversed depth-first:
+    /// gradients are reported via [`Painter::paint`](colr::Painter::paint),
+    /// while glyph, transform and composite nodes are reported as matching
+    /// `push_*`/`pop_*` pairs around their children.
+    ///
     /// Returns `None` if the glyph has no `COLR` definition or if the glyph
     /// definition is malformed.
     ///
@@ -2301,9 +4124,25 @@ impl<'a> Face<'a> {
         self.tables.fvar.map(|fvar| fvar.axes).unwrap_or_default()
     }
 
+    /// Returns the number of variation axes.
+    #[cfg(feature = "variable-fonts")]
+    #[inline]
+    pub fn variation_axis_count(&self) -> u16 {
+        self.variation_axes().len()
+    }
+
+    /// Returns a variation axis at index.
+    ///
+    /// Indices match the ones used by [`variation_coordinates`](Face::variation_coordinates).
+    #[cfg(feature = "variable-fonts")]
+    #[inline]
+    pub fn variation_axis(&self, index: u16) -> Option<VariationAxis> {
+        self.variation_axes().get(index)
+    }
+
     /// Sets a variation axis coordinate.
     ///
-    /// This is one of the two only mutable methods in the library.
+    /// This is one of the few mutable methods in the library.
     /// We can simplify the API a lot by storing the variable coordinates
     /// in the face object itself.
     ///
@@ -2333,6 +4172,19 @@ impl<'a> Face<'a> {
         Some(())
     }
 
+    /// Resets all variation axes to their default values.
+    ///
+    /// Unlike recreating a face, keeps all the parsed tables.
+    #[cfg(feature = "variable-fonts")]
+    #[inline]
+    pub fn reset_variations(&mut self) {
+        // `avar` always maps the default coordinate to itself,
+        // so there is no need to apply it.
+        for coord in self.coordinates.as_mut_slice() {
+            *coord = NormalizedCoordinate::default();
+        }
+    }
+
     /// Returns the current normalized variation coordinates.
     #[cfg(feature = "variable-fonts")]
     #[inline]
@@ -2357,6 +4209,114 @@ impl<'a> Face<'a> {
         gvar.phantom_points(glyf, self.coords(), glyph_id)
     }
 
+    /// Exercises the whole high-level API.
+    ///
+    /// Outlines every glyph, resolves its metrics, name and images,
+    /// and iterates over all `cmap`, `kern`, `GSUB`, `GPOS` and `name` records.
+    ///
+    /// Designed to be used as a single fuzzing entry point.
+    /// Like any other method, must never panic regardless of the input.
+    #[cfg(feature = "fuzzing")]
+    pub fn exercise_all(&self) -> ExerciseSummary {
+        // Counters can overflow on malformed fonts, like overlapping `cmap` groups.
+        let mut summary = ExerciseSummary::default();
+
+        for id in 0..self.number_of_glyphs() {
+            let glyph_id = GlyphId(id);
+
+            if self.outline_glyph(glyph_id, &mut DummyOutline).is_some() {
+                summary.outlines = summary.outlines.saturating_add(1);
+            }
+
+            let _ = self.glyph_hor_advance(glyph_id);
+            let _ = self.glyph_ver_advance(glyph_id);
+            let _ = self.glyph_hor_side_bearing(glyph_id);
+            let _ = self.glyph_ver_side_bearing(glyph_id);
+            let _ = self.glyph_y_origin(glyph_id);
+
+            #[cfg(feature = "glyph-names")]
+            {
+                if let Some(name) = self.glyph_name(glyph_id) {
+                    let _ = self.glyph_index_by_name(name);
+                    summary.glyph_names = summary.glyph_names.saturating_add(1);
+                }
+            }
+
+            let has_raster_image = self.glyph_raster_image(glyph_id, u16::MAX).is_some();
+            let has_svg_image = self.glyph_svg_image(glyph_id).is_some();
+            if has_raster_image || has_svg_image {
+                summary.images = summary.images.saturating_add(1);
+            }
+
+            if self.is_color_glyph(glyph_id) {
+                summary.color_glyphs = summary.color_glyphs.saturating_add(1);
+            }
+        }
+
+        if let Some(cmap) = self.tables.cmap {
+            for subtable in cmap.subtables {
+                // Format 12 and 13 groups can span the whole code points range,
+                // so they are checked only at their boundaries.
+                let add_mapped_range = |first: u32, last: u32| {
+                    let _ = subtable.glyph_index(first);
+                    let _ = subtable.glyph_index(last);
+                    let len = (last - first).saturating_add(1);
+                    summary.code_points = summary.code_points.saturating_add(len);
+                };
+
+                match subtable.format {
+                    cmap::Format::SegmentedCoverage(ref table) => {
+                        table.mapped_ranges(add_mapped_range)
+                    }
+                    cmap::Format::ManyToOneRangeMappings(ref table) => {
+                        table.mapped_ranges(add_mapped_range)
+                    }
+                    _ => subtable.codepoints(|c| {
+                        if subtable.glyph_index(c).is_some() {
+                            summary.code_points = summary.code_points.saturating_add(1);
+                        }
+                    }),
+                }
+            }
+        }
+
+        if let Some(kern) = self.tables.kern {
+            for subtable in kern.subtables {
+                for id in 1..self.number_of_glyphs() {
+                    let value = subtable.glyphs_kerning(GlyphId(id - 1), GlyphId(id));
+                    if value.unwrap_or(0) != 0 {
+                        summary.kerning_pairs = summary.kerning_pairs.saturating_add(1);
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "opentype-layout")]
+        {
+            if let Some(gsub) = self.tables.gsub {
+                for lookup in gsub.lookups {
+                    for _ in lookup.subtables.into_iter::<gsub::SubstitutionSubtable>() {
+                        summary.lookup_subtables = summary.lookup_subtables.saturating_add(1);
+                    }
+                }
+            }
+
+            if let Some(gpos) = self.tables.gpos {
+                for lookup in gpos.lookups {
+                    for _ in lookup.subtables.into_iter::<gpos::PositioningSubtable>() {
+                        summary.lookup_subtables = summary.lookup_subtables.saturating_add(1);
+                    }
+                }
+            }
+        }
+
+        for _ in self.names() {
+            summary.names = summary.names.saturating_add(1);
+        }
+
+        summary
+    }
+
     #[cfg(feature = "variable-fonts")]
     #[inline]
     fn metrics_var_offset(&self, tag: Tag) -> f32 {
@@ -2401,6 +4361,74 @@ impl core::fmt::Debug for Face<'_> {
     }
 }
 
+/// Parses font collection face offsets.
+///
+/// Expects the data to start with a font collection magic.
+fn parse_collection_offsets<'a>(
+    data: &'a [u8],
+) -> Result<LazyArray32<'a, Offset32>, FaceParsingError> {
+    let mut s = Stream::new(data);
+    s.skip::<Magic>();
+    s.skip::<u32>(); // version
+    let number_of_faces = s.read::<u32>().ok_or(FaceParsingError::MalformedFont)?;
+    s.read_array32::<Offset32>(number_of_faces)
+        .ok_or(FaceParsingError::MalformedFont)
+}
+
+#[derive(Clone, Copy)]
+enum CollectionFaces<'a> {
+    Collection(LazyArray32<'a, Offset32>),
+    Single,
+    Error(FaceParsingError),
+}
+
+/// An iterator over font faces in a font collection.
+///
+/// Created by [`Face::collection`].
+#[derive(Clone, Copy)]
+pub struct FaceCollectionIter<'a> {
+    data: &'a [u8],
+    faces: CollectionFaces<'a>,
+    index: u32,
+}
+
+impl core::fmt::Debug for FaceCollectionIter<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "FaceCollectionIter {{ ... }}")
+    }
+}
+
+impl<'a> Iterator for FaceCollectionIter<'a> {
+    type Item = Result<Face<'a>, FaceParsingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let raw_face = match self.faces {
+            CollectionFaces::Collection(offsets) => {
+                let face_offset = offsets.get(self.index)?;
+                RawFace::parse_collection_face(self.data, offsets, face_offset)
+            }
+            CollectionFaces::Single if self.index == 0 => RawFace::parse_face(self.data, 0),
+            CollectionFaces::Error(e) if self.index == 0 => Err(e),
+            _ => return None,
+        };
+
+        self.index += 1;
+        Some(raw_face.and_then(Face::from_raw_face))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let total = match self.faces {
+            CollectionFaces::Collection(offsets) => offsets.len(),
+            _ => 1,
+        };
+
+        let left = usize::num_from(total.saturating_sub(self.index));
+        (left, Some(left))
+    }
+}
+
+impl ExactSizeIterator for FaceCollectionIter<'_> {}
+
 /// Returns the number of fonts stored in a TrueType font collection.
 ///
 /// Returns `None` if a provided data is not a TrueType font collection.
@@ -0,0 +1,713 @@
+//! Outline builder adapters and metrics.
+
+#[cfg(not(feature = "std"))]
+use core_maths::CoreFloat;
+
+use crate::{OutlineBuilder, PointF, Rect, RectF};
+
+/// An [`OutlineBuilder`] that is notified about contour boundaries.
+///
+/// Used by [`Face::outline_glyph_indexed`](crate::Face::outline_glyph_indexed).
+pub trait ContourBuilder: OutlineBuilder {
+    /// Called before the first segment of a contour.
+    fn begin_contour(&mut self, index: u16);
+
+    /// Called after the last segment of a contour.
+    fn end_contour(&mut self, index: u16);
+}
+
+/// An outline segment.
+///
+/// Returned by [`Face::glyph_outline`](crate::Face::glyph_outline).
+#[cfg(feature = "std")]
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PathSegment {
+    MoveTo(PointF),
+    LineTo(PointF),
+    QuadTo(PointF, PointF),
+    CurveTo(PointF, PointF, PointF),
+    Close,
+}
+
+#[cfg(feature = "std")]
+pub(crate) struct PathSegmentsBuilder(pub std::vec::Vec<PathSegment>);
+
+#[cfg(feature = "std")]
+impl OutlineBuilder for PathSegmentsBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.0.push(PathSegment::MoveTo(PointF { x, y }));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.0.push(PathSegment::LineTo(PointF { x, y }));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.0.push(PathSegment::QuadTo(
+            PointF { x: x1, y: y1 },
+            PointF { x, y },
+        ));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.0.push(PathSegment::CurveTo(
+            PointF { x: x1, y: y1 },
+            PointF { x: x2, y: y2 },
+            PointF { x, y },
+        ));
+    }
+
+    fn close(&mut self) {
+        self.0.push(PathSegment::Close);
+    }
+}
+
+/// Outline metrics.
+///
+/// Can be used as an [`OutlineBuilder`] directly or via [`Face::glyph_path_stats`](crate::Face::glyph_path_stats).
+/// When used directly, call [`finish`](PathStats::finish) after the outline
+/// to count the last contour, in case it wasn't closed.
+///
+/// Curves are approximated by lines, so values are not exact.
+/// Contour orientation is reported in font coordinates, i.e. with the Y axis pointing up.
+/// TrueType fonts use clockwise outer contours, while CFF ones use counter-clockwise ones.
+#[derive(Clone, Copy, Debug)]
+pub struct PathStats {
+    /// Total length of all contours.
+    pub length: f32,
+    /// Total signed area of all contours.
+    ///
+    /// Positive for counter-clockwise contours and negative for clockwise ones.
+    pub area: f32,
+    /// Number of contours.
+    pub contours: u16,
+    /// Number of clockwise contours.
+    pub clockwise_contours: u16,
+    /// Number of counter-clockwise contours.
+    ///
+    /// Contours with a zero area are neither clockwise nor counter-clockwise.
+    pub counter_clockwise_contours: u16,
+    start: PointF,
+    last: PointF,
+    contour_area: f32,
+    is_open: bool,
+}
+
+impl PathStats {
+    /// Number of lines each curve is approximated with.
+    const CURVE_STEPS: u8 = 16;
+
+    /// Creates empty stats.
+    pub fn new() -> Self {
+        PathStats {
+            length: 0.0,
+            area: 0.0,
+            contours: 0,
+            clockwise_contours: 0,
+            counter_clockwise_contours: 0,
+            start: PointF { x: 0.0, y: 0.0 },
+            last: PointF { x: 0.0, y: 0.0 },
+            contour_area: 0.0,
+            is_open: false,
+        }
+    }
+
+    fn add_line(&mut self, p: PointF) {
+        let dx = p.x - self.last.x;
+        let dy = p.y - self.last.y;
+        self.length += (dx * dx + dy * dy).sqrt();
+        // Shoelace formula.
+        self.contour_area += (self.last.x * p.y - p.x * self.last.y) * 0.5;
+        self.last = p;
+    }
+
+    /// Counts the current contour, if any, as if it was closed.
+    ///
+    /// Outlines are not required to close their last contour,
+    /// so must be called after the outline was fully built.
+    pub fn finish(&mut self) {
+        if !self.is_open {
+            return;
+        }
+
+        let start = self.start;
+        self.add_line(start);
+        self.is_open = false;
+
+        self.area += self.contour_area;
+        self.contours = self.contours.saturating_add(1);
+        if self.contour_area < 0.0 {
+            self.clockwise_contours = self.clockwise_contours.saturating_add(1);
+        } else if self.contour_area > 0.0 {
+            self.counter_clockwise_contours = self.counter_clockwise_contours.saturating_add(1);
+        }
+    }
+}
+
+impl Default for PathStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutlineBuilder for PathStats {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.finish();
+        self.start = PointF { x, y };
+        self.last = self.start;
+        self.contour_area = 0.0;
+        self.is_open = true;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.add_line(PointF { x, y });
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let p0 = self.last;
+        for i in 1..=Self::CURVE_STEPS {
+            let t = f32::from(i) / f32::from(Self::CURVE_STEPS);
+            let mt = 1.0 - t;
+            self.add_line(PointF {
+                x: mt * mt * p0.x + 2.0 * mt * t * x1 + t * t * x,
+                y: mt * mt * p0.y + 2.0 * mt * t * y1 + t * t * y,
+            });
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let p0 = self.last;
+        for i in 1..=Self::CURVE_STEPS {
+            let t = f32::from(i) / f32::from(Self::CURVE_STEPS);
+            let mt = 1.0 - t;
+            let (a, b, c, d) = (mt * mt * mt, 3.0 * mt * mt * t, 3.0 * mt * t * t, t * t * t);
+            self.add_line(PointF {
+                x: a * p0.x + b * x1 + c * x2 + d * x,
+                y: a * p0.y + b * y1 + c * y2 + d * y,
+            });
+        }
+    }
+
+    fn close(&mut self) {
+        self.finish();
+    }
+}
+
+/// An outline builder adapter that approximates curves with lines.
+///
+/// Quadratic and cubic curves are recursively subdivided until each part
+/// deviates from a straight line by no more than `tolerance`,
+/// after which only `line_to` segments are forwarded to the inner builder.
+/// `move_to`, `line_to` and `close` are forwarded unchanged.
+///
+/// Useful for consumers that can handle only polylines.
+pub struct FlatteningBuilder<'a> {
+    builder: &'a mut dyn OutlineBuilder,
+    tolerance: f32,
+    last: PointF,
+}
+
+impl<'a> FlatteningBuilder<'a> {
+    /// Maximum number of subdivisions per curve.
+    ///
+    /// Prevents an endless recursion on a zero or NaN tolerance.
+    const MAX_DEPTH: u8 = 16;
+
+    /// Creates a new adapter.
+    ///
+    /// `tolerance` is the maximum allowed distance between a curve and its approximation,
+    /// in font units.
+    pub fn new(builder: &'a mut dyn OutlineBuilder, tolerance: f32) -> Self {
+        FlatteningBuilder {
+            builder,
+            tolerance,
+            last: PointF { x: 0.0, y: 0.0 },
+        }
+    }
+
+    fn is_flat(&self, dx: f32, dy: f32) -> bool {
+        dx * dx + dy * dy <= self.tolerance * self.tolerance
+    }
+
+    fn flatten_quad(&mut self, p0: PointF, p1: PointF, p2: PointF, depth: u8) {
+        // The maximum distance between a quadratic curve and its chord
+        // is a quarter of the second difference.
+        let dx = (p0.x - 2.0 * p1.x + p2.x) * 0.25;
+        let dy = (p0.y - 2.0 * p1.y + p2.y) * 0.25;
+        if depth == Self::MAX_DEPTH || self.is_flat(dx, dy) {
+            self.builder.line_to(p2.x, p2.y);
+            return;
+        }
+
+        let p01 = mid(p0, p1);
+        let p12 = mid(p1, p2);
+        let p012 = mid(p01, p12);
+        self.flatten_quad(p0, p01, p012, depth + 1);
+        self.flatten_quad(p012, p12, p2, depth + 1);
+    }
+
+    fn flatten_curve(&mut self, p0: PointF, p1: PointF, p2: PointF, p3: PointF, depth: u8) {
+        // An upper bound of the distance between a cubic curve and its chord.
+        let dx = f32::max(
+            (p0.x - 2.0 * p1.x + p2.x).abs(),
+            (p1.x - 2.0 * p2.x + p3.x).abs(),
+        ) * 0.75;
+        let dy = f32::max(
+            (p0.y - 2.0 * p1.y + p2.y).abs(),
+            (p1.y - 2.0 * p2.y + p3.y).abs(),
+        ) * 0.75;
+        if depth == Self::MAX_DEPTH || self.is_flat(dx, dy) {
+            self.builder.line_to(p3.x, p3.y);
+            return;
+        }
+
+        let p01 = mid(p0, p1);
+        let p12 = mid(p1, p2);
+        let p23 = mid(p2, p3);
+        let p012 = mid(p01, p12);
+        let p123 = mid(p12, p23);
+        let p0123 = mid(p012, p123);
+        self.flatten_curve(p0, p01, p012, p0123, depth + 1);
+        self.flatten_curve(p0123, p123, p23, p3, depth + 1);
+    }
+}
+
+#[inline]
+fn mid(a: PointF, b: PointF) -> PointF {
+    PointF {
+        x: (a.x + b.x) * 0.5,
+        y: (a.y + b.y) * 0.5,
+    }
+}
+
+impl core::fmt::Debug for FlatteningBuilder<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "FlatteningBuilder {{ ... }}")
+    }
+}
+
+impl OutlineBuilder for FlatteningBuilder<'_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.last = PointF { x, y };
+        self.builder.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.last = PointF { x, y };
+        self.builder.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let p0 = self.last;
+        self.flatten_quad(p0, PointF { x: x1, y: y1 }, PointF { x, y }, 0);
+        self.last = PointF { x, y };
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let p0 = self.last;
+        self.flatten_curve(
+            p0,
+            PointF { x: x1, y: y1 },
+            PointF { x: x2, y: y2 },
+            PointF { x, y },
+            0,
+        );
+        self.last = PointF { x, y };
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+    }
+}
+
+/// An outline builder adapter that converts quadratic curves into cubic ones.
+///
+/// Each `quad_to` is forwarded as an equivalent `curve_to` via degree elevation,
+/// so the resulting outline is exactly the same.
+/// All other segments are forwarded unchanged.
+///
+/// Useful for consumers that can handle only cubic curves, like PDF or PostScript.
+pub struct QuadToCubicBuilder<'a> {
+    builder: &'a mut dyn OutlineBuilder,
+    last: PointF,
+}
+
+impl<'a> QuadToCubicBuilder<'a> {
+    /// Creates a new adapter.
+    pub fn new(builder: &'a mut dyn OutlineBuilder) -> Self {
+        QuadToCubicBuilder {
+            builder,
+            last: PointF { x: 0.0, y: 0.0 },
+        }
+    }
+}
+
+impl core::fmt::Debug for QuadToCubicBuilder<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "QuadToCubicBuilder {{ ... }}")
+    }
+}
+
+impl OutlineBuilder for QuadToCubicBuilder<'_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.last = PointF { x, y };
+        self.builder.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.last = PointF { x, y };
+        self.builder.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let p0 = self.last;
+        // Control points are 2/3 of the way from the end points to the quadratic control point.
+        self.builder.curve_to(
+            p0.x + (x1 - p0.x) * (2.0 / 3.0),
+            p0.y + (y1 - p0.y) * (2.0 / 3.0),
+            x + (x1 - x) * (2.0 / 3.0),
+            y + (y1 - y) * (2.0 / 3.0),
+            x,
+            y,
+        );
+        self.last = PointF { x, y };
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.last = PointF { x, y };
+        self.builder.curve_to(x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+    }
+}
+
+pub(crate) struct DummyOutline;
+impl OutlineBuilder for DummyOutline {
+    fn move_to(&mut self, _: f32, _: f32) {}
+    fn line_to(&mut self, _: f32, _: f32) {}
+    fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {}
+    fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {}
+    fn close(&mut self) {}
+}
+
+/// Limits used by [`Face::outline_glyph_with_limits`](crate::Face::outline_glyph_with_limits).
+///
+/// The built-in limits are always applied, so these can only make them stricter.
+/// The default value doesn't add any restrictions.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OutlineLimits {
+    /// The maximum nesting depth.
+    ///
+    /// Composite glyphs nesting for `glyf` and subroutines nesting for `CFF`.
+    /// 0 means no composite glyphs or subroutine calls at all.
+    ///
+    /// The built-in limit is 31 for `glyf` and 10 for `CFF`.
+    pub max_depth: u8,
+
+    /// The maximum number of points passed to the builder.
+    ///
+    /// Includes control points. Each `move_to` and `line_to` call is one point,
+    /// `quad_to` is two and `curve_to` is three.
+    pub max_points: u32,
+
+    /// The maximum number of segments passed to the builder.
+    ///
+    /// Each `OutlineBuilder` method call, including `move_to` and `close`, is a segment.
+    ///
+    /// Like `max_points`, this limits only the builder output. The glyph is still parsed
+    /// to the end, which is bounded by the glyph data size and the nesting depth.
+    pub max_segments: u32,
+}
+
+impl Default for OutlineLimits {
+    #[inline]
+    fn default() -> Self {
+        OutlineLimits {
+            max_depth: u8::MAX,
+            max_points: u32::MAX,
+            max_segments: u32::MAX,
+        }
+    }
+}
+
+/// An outline builder that stops forwarding segments after a limit was reached.
+///
+/// The parser isn't notified, so the remaining segments are still produced and dropped.
+pub(crate) struct LimitedBuilder<'a> {
+    builder: &'a mut dyn OutlineBuilder,
+    segments_left: u32,
+    points_left: u32,
+    pub exceeded: bool,
+}
+
+impl<'a> LimitedBuilder<'a> {
+    #[inline]
+    pub fn new(builder: &'a mut dyn OutlineBuilder, limits: OutlineLimits) -> Self {
+        LimitedBuilder {
+            builder,
+            segments_left: limits.max_segments,
+            points_left: limits.max_points,
+            exceeded: false,
+        }
+    }
+
+    #[inline]
+    fn take_segment(&mut self, points: u32) -> bool {
+        if self.exceeded || self.segments_left == 0 || self.points_left < points {
+            self.exceeded = true;
+            return false;
+        }
+
+        self.segments_left -= 1;
+        self.points_left -= points;
+        true
+    }
+}
+
+impl OutlineBuilder for LimitedBuilder<'_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        if self.take_segment(1) {
+            self.builder.move_to(x, y);
+        }
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        if self.take_segment(1) {
+            self.builder.line_to(x, y);
+        }
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        if self.take_segment(2) {
+            self.builder.quad_to(x1, y1, x, y);
+        }
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        if self.take_segment(3) {
+            self.builder.curve_to(x1, y1, x2, y2, x, y);
+        }
+    }
+
+    fn close(&mut self) {
+        if self.take_segment(0) {
+            self.builder.close();
+        }
+    }
+}
+
+/// An outline builder that reports contour boundaries to a [`ContourBuilder`].
+pub(crate) struct IndexedBuilder<'a> {
+    builder: &'a mut dyn ContourBuilder,
+    index: u16,
+    is_open: bool,
+}
+
+impl<'a> IndexedBuilder<'a> {
+    #[inline]
+    pub fn new(builder: &'a mut dyn ContourBuilder) -> Self {
+        IndexedBuilder {
+            builder,
+            index: 0,
+            is_open: false,
+        }
+    }
+
+    #[inline]
+    pub fn end_contour(&mut self) {
+        if self.is_open {
+            self.builder.end_contour(self.index);
+            self.index = self.index.wrapping_add(1);
+            self.is_open = false;
+        }
+    }
+}
+
+impl OutlineBuilder for IndexedBuilder<'_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        // A contour may not be closed explicitly.
+        self.end_contour();
+        self.builder.begin_contour(self.index);
+        self.is_open = true;
+        self.builder.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.builder.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.builder.quad_to(x1, y1, x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.builder.curve_to(x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+        self.end_contour();
+    }
+}
+
+/// Glyph outline complexity metrics.
+///
+/// Points are counted as passed to [`OutlineBuilder`], not as stored in the font.
+/// For example, a `glyf` contour is closed by an explicit line to its first point
+/// and implied on-curve points between two off-curve ones are counted as well.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct GlyphPointCounts {
+    /// The number of contours.
+    pub contours: u32,
+    /// The number of on-curve points.
+    pub on_curve_points: u32,
+    /// The number of off-curve (control) points.
+    pub off_curve_points: u32,
+}
+
+impl OutlineBuilder for GlyphPointCounts {
+    #[inline]
+    fn move_to(&mut self, _: f32, _: f32) {
+        self.contours = self.contours.saturating_add(1);
+        self.on_curve_points = self.on_curve_points.saturating_add(1);
+    }
+
+    #[inline]
+    fn line_to(&mut self, _: f32, _: f32) {
+        self.on_curve_points = self.on_curve_points.saturating_add(1);
+    }
+
+    #[inline]
+    fn quad_to(&mut self, _: f32, _: f32, _: f32, _: f32) {
+        self.on_curve_points = self.on_curve_points.saturating_add(1);
+        self.off_curve_points = self.off_curve_points.saturating_add(1);
+    }
+
+    #[inline]
+    fn curve_to(&mut self, _: f32, _: f32, _: f32, _: f32, _: f32, _: f32) {
+        self.on_curve_points = self.on_curve_points.saturating_add(1);
+        self.off_curve_points = self.off_curve_points.saturating_add(2);
+    }
+
+    #[inline]
+    fn close(&mut self) {}
+}
+
+/// An outline builder that calculates a bounding box using curves extrema
+/// instead of control points.
+pub(crate) struct PreciseBBoxBuilder {
+    bbox: RectF,
+    last: PointF,
+}
+
+impl PreciseBBoxBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        PreciseBBoxBuilder {
+            bbox: RectF::new(),
+            last: PointF { x: 0.0, y: 0.0 },
+        }
+    }
+
+    pub fn to_rect(&self) -> Option<Rect> {
+        if self.bbox.is_default() {
+            return None;
+        }
+
+        // Make sure that the bbox still encloses the outline after the conversion.
+        RectF {
+            x_min: self.bbox.x_min.floor(),
+            y_min: self.bbox.y_min.floor(),
+            x_max: self.bbox.x_max.ceil(),
+            y_max: self.bbox.y_max.ceil(),
+        }
+        .to_rect()
+    }
+}
+
+impl OutlineBuilder for PreciseBBoxBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.bbox.extend_by(x, y);
+        self.last = PointF { x, y };
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.bbox.extend_by(x, y);
+        self.last = PointF { x, y };
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let p0 = self.last;
+        let quad_at = |t: f32| {
+            let mt = 1.0 - t;
+            PointF {
+                x: mt * mt * p0.x + 2.0 * mt * t * x1 + t * t * x,
+                y: mt * mt * p0.y + 2.0 * mt * t * y1 + t * t * y,
+            }
+        };
+
+        // The derivative is zero at `t = (p0 - p1) / (p0 - 2 * p1 + p2)`.
+        for (a, b, c) in [(p0.x, x1, x), (p0.y, y1, y)] {
+            let denom = a - 2.0 * b + c;
+            if denom != 0.0 {
+                let t = (a - b) / denom;
+                if t > 0.0 && t < 1.0 {
+                    let p = quad_at(t);
+                    self.bbox.extend_by(p.x, p.y);
+                }
+            }
+        }
+
+        self.bbox.extend_by(x, y);
+        self.last = PointF { x, y };
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let p0 = self.last;
+        let cubic_at = |t: f32| {
+            let mt = 1.0 - t;
+            let (k0, k1, k2, k3) = (mt * mt * mt, 3.0 * mt * mt * t, 3.0 * mt * t * t, t * t * t);
+            PointF {
+                x: k0 * p0.x + k1 * x1 + k2 * x2 + k3 * x,
+                y: k0 * p0.y + k1 * y1 + k2 * y2 + k3 * y,
+            }
+        };
+
+        // The derivative is a quadratic polynomial `a * t^2 + b * t + c`.
+        for (p0, p1, p2, p3) in [(p0.x, x1, x2, x), (p0.y, y1, y2, y)] {
+            let a = -p0 + 3.0 * p1 - 3.0 * p2 + p3;
+            let b = 2.0 * (p0 - 2.0 * p1 + p2);
+            let c = p1 - p0;
+
+            let mut roots = [f32::NAN; 2];
+            if a == 0.0 {
+                if b != 0.0 {
+                    roots[0] = -c / b;
+                }
+            } else {
+                let discriminant = b * b - 4.0 * a * c;
+                if discriminant >= 0.0 {
+                    let sqrt = discriminant.sqrt();
+                    roots[0] = (-b + sqrt) / (2.0 * a);
+                    roots[1] = (-b - sqrt) / (2.0 * a);
+                }
+            }
+
+            // NaN roots are ignored by the range check.
+            for t in roots {
+                if t > 0.0 && t < 1.0 {
+                    let p = cubic_at(t);
+                    self.bbox.extend_by(p.x, p.y);
+                }
+            }
+        }
+
+        self.bbox.extend_by(x, y);
+        self.last = PointF { x, y };
+    }
+
+    fn close(&mut self) {}
+}
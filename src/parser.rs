@@ -118,7 +118,7 @@ impl FromData for U24 {
 }
 
 /// A 16-bit signed fixed number with the low 14 bits of fraction (2.14).
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct F2DOT14(pub i16);
 
 impl F2DOT14 {
@@ -130,7 +130,7 @@ impl F2DOT14 {
 
     #[cfg(feature = "variable-fonts")]
     #[inline]
-    pub fn apply_float_delta(&self, delta: f32) -> f32 {
+    pub(crate) fn apply_float_delta(&self, delta: f32) -> f32 {
         self.to_f32() + (delta as f64 * (1.0 / 16384.0)) as f32
     }
 }
@@ -280,6 +280,8 @@ impl<'a, T: FromData> LazyArray16<'a, T> {
     }
 
     /// Returns a value at `index`.
+    ///
+    /// Returns `None` when `index` is out of bounds or the value cannot be parsed.
     #[inline]
     pub fn get(&self, index: u16) -> Option<T> {
         if index < self.len() {
@@ -325,6 +327,10 @@ impl<'a, T: FromData> LazyArray16<'a, T> {
     }
 
     /// Performs a binary search by specified `key`.
+    ///
+    /// The array must be sorted, otherwise the result is unspecified.
+    ///
+    /// Returns the index and the value of a matching element.
     #[inline]
     pub fn binary_search(&self, key: &T) -> Option<(u16, T)>
     where
@@ -334,6 +340,11 @@ impl<'a, T: FromData> LazyArray16<'a, T> {
     }
 
     /// Performs a binary search using specified closure.
+    ///
+    /// The closure must return an ordering of an element relative to the target,
+    /// and the array must be sorted accordingly, otherwise the result is unspecified.
+    ///
+    /// Returns the index and the value of a matching element.
     #[inline]
     pub fn binary_search_by<F>(&self, mut f: F) -> Option<(u16, T)>
     where
@@ -452,6 +463,8 @@ impl<'a, T: FromData> LazyArray32<'a, T> {
     }
 
     /// Returns a value at `index`.
+    ///
+    /// Returns `None` when `index` is out of bounds or the value cannot be parsed.
     #[inline]
     pub fn get(&self, index: u32) -> Option<T> {
         if index < self.len() {
@@ -475,6 +488,10 @@ impl<'a, T: FromData> LazyArray32<'a, T> {
     }
 
     /// Performs a binary search by specified `key`.
+    ///
+    /// The array must be sorted, otherwise the result is unspecified.
+    ///
+    /// Returns the index and the value of a matching element.
     #[inline]
     pub fn binary_search(&self, key: &T) -> Option<(u32, T)>
     where
@@ -484,6 +501,11 @@ impl<'a, T: FromData> LazyArray32<'a, T> {
     }
 
     /// Performs a binary search using specified closure.
+    ///
+    /// The closure must return an ordering of an element relative to the target,
+    /// and the array must be sorted accordingly, otherwise the result is unspecified.
+    ///
+    /// Returns the index and the value of a matching element.
     #[inline]
     pub fn binary_search_by<F>(&self, mut f: F) -> Option<(u32, T)>
     where
@@ -666,6 +688,26 @@ impl<'a, T: FromSlice<'a>> Iterator for LazyOffsetArrayIter16<'a, T> {
 }
 
 /// A streaming binary parser.
+///
+/// All values are read as big-endian. Reading past the end of the data returns `None`.
+///
+/// Can be used to parse tables not supported by this crate.
+///
+/// # Example
+///
+/// ```
+/// use ttf_parser::{LazyArray16, Stream};
+///
+/// // A hypothetical table with a sorted list of glyph IDs.
+/// let data = [0x00, 0x03, 0x00, 0x05, 0x00, 0x07, 0x00, 0x0A];
+/// let mut s = Stream::new(&data);
+/// let count = s.read::<u16>().unwrap();
+/// let glyphs: LazyArray16<u16> = s.read_array16(count).unwrap();
+/// assert!(s.at_end());
+/// assert_eq!(glyphs.get(1), Some(7));
+/// assert_eq!(glyphs.binary_search(&10), Some((2, 10)));
+/// assert_eq!(glyphs.binary_search_by(|g| g.cmp(&6)), None);
+/// ```
 #[derive(Clone, Default, Debug)]
 pub struct Stream<'a> {
     data: &'a [u8],
@@ -729,16 +771,16 @@ impl<'a> Stream<'a> {
 
     /// Advances by the specified `len`.
     ///
-    /// Doesn't check bounds.
+    /// Doesn't check bounds. Reads past the end of the data will fail.
     #[inline]
     pub fn advance(&mut self, len: usize) {
-        self.offset += len;
+        self.offset = self.offset.saturating_add(len);
     }
 
     /// Advances by the specified `len` and checks for bounds.
     #[inline]
     pub fn advance_checked(&mut self, len: usize) -> Option<()> {
-        if self.offset + len <= self.data.len() {
+        if self.offset.checked_add(len)? <= self.data.len() {
             self.advance(len);
             Some(())
         } else {
@@ -755,21 +797,24 @@ impl<'a> Stream<'a> {
         self.read_bytes(T::SIZE).and_then(T::parse)
     }
 
+    /// Parses an [`F2DOT14`] from the steam and converts it to `f32`.
+    #[inline]
+    pub fn read_f2dot14(&mut self) -> Option<f32> {
+        self.read::<F2DOT14>().map(F2DOT14::to_f32)
+    }
+
     /// Parses the type from the steam at offset.
     #[inline]
     pub fn read_at<T: FromData>(data: &[u8], offset: usize) -> Option<T> {
-        data.get(offset..offset + T::SIZE).and_then(T::parse)
+        data.get(offset..offset.checked_add(T::SIZE)?)
+            .and_then(T::parse)
     }
 
     /// Reads N bytes from the stream.
     #[inline]
     pub fn read_bytes(&mut self, len: usize) -> Option<&'a [u8]> {
-        // An integer overflow here on 32bit systems is almost guarantee to be caused
-        // by an incorrect parsing logic from the caller side.
-        // Simply using `checked_add` here would silently swallow errors, which is not what we want.
-        debug_assert!(self.offset as u64 + len as u64 <= u32::MAX as u64);
-
-        let v = self.data.get(self.offset..self.offset + len)?;
+        let end = self.offset.checked_add(len)?;
+        let v = self.data.get(self.offset..end)?;
         self.advance(len);
         Some(v)
     }
@@ -784,11 +829,13 @@ impl<'a> Stream<'a> {
     /// Reads the next `count` types as a slice.
     #[inline]
     pub fn read_array32<T: FromData>(&mut self, count: u32) -> Option<LazyArray32<'a, T>> {
-        let len = usize::num_from(count) * T::SIZE;
+        let len = usize::num_from(count).checked_mul(T::SIZE)?;
         self.read_bytes(len).map(LazyArray32::new)
     }
 
-    #[allow(dead_code)]
+    /// Reads an `Offset16` and returns `data` starting at it.
+    ///
+    /// Returns `None` when the offset is out of `data` bounds.
     #[inline]
     pub fn read_at_offset16(&mut self, data: &'a [u8]) -> Option<&'a [u8]> {
         let offset = self.read::<Offset16>()?.to_usize();
@@ -924,3 +971,32 @@ pub fn f32_bound(min: f32, val: f32, max: f32) -> f32 {
 
     val
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f2dot14() {
+        let data = [0xC0, 0x00, 0x00, 0x00, 0x40, 0x00, 0x7F, 0xFF, 0x80, 0x00];
+        let mut s = Stream::new(&data);
+        assert_eq!(s.read_f2dot14(), Some(-1.0));
+        assert_eq!(s.read_f2dot14(), Some(0.0));
+        assert_eq!(s.read_f2dot14(), Some(1.0));
+        assert_eq!(s.read_f2dot14(), Some(1.999939));
+        assert_eq!(s.read_f2dot14(), Some(-2.0));
+        assert_eq!(s.read_f2dot14(), None);
+    }
+
+    #[test]
+    fn fixed() {
+        let data = [
+            0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        ];
+        let mut s = Stream::new(&data);
+        assert_eq!(s.read::<Fixed>().map(|n| n.0), Some(-1.0));
+        assert_eq!(s.read::<Fixed>().map(|n| n.0), Some(0.0));
+        assert_eq!(s.read::<Fixed>().map(|n| n.0), Some(1.0));
+        assert!(s.read::<Fixed>().is_none());
+    }
+}
@@ -0,0 +1,100 @@
+//! A minimal TrueType/OpenType font writer used by WOFF decoders.
+
+use core::convert::TryFrom;
+use std::borrow::Cow;
+use std::vec::Vec;
+
+use crate::Tag;
+
+const HEADER_SIZE: usize = 12;
+const TABLE_RECORD_SIZE: usize = 16;
+
+pub(crate) struct Table<'a> {
+    pub tag: Tag,
+    pub checksum: u32,
+    pub data: Cow<'a, [u8]>,
+}
+
+#[cfg(feature = "woff2")]
+impl<'a> Table<'a> {
+    /// Creates a new table with a calculated checksum.
+    pub fn new(tag: Tag, data: Cow<'a, [u8]>) -> Self {
+        Table {
+            tag,
+            checksum: checksum(&data),
+            data,
+        }
+    }
+}
+
+/// Writes a single font with the specified tables.
+///
+/// Tables will be sorted by tag, as required by the spec.
+pub(crate) fn write(flavor: u32, tables: &mut [Table]) -> Option<Vec<u8>> {
+    tables.sort_by_key(|table| table.tag);
+
+    let num_tables = u16::try_from(tables.len()).ok()?;
+    let mut data_len = HEADER_SIZE + tables.len() * TABLE_RECORD_SIZE;
+    for table in tables.iter() {
+        data_len = data_len.checked_add(padded_len(table.data.len())?)?;
+    }
+
+    // Must be addressable via 32-bit offsets.
+    u32::try_from(data_len).ok()?;
+
+    let mut data = Vec::with_capacity(data_len);
+    write_header(&mut data, flavor, num_tables);
+
+    let mut offset = HEADER_SIZE + tables.len() * TABLE_RECORD_SIZE;
+    for table in tables.iter() {
+        data.extend_from_slice(&table.tag.to_bytes());
+        data.extend_from_slice(&table.checksum.to_be_bytes());
+        data.extend_from_slice(&(offset as u32).to_be_bytes());
+        data.extend_from_slice(&(table.data.len() as u32).to_be_bytes());
+        offset += padded_len(table.data.len())?;
+    }
+
+    for table in tables.iter() {
+        data.extend_from_slice(&table.data);
+        // Tables are 4-byte aligned.
+        while data.len() % 4 != 0 {
+            data.push(0);
+        }
+    }
+
+    Some(data)
+}
+
+fn write_header(data: &mut Vec<u8>, flavor: u32, num_tables: u16) {
+    // The largest power of two less than or equal to `num_tables`.
+    let mut entry_selector = 0u16;
+    while u32::from(num_tables) >> (entry_selector + 1) != 0 {
+        entry_selector += 1;
+    }
+
+    let search_range = (1u16 << entry_selector).wrapping_mul(16);
+    let range_shift = num_tables.wrapping_mul(16).wrapping_sub(search_range);
+
+    data.extend_from_slice(&flavor.to_be_bytes());
+    data.extend_from_slice(&num_tables.to_be_bytes());
+    data.extend_from_slice(&search_range.to_be_bytes());
+    data.extend_from_slice(&entry_selector.to_be_bytes());
+    data.extend_from_slice(&range_shift.to_be_bytes());
+}
+
+fn padded_len(len: usize) -> Option<usize> {
+    len.checked_add(3).map(|n| n & !3)
+}
+
+/// Calculates a table checksum.
+#[cfg(feature = "woff2")]
+fn checksum(data: &[u8]) -> u32 {
+    let mut sum = 0u32;
+    for chunk in data.chunks(4) {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum = sum.wrapping_add(u32::from_be_bytes(word));
+    }
+
+    sum
+}
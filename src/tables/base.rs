@@ -0,0 +1,271 @@
+//! A [Baseline Table](https://docs.microsoft.com/en-us/typography/opentype/spec/base)
+//! implementation.
+
+use core::convert::TryFrom;
+
+use crate::gpos::Device;
+use crate::parser::{FromData, LazyArray16, Offset, Offset16, Stream};
+use crate::{GlyphId, Tag};
+
+#[cfg(feature = "variable-fonts")]
+use crate::parser::Offset32;
+#[cfg(feature = "variable-fonts")]
+use crate::var_store::ItemVariationStore;
+#[cfg(feature = "variable-fonts")]
+use crate::NormalizedCoordinate;
+
+/// A [Baseline Coordinate](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/base#basecoord-tables).
+#[derive(Clone, Copy, Debug)]
+pub struct BaseCoord<'a> {
+    /// A coordinate in font design units.
+    pub coordinate: i16,
+    /// A glyph and its contour point index, which adjust the coordinate.
+    ///
+    /// Set only for format 2.
+    pub reference_point: Option<(GlyphId, u16)>,
+    /// Device corrections for this coordinate.
+    ///
+    /// Set only for format 3.
+    pub device: Option<Device<'a>>,
+}
+
+impl<'a> BaseCoord<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let format = s.read::<u16>()?;
+        let coordinate = s.read::<i16>()?;
+        let mut coord = BaseCoord {
+            coordinate,
+            reference_point: None,
+            device: None,
+        };
+
+        match format {
+            1 => {}
+            2 => {
+                let glyph_id = s.read::<GlyphId>()?;
+                let point = s.read::<u16>()?;
+                coord.reference_point = Some((glyph_id, point));
+            }
+            3 => {
+                coord.device = s
+                    .read::<Option<Offset16>>()?
+                    .and_then(|offset| data.get(offset.to_usize()..))
+                    .and_then(Device::parse);
+            }
+            _ => return None,
+        }
+
+        Some(coord)
+    }
+}
+
+/// A list of script's baseline coordinates.
+///
+/// Coordinates are stored in the same order as [`Axis::baseline_tags`].
+#[derive(Clone, Copy)]
+pub struct BaseValues<'a> {
+    /// An index of the script's default baseline in [`Axis::baseline_tags`].
+    pub default_baseline_index: u16,
+    data: &'a [u8],
+    offsets: LazyArray16<'a, Offset16>,
+}
+
+impl<'a> BaseValues<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let default_baseline_index = s.read::<u16>()?;
+        let count = s.read::<u16>()?;
+        let offsets = s.read_array16::<Offset16>(count)?;
+        Some(BaseValues {
+            default_baseline_index,
+            data,
+            offsets,
+        })
+    }
+
+    /// Returns a baseline coordinate at an index.
+    pub fn get(&self, index: u16) -> Option<BaseCoord<'a>> {
+        let offset = self.offsets.get(index)?;
+        BaseCoord::parse(self.data.get(offset.to_usize()..)?)
+    }
+
+    /// Returns the number of coordinates.
+    pub fn len(&self) -> u16 {
+        self.offsets.len()
+    }
+
+    /// Checks if there are any coordinates.
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+}
+
+impl core::fmt::Debug for BaseValues<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "BaseValues {{ ... }}")
+    }
+}
+
+#[derive(Clone, Copy)]
+struct BaseScriptRecord {
+    tag: Tag,
+    offset: Offset16,
+}
+
+impl FromData for BaseScriptRecord {
+    const SIZE: usize = 6;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(BaseScriptRecord {
+            tag: s.read::<Tag>()?,
+            offset: s.read::<Offset16>()?,
+        })
+    }
+}
+
+/// A baseline [Axis](https://docs.microsoft.com/en-us/typography/opentype/spec/base#axis-tables).
+#[derive(Clone, Copy)]
+pub struct Axis<'a> {
+    /// A list of baseline tags, like `romn`, `ideo` or `hang`.
+    pub baseline_tags: LazyArray16<'a, Tag>,
+    scripts_data: &'a [u8],
+    scripts: LazyArray16<'a, BaseScriptRecord>,
+}
+
+impl<'a> Axis<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let tags_offset = s.read::<Option<Offset16>>()?;
+        let scripts_offset = s.read::<Offset16>()?;
+
+        let baseline_tags = match tags_offset {
+            Some(offset) => {
+                let mut s = Stream::new_at(data, offset.to_usize())?;
+                let count = s.read::<u16>()?;
+                s.read_array16::<Tag>(count)?
+            }
+            None => LazyArray16::default(),
+        };
+
+        let scripts_data = data.get(scripts_offset.to_usize()..)?;
+        let mut s = Stream::new(scripts_data);
+        let count = s.read::<u16>()?;
+        let scripts = s.read_array16::<BaseScriptRecord>(count)?;
+
+        Some(Axis {
+            baseline_tags,
+            scripts_data,
+            scripts,
+        })
+    }
+
+    /// Returns script's baseline coordinates.
+    ///
+    /// Returns `None` when the script is not present or has no baseline coordinates.
+    pub fn script_values(&self, script: Tag) -> Option<BaseValues<'a>> {
+        let record = self
+            .scripts
+            .binary_search_by(|record| record.tag.cmp(&script))
+            .map(|p| p.1)?;
+        let data = self.scripts_data.get(record.offset.to_usize()..)?;
+        let offset = Stream::read_at::<Option<Offset16>>(data, 0)??;
+        BaseValues::parse(data.get(offset.to_usize()..)?)
+    }
+
+    /// Returns script's baseline coordinate.
+    ///
+    /// Falls back to the `DFLT` script when `script` is not present.
+    pub fn baseline(&self, script: Tag, baseline: Tag) -> Option<BaseCoord<'a>> {
+        let index = self
+            .baseline_tags
+            .into_iter()
+            .position(|tag| tag == baseline)?;
+        let values = self
+            .script_values(script)
+            .or_else(|| self.script_values(Tag::from_bytes(b"DFLT")))?;
+        values.get(u16::try_from(index).ok()?)
+    }
+}
+
+impl core::fmt::Debug for Axis<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Axis {{ ... }}")
+    }
+}
+
+/// A [Baseline Table](https://docs.microsoft.com/en-us/typography/opentype/spec/base).
+#[derive(Clone, Copy)]
+pub struct Table<'a> {
+    /// Baselines for horizontal text.
+    pub horizontal_axis: Option<Axis<'a>>,
+    /// Baselines for vertical text.
+    pub vertical_axis: Option<Axis<'a>>,
+    #[cfg(feature = "variable-fonts")]
+    variation_store: Option<ItemVariationStore<'a>>,
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        let major_version = s.read::<u16>()?;
+        let minor_version = s.read::<u16>()?;
+        if major_version != 1 {
+            return None;
+        }
+
+        let horizontal_axis = s
+            .read::<Option<Offset16>>()?
+            .and_then(|offset| data.get(offset.to_usize()..))
+            .and_then(Axis::parse);
+        let vertical_axis = s
+            .read::<Option<Offset16>>()?
+            .and_then(|offset| data.get(offset.to_usize()..))
+            .and_then(Axis::parse);
+
+        #[cfg(not(feature = "variable-fonts"))]
+        let _ = minor_version;
+
+        #[cfg(feature = "variable-fonts")]
+        let variation_store = if minor_version > 0 {
+            s.read::<Option<Offset32>>()
+                .flatten()
+                .and_then(|offset| Stream::new_at(data, offset.to_usize()))
+                .and_then(ItemVariationStore::parse)
+        } else {
+            None
+        };
+
+        Some(Table {
+            horizontal_axis,
+            vertical_axis,
+            #[cfg(feature = "variable-fonts")]
+            variation_store,
+        })
+    }
+
+    /// Returns a variation delta at a specified index according to
+    /// [Item Variation Store Table](
+    /// https://docs.microsoft.com/en-us/typography/opentype/spec/base#base-header).
+    #[cfg(feature = "variable-fonts")]
+    #[inline]
+    pub fn variation_delta(
+        &self,
+        outer_index: u16,
+        inner_index: u16,
+        coordinates: &[NormalizedCoordinate],
+    ) -> Option<f32> {
+        self.variation_store
+            .and_then(|store| store.parse_delta(outer_index, inner_index, coordinates))
+    }
+}
+
+impl core::fmt::Debug for Table<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Table {{ ... }}")
+    }
+}
@@ -1,6 +1,8 @@
 //! A [Color Bitmap Data Table](
 //! https://docs.microsoft.com/en-us/typography/opentype/spec/cbdt) implementation.
 
+use core::convert::TryFrom;
+
 use crate::cblc::{self, BitmapDataFormat, Metrics, MetricsFormat};
 use crate::parser::{NumFrom, Stream};
 use crate::{GlyphId, RasterGlyphImage, RasterImageFormat};
@@ -21,7 +23,15 @@ impl<'a> Table<'a> {
         Some(Self { locations, data })
     }
 
+    /// Returns an iterator over strikes' pixels per em from the location table.
+    #[inline]
+    pub fn strikes(&self) -> cblc::Strikes<'a> {
+        self.locations.strikes()
+    }
+
     /// Returns a raster image for the glyph.
+    ///
+    /// [`RasterGlyphImage::data_offset`] is relative to the start of the table.
     pub fn get(&self, glyph_id: GlyphId, pixels_per_em: u16) -> Option<RasterGlyphImage<'a>> {
         let location = self.locations.get(glyph_id, pixels_per_em)?;
         let mut s = Stream::new_at(self.data, location.offset)?;
@@ -61,6 +71,7 @@ impl<'a> Table<'a> {
             BitmapDataFormat::ByteAligned { bit_depth } => {
                 let row_len = (u32::from(metrics.width) * u32::from(bit_depth) + 7) / 8;
                 let data_len = row_len * u32::from(metrics.height);
+                let data_start = s.offset();
                 let data = s.read_bytes(usize::num_from(data_len))?;
                 Some(RasterGlyphImage {
                     x: i16::from(metrics.x),
@@ -78,6 +89,7 @@ impl<'a> Table<'a> {
                         _ => return None,
                     },
                     data,
+                    data_offset: u32::try_from(data_start).ok()?,
                 })
             }
             BitmapDataFormat::BitAligned { bit_depth } => {
@@ -88,6 +100,7 @@ impl<'a> Table<'a> {
                     (w * h * d + 7) / 8
                 };
 
+                let data_start = s.offset();
                 let data = s.read_bytes(usize::num_from(data_len))?;
                 Some(RasterGlyphImage {
                     x: i16::from(metrics.x),
@@ -105,10 +118,12 @@ impl<'a> Table<'a> {
                         _ => return None,
                     },
                     data,
+                    data_offset: u32::try_from(data_start).ok()?,
                 })
             }
             BitmapDataFormat::PNG => {
                 let data_len = s.read::<u32>()?;
+                let data_start = s.offset();
                 let data = s.read_bytes(usize::num_from(data_len))?;
                 Some(RasterGlyphImage {
                     x: i16::from(metrics.x),
@@ -119,6 +134,7 @@ impl<'a> Table<'a> {
                     pixels_per_em: location.ppem,
                     format: RasterImageFormat::PNG,
                     data,
+                    data_offset: u32::try_from(data_start).ok()?,
                 })
             }
         }
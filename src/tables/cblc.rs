@@ -1,7 +1,7 @@
 //! A [Color Bitmap Location Table](
 //! https://docs.microsoft.com/en-us/typography/opentype/spec/cblc) implementation.
 
-use crate::parser::{FromData, NumFrom, Offset, Offset16, Offset32, Stream};
+use crate::parser::{FromData, LazyArray32, NumFrom, Offset, Offset16, Offset32, Stream};
 use crate::GlyphId;
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -99,6 +99,38 @@ fn select_bitmap_size_table(
     })
 }
 
+/// Only the `ppemX` field of a `BitmapSize` record.
+#[derive(Clone, Copy)]
+struct BitmapSizePpem(u16);
+
+impl FromData for BitmapSizePpem {
+    const SIZE: usize = 48;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        data.get(44).map(|ppem| BitmapSizePpem(u16::from(*ppem)))
+    }
+}
+
+/// An iterator over strikes' pixels per em.
+#[derive(Clone, Copy, Default)]
+#[allow(missing_debug_implementations)]
+pub struct Strikes<'a> {
+    sizes: LazyArray32<'a, BitmapSizePpem>,
+    index: u32,
+}
+
+impl Iterator for Strikes<'_> {
+    type Item = u16;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ppem = self.sizes.get(self.index)?;
+        self.index += 1;
+        Some(ppem.0)
+    }
+}
+
 #[derive(Clone, Copy)]
 struct IndexSubtableInfo {
     start_glyph_id: GlyphId,
@@ -164,6 +196,19 @@ impl<'a> Table<'a> {
         Some(Self { data })
     }
 
+    /// Returns an iterator over strikes' pixels per em, in the stored order.
+    ///
+    /// Only horizontal pixels per em are reported.
+    pub fn strikes(&self) -> Strikes<'a> {
+        let mut s = Stream::new(self.data);
+        s.skip::<u32>(); // version
+        let sizes = s
+            .read::<u32>()
+            .and_then(|count| s.read_array32::<BitmapSizePpem>(count))
+            .unwrap_or_default();
+        Strikes { sizes, index: 0 }
+    }
+
     pub(crate) fn get(&self, glyph_id: GlyphId, pixels_per_em: u16) -> Option<Location> {
         let mut s = Stream::new(self.data);
 
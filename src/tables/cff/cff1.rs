@@ -357,6 +357,7 @@ fn parse_char_string(
     metadata: &Table,
     glyph_id: GlyphId,
     width_only: bool,
+    max_depth: u8,
     builder: &mut dyn OutlineBuilder,
 ) -> Result<(Rect, Option<f32>), CFFError> {
     let local_subrs = match metadata.kind {
@@ -393,7 +394,8 @@ fn parse_char_string(
         is_first_move_to: true,
         width_only,
     };
-    _parse_char_string(&mut ctx, data, 0, &mut parser)?;
+    let depth = STACK_LIMIT.saturating_sub(max_depth);
+    _parse_char_string(&mut ctx, data, depth, &mut parser)?;
 
     if width_only {
         return Ok((Rect::zero(), ctx.width));
@@ -957,7 +959,23 @@ impl<'a> Table<'a> {
             .char_strings
             .get(u32::from(glyph_id.0))
             .ok_or(CFFError::NoGlyph)?;
-        parse_char_string(data, self, glyph_id, false, builder).map(|v| v.0)
+        parse_char_string(data, self, glyph_id, false, STACK_LIMIT, builder).map(|v| v.0)
+    }
+
+    /// Outlines a glyph, allowing at most `max_depth` levels of nested subroutines.
+    ///
+    /// Cannot exceed the built-in limit.
+    pub(crate) fn outline_with_depth(
+        &self,
+        glyph_id: GlyphId,
+        max_depth: u8,
+        builder: &mut dyn OutlineBuilder,
+    ) -> Result<Rect, CFFError> {
+        let data = self
+            .char_strings
+            .get(u32::from(glyph_id.0))
+            .ok_or(CFFError::NoGlyph)?;
+        parse_char_string(data, self, glyph_id, false, max_depth, builder).map(|v| v.0)
     }
 
     /// Resolves a Glyph ID for a code point.
@@ -990,7 +1008,8 @@ impl<'a> Table<'a> {
             FontKind::SID(ref sid) => {
                 let data = self.char_strings.get(u32::from(glyph_id.0))?;
                 let (_, width) =
-                    parse_char_string(data, self, glyph_id, true, &mut DummyOutline).ok()?;
+                    parse_char_string(data, self, glyph_id, true, STACK_LIMIT, &mut DummyOutline)
+                        .ok()?;
                 let width = width
                     .map(|w| sid.nominal_width + w)
                     .unwrap_or(sid.default_width);
@@ -233,6 +233,7 @@ fn parse_char_string(
     data: &[u8],
     metadata: &Table,
     coordinates: &[NormalizedCoordinate],
+    max_depth: u8,
     builder: &mut dyn OutlineBuilder,
 ) -> Result<Rect, CFFError> {
     let mut ctx = CharStringParserContext {
@@ -266,7 +267,8 @@ fn parse_char_string(
         is_first_move_to: true,
         width_only: false,
     };
-    _parse_char_string(&mut ctx, data, 0, &mut parser)?;
+    let depth = STACK_LIMIT.saturating_sub(max_depth);
+    _parse_char_string(&mut ctx, data, depth, &mut parser)?;
     // let _ = _parse_char_string(&mut ctx, data, 0.0, 0.0, &mut stack, 0, &mut inner_builder)?;
 
     let bbox = parser.builder.bbox;
@@ -553,7 +555,24 @@ impl<'a> Table<'a> {
             .char_strings
             .get(u32::from(glyph_id.0))
             .ok_or(CFFError::NoGlyph)?;
-        parse_char_string(data, self, coordinates, builder)
+        parse_char_string(data, self, coordinates, STACK_LIMIT, builder)
+    }
+
+    /// Outlines a glyph, allowing at most `max_depth` levels of nested subroutines.
+    ///
+    /// Cannot exceed the built-in limit.
+    pub(crate) fn outline_with_depth(
+        &self,
+        coordinates: &[NormalizedCoordinate],
+        glyph_id: GlyphId,
+        max_depth: u8,
+        builder: &mut dyn OutlineBuilder,
+    ) -> Result<Rect, CFFError> {
+        let data = self
+            .char_strings
+            .get(u32::from(glyph_id.0))
+            .ok_or(CFFError::NoGlyph)?;
+        parse_char_string(data, self, coordinates, max_depth, builder)
     }
 }
 
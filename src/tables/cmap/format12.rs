@@ -73,6 +73,23 @@ impl<'a> Subtable12<'a> {
             }
         }
     }
+
+    /// Calls `f` with the first and last code points of each group,
+    /// limited to the code points mapped to a valid glyph ID.
+    pub(crate) fn mapped_ranges(&self, mut f: impl FnMut(u32, u32)) {
+        for group in self.groups {
+            let max_len = match u32::from(u16::MAX).checked_sub(group.start_glyph_id) {
+                Some(v) => v,
+                None => continue,
+            };
+            let end = group
+                .end_char_code
+                .min(group.start_char_code.saturating_add(max_len));
+            if group.start_char_code <= end {
+                f(group.start_char_code, end);
+            }
+        }
+    }
 }
 
 impl core::fmt::Debug for Subtable12<'_> {
@@ -46,6 +46,18 @@ impl<'a> Subtable13<'a> {
             }
         }
     }
+
+    /// Calls `f` with the first and last code points of each group
+    /// mapped to a valid glyph ID.
+    pub(crate) fn mapped_ranges(&self, mut f: impl FnMut(u32, u32)) {
+        for group in self.groups {
+            if group.start_glyph_id <= u32::from(u16::MAX)
+                && group.start_char_code <= group.end_char_code
+            {
+                f(group.start_char_code, group.end_char_code);
+            }
+        }
+    }
 }
 
 impl core::fmt::Debug for Subtable13<'_> {
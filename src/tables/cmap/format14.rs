@@ -100,6 +100,14 @@ impl<'a> Subtable14<'a> {
         Some(Self { records, data })
     }
 
+    /// Returns an iterator over variation selectors.
+    ///
+    /// Ordered by code point.
+    #[inline]
+    pub fn variation_selectors(&self) -> impl Iterator<Item = u32> + 'a {
+        self.records.into_iter().map(|r| r.var_selector)
+    }
+
     /// Returns a glyph index for a code point.
     pub fn glyph_index(&self, code_point: u32, variation: u32) -> Option<GlyphVariationResult> {
         let (_, record) = self
@@ -74,23 +74,21 @@ impl<'a> Subtable4<'a> {
                         return None;
                     }
 
-                    let delta = (u32::from(code_point) - u32::from(start_value)) * 2;
-                    let delta = u16::try_from(delta).ok()?;
+                    // `id_range_offset` is relative to its own position in the subtable.
+                    let pos = self.id_range_offset_pos
+                        + usize::from(index) * 2
+                        + usize::from(id_range_offset)
+                        + usize::from(code_point - start_value) * 2;
 
-                    let id_range_offset_pos =
-                        (self.id_range_offset_pos + usize::from(index) * 2) as u16;
-                    let pos = id_range_offset_pos.wrapping_add(delta);
-                    let pos = pos.wrapping_add(id_range_offset);
-
-                    let glyph_array_value: u16 = Stream::read_at(self.data, usize::from(pos))?;
+                    let glyph_array_value: u16 = Stream::read_at(self.data, pos)?;
 
                     // 0 indicates missing glyph.
                     if glyph_array_value == 0 {
                         return None;
                     }
 
-                    let glyph_id = (glyph_array_value as i16).wrapping_add(id_delta);
-                    return u16::try_from(glyph_id).ok().map(GlyphId);
+                    // The idDelta arithmetic is modulo 65536.
+                    return Some(GlyphId(glyph_array_value.wrapping_add(id_delta as u16)));
                 }
             } else {
                 start = index + 1;
@@ -8,6 +8,8 @@ This module provides a low-level alternative to
 methods.
 */
 
+use core::convert::TryFrom;
+
 use crate::parser::{FromData, LazyArray16, Offset, Offset32, Stream};
 use crate::{name::PlatformId, GlyphId};
 
@@ -44,6 +46,24 @@ pub enum Format<'a> {
     UnicodeVariationSequences(Subtable14<'a>),
 }
 
+impl Format<'_> {
+    /// Returns the subtable format number, like 4 or 12.
+    #[inline]
+    pub fn number(&self) -> u16 {
+        match self {
+            Self::ByteEncodingTable(_) => 0,
+            Self::HighByteMappingThroughTable(_) => 2,
+            Self::SegmentMappingToDeltaValues(_) => 4,
+            Self::TrimmedTableMapping(_) => 6,
+            Self::MixedCoverage => 8,
+            Self::TrimmedArray(_) => 10,
+            Self::SegmentedCoverage(_) => 12,
+            Self::ManyToOneRangeMappings(_) => 13,
+            Self::UnicodeVariationSequences(_) => 14,
+        }
+    }
+}
+
 /// A character encoding subtable.
 #[derive(Clone, Copy, Debug)]
 pub struct Subtable<'a> {
@@ -112,8 +132,12 @@ impl<'a> Subtable<'a> {
 
     /// Resolves a variation of a glyph ID from two code points.
     ///
+    /// Returns [`GlyphVariationResult::UseDefault`] for a default variation sequence,
+    /// meaning that the base glyph should be used,
+    /// and [`GlyphVariationResult::Found`] for a non-default one.
+    ///
     /// Returns `None`:
-    /// - when glyph ID is `0`.
+    /// - when the variation sequence is not present in this subtable.
     /// - when format is not `UnicodeVariationSequences`.
     #[inline]
     pub fn glyph_variation_index(
@@ -193,6 +217,8 @@ impl core::fmt::Debug for Subtables<'_> {
 
 impl<'a> Subtables<'a> {
     /// Returns a subtable at an index.
+    ///
+    /// Returns `None` when the index is out of bounds or the subtable is malformed.
     pub fn get(&self, index: u16) -> Option<Subtable<'a>> {
         let record = self.records.get(index)?;
         let data = self.data.get(record.offset.to_usize()..)?;
@@ -226,6 +252,56 @@ impl<'a> Subtables<'a> {
     pub fn is_empty(&self) -> bool {
         self.records.is_empty()
     }
+
+    /// Returns the best Unicode subtable.
+    ///
+    /// Subtables are preferred in the following order:
+    ///
+    /// 1. Windows platform, format 12.
+    /// 2. Unicode platform, format 12.
+    /// 3. Windows platform, format 4.
+    /// 4. Unicode platform, format 4.
+    /// 5. Any other Unicode subtable, except format 14.
+    ///
+    /// When several subtables have the same rank, the first one is used.
+    pub fn best_unicode(&self) -> Option<Subtable<'a>> {
+        let mut best: Option<(u8, Subtable<'a>)> = None;
+        for subtable in *self {
+            let rank = match unicode_rank(&subtable) {
+                Some(v) => v,
+                None => continue,
+            };
+
+            match best {
+                Some((best_rank, _)) if best_rank <= rank => {}
+                _ => best = Some((rank, subtable)),
+            }
+
+            if rank == 0 {
+                break;
+            }
+        }
+
+        best.map(|(_, subtable)| subtable)
+    }
+}
+
+/// Returns a Unicode subtable rank. Lower is better.
+fn unicode_rank(subtable: &Subtable) -> Option<u8> {
+    if !subtable.is_unicode() {
+        return None;
+    }
+
+    let rank = match (subtable.platform_id, &subtable.format) {
+        (_, Format::UnicodeVariationSequences(_)) => return None,
+        (PlatformId::Windows, Format::SegmentedCoverage(_)) => 0,
+        (PlatformId::Unicode, Format::SegmentedCoverage(_)) => 1,
+        (PlatformId::Windows, Format::SegmentMappingToDeltaValues(_)) => 2,
+        (PlatformId::Unicode, Format::SegmentMappingToDeltaValues(_)) => 3,
+        _ => 4,
+    };
+
+    Some(rank)
 }
 
 impl<'a> IntoIterator for Subtables<'a> {
@@ -282,3 +358,35 @@ impl<'a> Table<'a> {
         })
     }
 }
+
+/// Unicode code points of the Mac Roman 0x80..=0xFF range.
+#[rustfmt::skip]
+const MAC_ROMAN: [u16; 128] = [
+    0x00C4, 0x00C5, 0x00C7, 0x00C9, 0x00D1, 0x00D6, 0x00DC, 0x00E1,
+    0x00E0, 0x00E2, 0x00E4, 0x00E3, 0x00E5, 0x00E7, 0x00E9, 0x00E8,
+    0x00EA, 0x00EB, 0x00ED, 0x00EC, 0x00EE, 0x00EF, 0x00F1, 0x00F3,
+    0x00F2, 0x00F4, 0x00F6, 0x00F5, 0x00FA, 0x00F9, 0x00FB, 0x00FC,
+    0x2020, 0x00B0, 0x00A2, 0x00A3, 0x00A7, 0x2022, 0x00B6, 0x00DF,
+    0x00AE, 0x00A9, 0x2122, 0x00B4, 0x00A8, 0x2260, 0x00C6, 0x00D8,
+    0x221E, 0x00B1, 0x2264, 0x2265, 0x00A5, 0x00B5, 0x2202, 0x2211,
+    0x220F, 0x03C0, 0x222B, 0x00AA, 0x00BA, 0x03A9, 0x00E6, 0x00F8,
+    0x00BF, 0x00A1, 0x00AC, 0x221A, 0x0192, 0x2248, 0x2206, 0x00AB,
+    0x00BB, 0x2026, 0x00A0, 0x00C0, 0x00C3, 0x00D5, 0x0152, 0x0153,
+    0x2013, 0x2014, 0x201C, 0x201D, 0x2018, 0x2019, 0x00F7, 0x25CA,
+    0x00FF, 0x0178, 0x2044, 0x20AC, 0x2039, 0x203A, 0xFB01, 0xFB02,
+    0x2021, 0x00B7, 0x201A, 0x201E, 0x2030, 0x00C2, 0x00CA, 0x00C1,
+    0x00CB, 0x00C8, 0x00CD, 0x00CE, 0x00CF, 0x00CC, 0x00D3, 0x00D4,
+    0xF8FF, 0x00D2, 0x00DA, 0x00DB, 0x00D9, 0x0131, 0x02C6, 0x02DC,
+    0x00AF, 0x02D8, 0x02D9, 0x02DA, 0x00B8, 0x02DD, 0x02DB, 0x02C7,
+];
+
+/// Encodes a character using the Mac Roman encoding.
+pub(crate) fn mac_roman_from_char(c: char) -> Option<u8> {
+    if c.is_ascii() {
+        return Some(c as u8);
+    }
+
+    let c = u16::try_from(u32::from(c)).ok()?;
+    let index = MAC_ROMAN.iter().position(|v| *v == c)?;
+    Some(0x80 + index as u8)
+}
@@ -6,7 +6,9 @@
 
 #[cfg(feature = "variable-fonts")]
 use crate::delta_set::DeltaSetIndexMap;
-use crate::parser::{FromData, LazyArray16, Offset, Offset24, Offset32, Stream, F2DOT14};
+use crate::parser::{
+    FromData, LazyArray16, LazyArrayIter16, Offset, Offset24, Offset32, Stream, F2DOT14,
+};
 #[cfg(feature = "variable-fonts")]
 use crate::var_store::ItemVariationStore;
 #[cfg(feature = "variable-fonts")]
@@ -700,10 +702,48 @@ pub trait Painter<'a> {
     fn pop_transform(&mut self);
 }
 
+/// A layer of a version 0 color glyph.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ColorLayer {
+    /// A glyph to fill.
+    pub glyph_id: GlyphId,
+    /// A fill color resolved from the palette.
+    ///
+    /// `None` means that the text foreground color should be used.
+    pub color: Option<RgbaColor>,
+}
+
+/// An iterator over [`ColorLayer`]s.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct ColorLayers<'a> {
+    palettes: cpal::Table<'a>,
+    palette: u16,
+    layers: LazyArrayIter16<'a, LayerRecord>,
+}
+
+impl Iterator for ColorLayers<'_> {
+    type Item = ColorLayer;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let layer = self.layers.next()?;
+        let color = if layer.palette_index == 0xFFFF {
+            None
+        } else {
+            Some(self.palettes.get(self.palette, layer.palette_index)?)
+        };
+
+        Some(ColorLayer {
+            glyph_id: layer.glyph_id,
+            color,
+        })
+    }
+}
+
 /// A [Color Table](
 /// https://docs.microsoft.com/en-us/typography/opentype/spec/colr).
 ///
-/// Currently, only version 0 is supported.
+/// Both version 0 (flat layers) and version 1 (paint graph) are supported.
 #[derive(Clone, Copy, Debug)]
 pub struct Table<'a> {
     pub(crate) palettes: cpal::Table<'a>,
@@ -856,6 +896,24 @@ impl<'a> Table<'a> {
         self.get_v1(glyph_id).is_some() || self.get_v0(glyph_id).is_some()
     }
 
+    /// Returns an iterator over version 0 layers of a color glyph.
+    ///
+    /// Colors are resolved using the specified palette.
+    /// The iterator stops at the first layer with an invalid palette entry.
+    ///
+    /// Returns `None` when the glyph has no version 0 definition,
+    /// which is always the case for glyphs defined only by a version 1 paint graph.
+    pub fn layers(&self, glyph_id: GlyphId, palette: u16) -> Option<ColorLayers<'a>> {
+        let base = self.get_v0(glyph_id)?;
+        let start = base.first_layer_index;
+        let end = start.checked_add(base.num_layers)?;
+        Some(ColorLayers {
+            palettes: self.palettes,
+            palette,
+            layers: self.layers.slice(start..end)?.into_iter(),
+        })
+    }
+
     /// Returns the clip box for a glyph.
     pub fn clip_box(
         &self,
@@ -1187,8 +1245,8 @@ impl<'a> Table<'a> {
                 painter.paint(Paint::SweepGradient(SweepGradient {
                     center_x: s.read::<i16>()? as f32,
                     center_y: s.read::<i16>()? as f32,
-                    start_angle: s.read::<F2DOT14>()?.to_f32(),
-                    end_angle: s.read::<F2DOT14>()?.to_f32(),
+                    start_angle: s.read_f2dot14()?,
+                    end_angle: s.read_f2dot14()?,
                     extend: color_line.extend,
                     color_line: ColorLine::NonVarColorLine(color_line),
                     #[cfg(feature = "variable-fonts")]
@@ -1363,8 +1421,8 @@ impl<'a> Table<'a> {
             16 => {
                 // PaintScale
                 let paint_offset = s.read::<Offset24>()?;
-                let sx = s.read::<F2DOT14>()?.to_f32();
-                let sy = s.read::<F2DOT14>()?.to_f32();
+                let sx = s.read_f2dot14()?;
+                let sy = s.read_f2dot14()?;
 
                 painter.push_transform(Transform::new_scale(sx, sy));
                 self.parse_paint(
@@ -1408,8 +1466,8 @@ impl<'a> Table<'a> {
             18 => {
                 // PaintScaleAroundCenter
                 let paint_offset = s.read::<Offset24>()?;
-                let sx = s.read::<F2DOT14>()?.to_f32();
-                let sy = s.read::<F2DOT14>()?.to_f32();
+                let sx = s.read_f2dot14()?;
+                let sy = s.read_f2dot14()?;
                 let center_x = f32::from(s.read::<i16>()?);
                 let center_y = f32::from(s.read::<i16>()?);
 
@@ -1465,7 +1523,7 @@ impl<'a> Table<'a> {
             20 => {
                 // PaintScaleUniform
                 let paint_offset = s.read::<Offset24>()?;
-                let scale = s.read::<F2DOT14>()?.to_f32();
+                let scale = s.read_f2dot14()?;
 
                 painter.push_transform(Transform::new_scale(scale, scale));
                 self.parse_paint(
@@ -1508,7 +1566,7 @@ impl<'a> Table<'a> {
             22 => {
                 // PaintScaleUniformAroundCenter
                 let paint_offset = s.read::<Offset24>()?;
-                let scale = s.read::<F2DOT14>()?.to_f32();
+                let scale = s.read_f2dot14()?;
                 let center_x = f32::from(s.read::<i16>()?);
                 let center_y = f32::from(s.read::<i16>()?);
 
@@ -1563,7 +1621,7 @@ impl<'a> Table<'a> {
             24 => {
                 // PaintRotate
                 let paint_offset = s.read::<Offset24>()?;
-                let angle = s.read::<F2DOT14>()?.to_f32();
+                let angle = s.read_f2dot14()?;
 
                 painter.push_transform(Transform::new_rotate(angle));
                 self.parse_paint(
@@ -1606,7 +1664,7 @@ impl<'a> Table<'a> {
             26 => {
                 // PaintRotateAroundCenter
                 let paint_offset = s.read::<Offset24>()?;
-                let angle = s.read::<F2DOT14>()?.to_f32();
+                let angle = s.read_f2dot14()?;
                 let center_x = f32::from(s.read::<i16>()?);
                 let center_y = f32::from(s.read::<i16>()?);
 
@@ -1661,8 +1719,8 @@ impl<'a> Table<'a> {
             28 => {
                 // PaintSkew
                 let paint_offset = s.read::<Offset24>()?;
-                let skew_x = s.read::<F2DOT14>()?.to_f32();
-                let skew_y = s.read::<F2DOT14>()?.to_f32();
+                let skew_x = s.read_f2dot14()?;
+                let skew_y = s.read_f2dot14()?;
 
                 painter.push_transform(Transform::new_skew(skew_x, skew_y));
                 self.parse_paint(
@@ -1706,8 +1764,8 @@ impl<'a> Table<'a> {
             30 => {
                 // PaintSkewAroundCenter
                 let paint_offset = s.read::<Offset24>()?;
-                let skew_x = s.read::<F2DOT14>()?.to_f32();
-                let skew_y = s.read::<F2DOT14>()?.to_f32();
+                let skew_x = s.read_f2dot14()?;
+                let skew_y = s.read_f2dot14()?;
                 let center_x = f32::from(s.read::<i16>()?);
                 let center_y = f32::from(s.read::<i16>()?);
 
@@ -3,15 +3,19 @@
 
 use core::num::NonZeroU16;
 
-use crate::parser::{FromData, LazyArray16, Offset, Offset32, Stream};
+use crate::parser::{FromData, LazyArray16, LazyArrayIter16, Offset, Offset32, Stream};
 use crate::RgbaColor;
 
 /// A [Color Palette Table](
 /// https://docs.microsoft.com/en-us/typography/opentype/spec/cpal).
 #[derive(Clone, Copy, Debug)]
 pub struct Table<'a> {
+    palette_entries: u16,
     color_indices: LazyArray16<'a, u16>,
     colors: LazyArray16<'a, BgraColor>,
+    // v1
+    palette_labels: LazyArray16<'a, u16>,
+    palette_entry_labels: LazyArray16<'a, u16>,
 }
 
 impl<'a> Table<'a> {
@@ -24,7 +28,7 @@ impl<'a> Table<'a> {
             return None;
         }
 
-        s.skip::<u16>(); // number of palette entries
+        let palette_entries = s.read::<u16>()?;
 
         let num_palettes = s.read::<u16>()?;
         if num_palettes == 0 {
@@ -38,10 +42,36 @@ impl<'a> Table<'a> {
         let colors = Stream::new_at(data, color_records_offset.to_usize())?
             .read_array16::<BgraColor>(num_colors)?;
 
-        Some(Self {
+        let mut table = Self {
+            palette_entries,
             color_indices,
             colors,
-        })
+            palette_labels: LazyArray16::default(),
+            palette_entry_labels: LazyArray16::default(),
+        };
+
+        if version == 0 {
+            return Some(table);
+        }
+
+        // Labels are optional, therefore malformed ones are simply ignored.
+        s.skip::<Offset32>(); // palette types array offset
+        let palette_labels_offset = s.read::<Option<Offset32>>().flatten();
+        let palette_entry_labels_offset = s.read::<Option<Offset32>>().flatten();
+
+        if let Some(offset) = palette_labels_offset {
+            table.palette_labels = Stream::new_at(data, offset.to_usize())
+                .and_then(|mut s| s.read_array16::<u16>(num_palettes))
+                .unwrap_or_default();
+        }
+
+        if let Some(offset) = palette_entry_labels_offset {
+            table.palette_entry_labels = Stream::new_at(data, offset.to_usize())
+                .and_then(|mut s| s.read_array16::<u16>(palette_entries))
+                .unwrap_or_default();
+        }
+
+        Some(table)
     }
 
     /// Returns the number of palettes.
@@ -50,6 +80,39 @@ impl<'a> Table<'a> {
         NonZeroU16::new(self.color_indices.len()).unwrap()
     }
 
+    /// Returns the number of entries in each palette.
+    pub fn palette_entries(&self) -> u16 {
+        self.palette_entries
+    }
+
+    /// Returns an iterator over colors of the given palette.
+    ///
+    /// Returns `None` when the palette index is out of bounds
+    /// or the palette is malformed.
+    pub fn palette(&self, palette_index: u16) -> Option<PaletteColors<'a>> {
+        let start = self.color_indices.get(palette_index)?;
+        let end = start.checked_add(self.palette_entries)?;
+        Some(PaletteColors(self.colors.slice(start..end)?.into_iter()))
+    }
+
+    /// Returns a `name` table ID of the palette label.
+    ///
+    /// Version 1 only.
+    pub fn palette_label(&self, palette_index: u16) -> Option<u16> {
+        self.palette_labels
+            .get(palette_index)
+            .filter(|id| *id != 0xFFFF)
+    }
+
+    /// Returns a `name` table ID of the palette entry label.
+    ///
+    /// Version 1 only.
+    pub fn palette_entry_label(&self, palette_entry: u16) -> Option<u16> {
+        self.palette_entry_labels
+            .get(palette_entry)
+            .filter(|id| *id != 0xFFFF)
+    }
+
     /// Returns the color at the given index into the given palette.
     pub fn get(&self, palette_index: u16, palette_entry: u16) -> Option<RgbaColor> {
         let index = self
@@ -60,6 +123,20 @@ impl<'a> Table<'a> {
     }
 }
 
+/// An iterator over palette colors.
+#[derive(Clone, Copy)]
+#[allow(missing_debug_implementations)]
+pub struct PaletteColors<'a>(LazyArrayIter16<'a, BgraColor>);
+
+impl Iterator for PaletteColors<'_> {
+    type Item = RgbaColor;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|c| c.to_rgba())
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 struct BgraColor {
     blue: u8,
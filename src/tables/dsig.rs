@@ -0,0 +1,115 @@
+//! A [Digital Signature Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/dsig) implementation.
+
+use crate::parser::{FromData, LazyArray16, LazyArrayIter16, NumFrom, Offset, Offset32, Stream};
+
+#[derive(Clone, Copy, Debug)]
+struct SignatureRecord {
+    format: u32,
+    length: u32,
+    offset: Offset32,
+}
+
+impl FromData for SignatureRecord {
+    const SIZE: usize = 12;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(SignatureRecord {
+            format: s.read::<u32>()?,
+            length: s.read::<u32>()?,
+            offset: s.read::<Offset32>()?,
+        })
+    }
+}
+
+/// A signature block.
+#[derive(Clone, Copy, Debug)]
+pub struct Signature<'a> {
+    /// Signature format.
+    ///
+    /// Only format 1 (PKCS#7) is defined.
+    pub format: u32,
+    /// Raw signature block data, including its header.
+    pub data: &'a [u8],
+}
+
+/// A [Digital Signature Table](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/dsig).
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a> {
+    /// Permission flags.
+    ///
+    /// Bit 0 indicates that the font cannot be resigned.
+    pub flags: u16,
+    data: &'a [u8],
+    records: LazyArray16<'a, SignatureRecord>,
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+
+        let version = s.read::<u32>()?;
+        if version != 1 {
+            return None;
+        }
+
+        let count = s.read::<u16>()?;
+        let flags = s.read::<u16>()?;
+        let records = s.read_array16::<SignatureRecord>(count)?;
+
+        Some(Table {
+            flags,
+            data,
+            records,
+        })
+    }
+
+    /// Returns the number of signatures.
+    #[inline]
+    pub fn len(&self) -> u16 {
+        self.records.len()
+    }
+
+    /// Checks if there are any signatures.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns an iterator over signatures.
+    ///
+    /// Stops at the first out of bounds signature block.
+    #[inline]
+    pub fn signatures(&self) -> Signatures<'a> {
+        Signatures {
+            data: self.data,
+            records: self.records.into_iter(),
+        }
+    }
+}
+
+/// An iterator over [`Signature`]s.
+#[derive(Clone, Copy, Default)]
+#[allow(missing_debug_implementations)]
+pub struct Signatures<'a> {
+    data: &'a [u8],
+    records: LazyArrayIter16<'a, SignatureRecord>,
+}
+
+impl<'a> Iterator for Signatures<'a> {
+    type Item = Signature<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = self.records.next()?;
+        let start = record.offset.to_usize();
+        let end = start.checked_add(usize::num_from(record.length))?;
+        Some(Signature {
+            format: record.format,
+            data: self.data.get(start..end)?,
+        })
+    }
+}
@@ -10,6 +10,7 @@ use crate::{NormalizedCoordinate, Tag};
 #[repr(C)]
 #[allow(missing_docs)]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VariationAxis {
     pub tag: Tag,
     pub min_value: f32,
@@ -17,6 +18,7 @@ pub struct VariationAxis {
     pub max_value: f32,
     /// An axis name in the `name` table.
     pub name_id: u16,
+    /// Indicates that the axis should not be exposed directly in user interfaces.
     pub hidden: bool,
 }
 
@@ -38,7 +40,7 @@ impl FromData for VariationAxis {
             def_value: def_value.0,
             max_value: def_value.0.max(max_value.0),
             name_id,
-            hidden: (flags >> 3) & 1 == 1,
+            hidden: flags & 0x0001 != 0,
         })
     }
 }
@@ -0,0 +1,84 @@
+//! A [Grid-fitting and Scan-conversion Procedure Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/gasp) implementation.
+
+use crate::parser::{FromData, LazyArray16, Stream};
+
+/// A rasterizer behavior for a range of sizes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Range {
+    /// Upper limit of the range, in PPEM.
+    pub max_ppem: u16,
+    /// Raw behavior flags.
+    pub behavior: u16,
+}
+
+#[rustfmt::skip]
+impl Range {
+    /// Use gridfitting.
+    #[inline] pub fn gridfit(self) -> bool { self.behavior & 0x0001 != 0 }
+    /// Use grayscale rendering.
+    #[inline] pub fn grayscale(self) -> bool { self.behavior & 0x0002 != 0 }
+    /// Use gridfitting with ClearType symmetric smoothing.
+    ///
+    /// Version 1 only.
+    #[inline] pub fn symmetric_gridfit(self) -> bool { self.behavior & 0x0004 != 0 }
+    /// Use smoothing along multiple axes with ClearType.
+    ///
+    /// Version 1 only.
+    #[inline] pub fn symmetric_smoothing(self) -> bool { self.behavior & 0x0008 != 0 }
+}
+
+impl FromData for Range {
+    const SIZE: usize = 4;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(Range {
+            max_ppem: s.read::<u16>()?,
+            behavior: s.read::<u16>()?,
+        })
+    }
+}
+
+/// A [Grid-fitting and Scan-conversion Procedure Table](
+/// https://docs.microsoft.com/en-us/typography/opentype/spec/gasp).
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a> {
+    /// Table version.
+    pub version: u16,
+    /// A list of ranges.
+    ///
+    /// Sorted by `max_ppem`.
+    pub ranges: LazyArray16<'a, Range>,
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+
+        let version = s.read::<u16>()?;
+        if version > 1 {
+            return None;
+        }
+
+        let count = s.read::<u16>()?;
+        let ranges = s.read_array16::<Range>(count)?;
+
+        Some(Table { version, ranges })
+    }
+
+    /// Returns a rasterizer behavior for the specified size.
+    ///
+    /// Picks the first range with `max_ppem` greater than or equal to `ppem`.
+    /// Flags that are not defined by the table version are cleared.
+    pub fn find(&self, ppem: u16) -> Option<Range> {
+        let mut range = self.ranges.into_iter().find(|r| r.max_ppem >= ppem)?;
+        if self.version == 0 {
+            range.behavior &= 0x0003;
+        }
+
+        Some(range)
+    }
+}
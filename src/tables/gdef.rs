@@ -1,8 +1,8 @@
 //! A [Glyph Definition Table](
 //! https://docs.microsoft.com/en-us/typography/opentype/spec/gdef) implementation.
 
-use crate::opentype_layout::{Class, ClassDefinition, Coverage};
-use crate::parser::{FromSlice, LazyArray16, Offset, Offset16, Offset32, Stream};
+use crate::opentype_layout::{Class, ClassDefinition, ClassDefinitionIter, Coverage};
+use crate::parser::{FromSlice, LazyArray16, LazyArrayIter16, Offset, Offset16, Offset32, Stream};
 use crate::GlyphId;
 
 #[cfg(feature = "variable-fonts")]
@@ -25,6 +25,7 @@ pub enum GlyphClass {
 #[derive(Clone, Copy, Default)]
 pub struct Table<'a> {
     glyph_classes: Option<ClassDefinition<'a>>,
+    attach_list: Option<(&'a [u8], Coverage<'a>, LazyArray16<'a, Offset16>)>,
     mark_attach_classes: Option<ClassDefinition<'a>>,
     mark_glyph_coverage_offsets: Option<(&'a [u8], LazyArray16<'a, Offset32>)>,
     #[cfg(feature = "variable-fonts")]
@@ -41,7 +42,7 @@ impl<'a> Table<'a> {
         }
 
         let glyph_class_def_offset = s.read::<Option<Offset16>>()?;
-        s.skip::<Offset16>(); // attachListOffset
+        let attach_list_offset = s.read::<Option<Offset16>>()?;
         s.skip::<Offset16>(); // ligCaretListOffset
         let mark_attach_class_def_offset = s.read::<Option<Offset16>>()?;
 
@@ -69,6 +70,22 @@ impl<'a> Table<'a> {
             }
         }
 
+        if let Some(offset) = attach_list_offset {
+            if let Some(subdata) = data.get(offset.to_usize()..) {
+                let mut s = Stream::new(subdata);
+                let coverage = s
+                    .read::<Offset16>()
+                    .and_then(|offset| subdata.get(offset.to_usize()..))
+                    .and_then(Coverage::parse);
+                let offsets = s
+                    .read::<u16>()
+                    .and_then(|count| s.read_array16::<Offset16>(count));
+                if let (Some(coverage), Some(offsets)) = (coverage, offsets) {
+                    table.attach_list = Some((subdata, coverage, offsets));
+                }
+            }
+        }
+
         if let Some(offset) = mark_attach_class_def_offset {
             if let Some(subdata) = data.get(offset.to_usize()..) {
                 table.mark_attach_classes = ClassDefinition::parse(subdata);
@@ -127,6 +144,29 @@ impl<'a> Table<'a> {
         }
     }
 
+    /// Returns an iterator over all glyphs with a valid class according to
+    /// [Glyph Class Definition Table](
+    /// https://docs.microsoft.com/en-us/typography/opentype/spec/gdef#glyph-class-definition-table).
+    ///
+    /// Unlike calling [`Table::glyph_class`] for each glyph,
+    /// walks the table ranges directly.
+    #[inline]
+    pub fn glyph_classes(&self) -> GlyphClasses<'a> {
+        GlyphClasses(self.glyph_classes.map(|def| def.classes()))
+    }
+
+    /// Returns glyph's contour point indices according to
+    /// [Attachment Point List Table](
+    /// https://docs.microsoft.com/en-us/typography/opentype/spec/gdef#attachment-point-list-table).
+    ///
+    /// Returns an empty iterator when glyph is not covered.
+    #[inline]
+    pub fn attachment_points(&self, glyph_id: GlyphId) -> LazyArrayIter16<'a, u16> {
+        attachment_points_impl(self, glyph_id)
+            .unwrap_or_default()
+            .into_iter()
+    }
+
     /// Returns glyph's mark attachment class according to
     /// [Mark Attachment Class Definition Table](
     /// https://docs.microsoft.com/en-us/typography/opentype/spec/gdef#mark-attachment-class-definition-table).
@@ -150,6 +190,21 @@ impl<'a> Table<'a> {
         is_mark_glyph_impl(self, glyph_id, set_index).is_some()
     }
 
+    /// Returns an iterator over
+    /// [Mark Glyph Sets](
+    /// https://docs.microsoft.com/en-us/typography/opentype/spec/gdef#mark-glyph-sets-table).
+    ///
+    /// Use [`Coverage::glyphs`] to enumerate glyphs in each set.
+    #[inline]
+    pub fn mark_glyph_sets(&self) -> MarkGlyphSets<'a> {
+        let (data, offsets) = self.mark_glyph_coverage_offsets.unwrap_or_default();
+        MarkGlyphSets {
+            data,
+            offsets,
+            index: 0,
+        }
+    }
+
     /// Returns glyph's variation delta at a specified index according to
     /// [Item Variation Store Table](
     /// https://docs.microsoft.com/en-us/typography/opentype/spec/gdef#item-variation-store-table).
@@ -166,6 +221,75 @@ impl<'a> Table<'a> {
     }
 }
 
+/// An iterator over glyphs and their [`GlyphClass`].
+#[derive(Clone, Copy, Debug)]
+pub struct GlyphClasses<'a>(Option<ClassDefinitionIter<'a>>);
+
+impl Iterator for GlyphClasses<'_> {
+    type Item = (GlyphId, GlyphClass);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter = self.0.as_mut()?;
+        for (glyph_id, class) in iter {
+            let class = match class {
+                1 => GlyphClass::Base,
+                2 => GlyphClass::Ligature,
+                3 => GlyphClass::Mark,
+                4 => GlyphClass::Component,
+                _ => continue,
+            };
+
+            return Some((glyph_id, class));
+        }
+
+        None
+    }
+}
+
+/// An iterator over mark glyph sets.
+///
+/// Stops at the first malformed set.
+#[derive(Clone, Copy)]
+pub struct MarkGlyphSets<'a> {
+    data: &'a [u8],
+    offsets: LazyArray16<'a, Offset32>,
+    index: u16,
+}
+
+impl<'a> Iterator for MarkGlyphSets<'a> {
+    type Item = Coverage<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offsets.get(self.index)?;
+        self.index += 1;
+        let coverage = self.data.get(offset.to_usize()..).and_then(Coverage::parse);
+        if coverage.is_none() {
+            // Stop at a malformed set, so that calling `next` again returns `None` as well.
+            self.index = self.offsets.len();
+        }
+
+        coverage
+    }
+}
+
+impl core::fmt::Debug for MarkGlyphSets<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "MarkGlyphSets {{ ... }}")
+    }
+}
+
+fn attachment_points_impl<'a>(
+    table: &Table<'a>,
+    glyph_id: GlyphId,
+) -> Option<LazyArray16<'a, u16>> {
+    let (data, coverage, offsets) = table.attach_list?;
+    let index = coverage.get(glyph_id)?;
+    let offset = offsets.get(index)?;
+    let mut s = Stream::new_at(data, offset.to_usize())?;
+    let count = s.read::<u16>()?;
+    s.read_array16::<u16>(count)
+}
+
 #[inline(never)]
 fn is_mark_glyph_impl(table: &Table, glyph_id: GlyphId, set_index: Option<u16>) -> Option<()> {
     let (data, offsets) = table.mark_glyph_coverage_offsets?;
@@ -3,7 +3,7 @@
 
 use core::num::NonZeroU16;
 
-use crate::parser::{LazyArray16, NumFrom, Stream, F2DOT14};
+use crate::parser::{LazyArray16, NumFrom, Stream};
 use crate::{loca, GlyphId, OutlineBuilder, Rect, RectF, Transform};
 
 pub(crate) struct Builder<'a> {
@@ -180,15 +180,15 @@ impl<'a> Iterator for CompositeGlyphIter<'a> {
         }
 
         if flags.we_have_a_two_by_two() {
-            ts.a = self.stream.read::<F2DOT14>()?.to_f32();
-            ts.b = self.stream.read::<F2DOT14>()?.to_f32();
-            ts.c = self.stream.read::<F2DOT14>()?.to_f32();
-            ts.d = self.stream.read::<F2DOT14>()?.to_f32();
+            ts.a = self.stream.read_f2dot14()?;
+            ts.b = self.stream.read_f2dot14()?;
+            ts.c = self.stream.read_f2dot14()?;
+            ts.d = self.stream.read_f2dot14()?;
         } else if flags.we_have_an_x_and_y_scale() {
-            ts.a = self.stream.read::<F2DOT14>()?.to_f32();
-            ts.d = self.stream.read::<F2DOT14>()?.to_f32();
+            ts.a = self.stream.read_f2dot14()?;
+            ts.d = self.stream.read_f2dot14()?;
         } else if flags.we_have_a_scale() {
-            ts.a = self.stream.read::<F2DOT14>()?.to_f32();
+            ts.a = self.stream.read_f2dot14()?;
             ts.d = ts.a;
         }
 
@@ -422,6 +422,7 @@ impl CompositeGlyphFlags {
     #[inline] pub fn more_components(self) -> bool { self.0 & 0x0020 != 0 }
     #[inline] pub fn we_have_an_x_and_y_scale(self) -> bool { self.0 & 0x0040 != 0 }
     #[inline] pub fn we_have_a_two_by_two(self) -> bool { self.0 & 0x0080 != 0 }
+    #[inline] pub fn we_have_instructions(self) -> bool { self.0 & 0x0100 != 0 }
 }
 
 // It's not defined in the spec, so we are using our own value.
@@ -599,6 +600,22 @@ impl<'a> Table<'a> {
         outline_impl(self.loca_table, self.data, glyph_data, 0, &mut b)?
     }
 
+    /// Outlines a glyph, allowing at most `max_depth` levels of nested composite glyphs.
+    ///
+    /// Cannot exceed the built-in limit.
+    #[inline]
+    pub(crate) fn outline_with_depth(
+        &self,
+        glyph_id: GlyphId,
+        max_depth: u8,
+        builder: &mut dyn OutlineBuilder,
+    ) -> Option<Rect> {
+        let mut b = Builder::new(Transform::default(), RectF::new(), builder);
+        let glyph_data = self.get(glyph_id)?;
+        let depth = (MAX_COMPONENTS - 1).saturating_sub(max_depth);
+        outline_impl(self.loca_table, self.data, glyph_data, depth, &mut b)?
+    }
+
     /// The bounding box of the glyph. Unlike the `outline` method, this method does not
     /// calculate the bounding box manually by outlining the glyph, but instead uses the
     /// bounding box in the `glyf` program. As a result, this method will be much faster,
@@ -618,6 +635,108 @@ impl<'a> Table<'a> {
         })
     }
 
+    /// Checks that the glyph has no outline, like a space glyph.
+    ///
+    /// Returns `false` for out of bounds glyph IDs.
+    #[inline]
+    pub fn is_empty_glyph(&self, glyph_id: GlyphId) -> bool {
+        if self.loca_table.is_empty_glyph(glyph_id) {
+            return true;
+        }
+
+        // A glyph can also be just a header with no contours.
+        self.get(glyph_id)
+            .and_then(|data| Stream::read_at::<i16>(data, 0))
+            .map(|number_of_contours| number_of_contours == 0)
+            .unwrap_or(false)
+    }
+
+    /// Returns glyph's data range in the `glyf` table.
+    ///
+    /// The range is empty for glyphs without an outline.
+    ///
+    /// Returns `None` for out of bounds glyph IDs and ranges.
+    #[inline]
+    pub fn glyph_data_range(&self, glyph_id: GlyphId) -> Option<core::ops::Range<usize>> {
+        let range = self.loca_table.offsets(glyph_id)?;
+        if range.start > range.end || range.end > self.data.len() {
+            return None;
+        }
+
+        Some(range)
+    }
+
+    /// Returns glyph's number of contours.
+    ///
+    /// Positive for simple glyphs, negative for composite glyphs
+    /// and zero for glyphs without an outline. The outline itself is not parsed.
+    ///
+    /// Returns `None` for out of bounds glyph IDs and malformed glyphs.
+    #[inline]
+    pub fn number_of_contours(&self, glyph_id: GlyphId) -> Option<i16> {
+        let range = self.glyph_data_range(glyph_id)?;
+        if range.is_empty() {
+            return Some(0);
+        }
+
+        Stream::read_at::<i16>(self.data.get(range)?, 0)
+    }
+
+    /// Returns glyph's TrueType instructions.
+    ///
+    /// Returns `None` when the glyph has no instructions or is malformed.
+    pub fn glyph_instructions(&self, glyph_id: GlyphId) -> Option<&'a [u8]> {
+        let data = self.get(glyph_id)?;
+        let mut s = Stream::new(data);
+        let number_of_contours = s.read::<i16>()?;
+
+        // Skip bbox.
+        s.advance(8);
+
+        if number_of_contours > 0 {
+            // Simple glyph. Skip end points.
+            s.advance(usize::from(number_of_contours as u16) * 2);
+        } else if number_of_contours < 0 {
+            // Composite glyph. Instructions are stored after the last component.
+            loop {
+                let flags = CompositeGlyphFlags(s.read::<u16>()?);
+                s.skip::<GlyphId>();
+
+                if flags.arg_1_and_2_are_words() {
+                    s.advance(4);
+                } else {
+                    s.advance(2);
+                }
+
+                if flags.we_have_a_two_by_two() {
+                    s.advance(8);
+                } else if flags.we_have_an_x_and_y_scale() {
+                    s.advance(4);
+                } else if flags.we_have_a_scale() {
+                    s.advance(2);
+                }
+
+                if !flags.more_components() {
+                    if !flags.we_have_instructions() {
+                        return None;
+                    }
+
+                    break;
+                }
+            }
+        } else {
+            // An empty glyph.
+            return None;
+        }
+
+        let len = s.read::<u16>()?;
+        if len == 0 {
+            return None;
+        }
+
+        s.read_bytes(usize::from(len))
+    }
+
     #[inline]
     pub(crate) fn get(&self, glyph_id: GlyphId) -> Option<&'a [u8]> {
         let range = self.loca_table.glyph_range(glyph_id)?;
@@ -327,6 +327,19 @@ impl<'a> SingleAdjustment<'a> {
             Self::Format2 { coverage, .. } => *coverage,
         }
     }
+
+    /// Returns glyph's [`ValueRecord`].
+    ///
+    /// Returns `None` when the glyph is not covered by this subtable.
+    pub fn value(&self, glyph_id: GlyphId) -> Option<ValueRecord<'a>> {
+        match self {
+            Self::Format1 { coverage, value } => {
+                coverage.get(glyph_id)?;
+                Some(*value)
+            }
+            Self::Format2 { coverage, values } => values.get(coverage.get(glyph_id)?),
+        }
+    }
 }
 
 /// A [`ValueRecord`] pairs set used by [`PairAdjustment`].
@@ -656,6 +669,14 @@ impl<'a> CursiveAdjustment<'a> {
             _ => None,
         }
     }
+
+    /// Returns glyph's entry and exit anchors.
+    ///
+    /// Returns `None` when the glyph is not covered by this subtable.
+    pub fn anchors(&self, glyph_id: GlyphId) -> Option<(Option<Anchor<'a>>, Option<Anchor<'a>>)> {
+        let index = self.coverage.get(glyph_id)?;
+        Some((self.sets.entry(index), self.sets.exit(index)))
+    }
 }
 
 /// A [Mark-to-Base Attachment Positioning Subtable](
@@ -980,7 +1001,7 @@ impl<'a> LookupSubtable<'a> for PositioningSubtable<'a> {
             6 => MarkToMarkAdjustment::parse(data).map(Self::MarkToMark),
             7 => ContextLookup::parse(data).map(Self::Context),
             8 => ChainedContextLookup::parse(data).map(Self::ChainContext),
-            9 => crate::ggg::parse_extension_lookup(data, Self::parse),
+            9 => crate::ggg::parse_extension_lookup(data, 9, Self::parse),
             _ => None,
         }
     }
@@ -1001,4 +1022,22 @@ impl<'a> PositioningSubtable<'a> {
             Self::ChainContext(t) => t.coverage(),
         }
     }
+
+    /// Returns the lookup type of the subtable.
+    ///
+    /// Never returns the extension type 9, because extension subtables
+    /// are resolved during parsing.
+    #[inline]
+    pub fn kind(&self) -> u16 {
+        match self {
+            Self::Single(_) => 1,
+            Self::Pair(_) => 2,
+            Self::Cursive(_) => 3,
+            Self::MarkToBase(_) => 4,
+            Self::MarkToLigature(_) => 5,
+            Self::MarkToMark(_) => 6,
+            Self::Context(_) => 7,
+            Self::ChainContext(_) => 8,
+        }
+    }
 }
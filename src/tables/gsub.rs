@@ -262,7 +262,7 @@ impl<'a> LookupSubtable<'a> for SubstitutionSubtable<'a> {
             4 => LigatureSubstitution::parse(data).map(Self::Ligature),
             5 => ContextLookup::parse(data).map(Self::Context),
             6 => ChainedContextLookup::parse(data).map(Self::ChainContext),
-            7 => crate::ggg::parse_extension_lookup(data, Self::parse),
+            7 => crate::ggg::parse_extension_lookup(data, 7, Self::parse),
             8 => ReverseChainSingleSubstitution::parse(data).map(Self::ReverseChainSingle),
             _ => None,
         }
@@ -284,6 +284,23 @@ impl<'a> SubstitutionSubtable<'a> {
         }
     }
 
+    /// Returns the lookup type of the subtable.
+    ///
+    /// Never returns the extension type 7, because extension subtables
+    /// are resolved during parsing.
+    #[inline]
+    pub fn kind(&self) -> u16 {
+        match self {
+            Self::Single(_) => 1,
+            Self::Multiple(_) => 2,
+            Self::Alternate(_) => 3,
+            Self::Ligature(_) => 4,
+            Self::Context(_) => 5,
+            Self::ChainContext(_) => 6,
+            Self::ReverseChainSingle(_) => 8,
+        }
+    }
+
     /// Checks that the current subtable is *Reverse Chaining Contextual Single*.
     #[inline]
     pub fn is_reverse(&self) -> bool {
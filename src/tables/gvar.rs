@@ -1757,6 +1757,32 @@ impl<'a> Table<'a> {
         b.bbox.to_rect()
     }
 
+    /// Outlines a glyph, allowing at most `max_depth` levels of nested composite glyphs.
+    ///
+    /// Unlike `outline`, fails when any of the components cannot be outlined.
+    /// Cannot exceed the built-in limit.
+    pub(crate) fn outline_with_depth(
+        &self,
+        glyf_table: glyf::Table,
+        coordinates: &[NormalizedCoordinate],
+        glyph_id: GlyphId,
+        max_depth: u8,
+        builder: &mut dyn OutlineBuilder,
+    ) -> Option<Rect> {
+        let mut b = glyf::Builder::new(Transform::default(), RectF::new(), builder);
+        let glyph_data = glyf_table.get(glyph_id)?;
+        outline_var_impl(
+            glyf_table,
+            self,
+            glyph_id,
+            glyph_data,
+            coordinates,
+            (glyf::MAX_COMPONENTS - 1).saturating_sub(max_depth),
+            &mut b,
+        )?;
+        b.bbox.to_rect()
+    }
+
     pub(crate) fn phantom_points(
         &self,
         glyf_table: glyf::Table,
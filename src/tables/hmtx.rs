@@ -98,6 +98,22 @@ impl<'a> Table<'a> {
         }
     }
 
+    /// Checks that all non-zero advances are equal.
+    ///
+    /// Zero advances are ignored, since they are usually used by combining marks.
+    /// Returns `false` when there are no non-zero advances.
+    pub fn is_monospaced(&self) -> bool {
+        let mut advances = self
+            .metrics
+            .into_iter()
+            .map(|m| m.advance)
+            .filter(|a| *a != 0);
+        match advances.next() {
+            Some(first) => advances.all(|a| a == first),
+            None => false,
+        }
+    }
+
     /// Returns side bearing for a glyph.
     #[inline]
     pub fn side_bearing(&self, glyph_id: GlyphId) -> Option<i16> {
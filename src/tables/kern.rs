@@ -16,7 +16,7 @@ But we still try to keep the API as high-level as possible.
 
 #[cfg(feature = "apple-layout")]
 use crate::aat;
-use crate::parser::{FromData, LazyArray16, NumFrom, Offset, Offset16, Stream};
+use crate::parser::{FromData, LazyArray16, LazyArrayIter16, NumFrom, Offset, Offset16, Stream};
 use crate::GlyphId;
 
 #[derive(Clone, Copy, Debug)]
@@ -138,6 +138,39 @@ impl<'a> Subtable<'a> {
             _ => None,
         }
     }
+
+    /// Returns an iterator over all kerning pairs.
+    ///
+    /// Only format 0 subtables are supported.
+    /// Returns an empty iterator for other formats.
+    #[inline]
+    pub fn pairs(&self) -> KerningPairs<'a> {
+        match self.format {
+            Format::Format0(ref subtable) => KerningPairs(subtable.pairs.into_iter()),
+            _ => KerningPairs(LazyArray16::default().into_iter()),
+        }
+    }
+}
+
+/// An iterator over kerning pairs as `(left, right, value)`.
+#[derive(Clone, Copy)]
+pub struct KerningPairs<'a>(LazyArrayIter16<'a, KerningPair>);
+
+impl Iterator for KerningPairs<'_> {
+    type Item = (GlyphId, GlyphId, i16);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .next()
+            .map(|pair| (pair.left(), pair.right(), pair.value))
+    }
+}
+
+impl core::fmt::Debug for KerningPairs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "KerningPairs {{ ... }}")
+    }
 }
 
 /// A list of subtables.
@@ -414,7 +447,7 @@ impl<'a> Subtable3<'a> {
         let left_class = left_hand_classes.get(left.0)?;
         let right_class = right_hand_classes.get(right.0)?;
 
-        if left_class > left_hand_classes_count || right_class > right_hand_classes_count {
+        if left_class >= left_hand_classes_count || right_class >= right_hand_classes_count {
             return None;
         }
 
@@ -74,6 +74,28 @@ impl<'a> Table<'a> {
     /// Returns glyph's range in the `glyf` table.
     #[inline]
     pub fn glyph_range(&self, glyph_id: GlyphId) -> Option<Range<usize>> {
+        let range = self.offsets(glyph_id)?;
+        if range.start >= range.end {
+            // 'The offsets must be in ascending order.'
+            // And range cannot be empty.
+            None
+        } else {
+            Some(range)
+        }
+    }
+
+    /// Checks that the glyph has no data in the `glyf` table.
+    ///
+    /// Returns `false` for out of bounds glyph IDs.
+    #[inline]
+    pub fn is_empty_glyph(&self, glyph_id: GlyphId) -> bool {
+        self.offsets(glyph_id)
+            .map(|range| range.start == range.end)
+            .unwrap_or(false)
+    }
+
+    #[inline]
+    pub(crate) fn offsets(&self, glyph_id: GlyphId) -> Option<Range<usize>> {
         let glyph_id = glyph_id.0;
         if glyph_id == u16::MAX {
             return None;
@@ -94,12 +116,6 @@ impl<'a> Table<'a> {
             }
         };
 
-        if range.start >= range.end {
-            // 'The offsets must be in ascending order.'
-            // And range cannot be empty.
-            None
-        } else {
-            Some(range)
-        }
+        Some(range)
     }
 }
@@ -0,0 +1,82 @@
+//! A [Metadata Table](
+//! https://docs.microsoft.com/en-us/typography/opentype/spec/meta) implementation.
+
+use crate::parser::{FromData, LazyArray32, NumFrom, Offset, Offset32, Stream};
+use crate::Tag;
+
+#[derive(Clone, Copy, Debug)]
+struct DataMap {
+    tag: Tag,
+    offset: Offset32,
+    length: u32,
+}
+
+impl FromData for DataMap {
+    const SIZE: usize = 12;
+
+    #[inline]
+    fn parse(data: &[u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        Some(DataMap {
+            tag: s.read::<Tag>()?,
+            offset: s.read::<Offset32>()?,
+            length: s.read::<u32>()?,
+        })
+    }
+}
+
+/// A [Metadata Table](https://docs.microsoft.com/en-us/typography/opentype/spec/meta).
+#[derive(Clone, Copy, Debug)]
+pub struct Table<'a> {
+    data: &'a [u8],
+    maps: LazyArray32<'a, DataMap>,
+}
+
+impl<'a> Table<'a> {
+    /// Parses a table from raw data.
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+
+        let version = s.read::<u32>()?;
+        if version != 1 {
+            return None;
+        }
+
+        s.skip::<u32>(); // flags
+        s.skip::<u32>(); // reserved
+        let count = s.read::<u32>()?;
+        let maps = s.read_array32::<DataMap>(count)?;
+
+        Some(Table { data, maps })
+    }
+
+    /// Returns the raw data of a metadata entry.
+    pub fn get(&self, tag: Tag) -> Option<&'a [u8]> {
+        let map = self.maps.into_iter().find(|m| m.tag == tag)?;
+        let start = map.offset.to_usize();
+        let end = start.checked_add(usize::num_from(map.length))?;
+        self.data.get(start..end)
+    }
+
+    /// Returns design languages.
+    ///
+    /// A comma-separated list of [BCP 47](https://www.rfc-editor.org/info/bcp47)
+    /// language tags the font was primarily designed for.
+    #[inline]
+    pub fn design_languages(&self) -> Option<&'a str> {
+        self.get_str(Tag::from_bytes(b"dlng"))
+    }
+
+    /// Returns supported languages.
+    ///
+    /// A comma-separated list of [BCP 47](https://www.rfc-editor.org/info/bcp47)
+    /// language tags the font is capable of supporting.
+    #[inline]
+    pub fn supported_languages(&self) -> Option<&'a str> {
+        self.get_str(Tag::from_bytes(b"slng"))
+    }
+
+    fn get_str(&self, tag: Tag) -> Option<&'a str> {
+        core::str::from_utf8(self.get(tag)?).ok()
+    }
+}
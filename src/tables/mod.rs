@@ -4,6 +4,8 @@ mod cff;
 pub mod cmap;
 pub mod colr;
 pub mod cpal;
+pub mod dsig;
+pub mod gasp;
 pub mod glyf;
 pub mod head;
 pub mod hhea;
@@ -11,6 +13,7 @@ pub mod hmtx;
 pub mod kern;
 pub mod loca;
 pub mod maxp;
+pub mod meta;
 pub mod name;
 pub mod os2;
 pub mod post;
@@ -20,6 +23,8 @@ pub mod svg;
 pub mod vhea;
 pub mod vorg;
 
+#[cfg(feature = "opentype-layout")]
+pub mod base;
 #[cfg(feature = "opentype-layout")]
 pub mod gdef;
 #[cfg(feature = "opentype-layout")]
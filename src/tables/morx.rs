@@ -1,7 +1,7 @@
 //! An [Extended Glyph Metamorphosis Table](
 //! https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6morx.html) implementation.
 
-// Note: We do not have tests for this table because it has a very complicated structure.
+// Note: We do not have tests for most of this table because it has a very complicated structure.
 // Specifically, the State Machine Tables. I have no idea how to generate them.
 // And all fonts that use this table are mainly Apple one, so we cannot use them for legal reasons.
 //
@@ -346,6 +346,26 @@ pub struct Chain<'a> {
     pub subtables: Subtables<'a>,
 }
 
+impl Chain<'_> {
+    /// Returns subtable flags enabled by the requested features.
+    ///
+    /// `features` is a list of feature type and setting pairs.
+    /// Starts with default flags and applies matching features in order.
+    pub fn flags(&self, features: &[(u16, u16)]) -> u32 {
+        let mut flags = self.default_flags;
+        for &(kind, setting) in features {
+            for feature in self.features {
+                if feature.kind == kind && feature.setting == setting {
+                    flags &= feature.disable_flags;
+                    flags |= feature.enable_flags;
+                }
+            }
+        }
+
+        flags
+    }
+}
+
 /// A list of metamorphosis chains.
 ///
 /// The internal data layout is not designed for random access,
@@ -467,4 +487,42 @@ impl<'a> Table<'a> {
     pub fn parse(number_of_glyphs: NonZeroU16, data: &'a [u8]) -> Option<Self> {
         Chains::parse(number_of_glyphs, data).map(|chains| Self { chains })
     }
+
+    /// Applies non-contextual substitutions to a glyph.
+    ///
+    /// `features` is a list of feature type and setting pairs.
+    /// See [`Chain::flags`] for details.
+    ///
+    /// All other subtable kinds are ignored,
+    /// as well as subtables that apply only to vertical text.
+    ///
+    /// Returns `None` when the glyph wasn't substituted.
+    pub fn substitute_non_contextual(
+        &self,
+        glyph_id: GlyphId,
+        features: &[(u16, u16)],
+    ) -> Option<GlyphId> {
+        let mut result = None;
+        for chain in self.chains {
+            let flags = chain.flags(features);
+            for subtable in chain.subtables {
+                if subtable.feature_flags & flags == 0 {
+                    continue;
+                }
+
+                if subtable.coverage.is_vertical() && !subtable.coverage.is_all_directions() {
+                    continue;
+                }
+
+                if let SubtableKind::NonContextual(ref lookup) = subtable.kind {
+                    let current = result.unwrap_or(glyph_id);
+                    if let Some(new_id) = lookup.value(current) {
+                        result = Some(GlyphId(new_id));
+                    }
+                }
+            }
+        }
+
+        result
+    }
 }
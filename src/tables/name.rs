@@ -234,6 +234,46 @@ impl<'a> Names<'a> {
         })
     }
 
+    /// Returns a name with the specified name ID, platform ID and language ID.
+    pub fn get_by_id(
+        &self,
+        name_id: u16,
+        platform_id: PlatformId,
+        language_id: u16,
+    ) -> Option<Name<'a>> {
+        self.into_iter().find(|name| {
+            name.name_id == name_id
+                && name.platform_id == platform_id
+                && name.language_id == language_id
+        })
+    }
+
+    /// Returns a name with the specified name ID.
+    ///
+    /// Prefers Windows English (United States) names, then any Unicode name
+    /// and then any name with this ID.
+    pub fn find(&self, name_id: u16) -> Option<Name<'a>> {
+        let mut fallback: Option<Name> = None;
+        for name in self.into_iter().filter(|name| name.name_id == name_id) {
+            if name.platform_id == PlatformId::Windows
+                && name.language() == Language::English_UnitedStates
+                && name.is_unicode()
+            {
+                return Some(name);
+            }
+
+            let is_better = match fallback {
+                Some(ref prev) => name.is_unicode() && !prev.is_unicode(),
+                None => true,
+            };
+            if is_better {
+                fallback = Some(name);
+            }
+        }
+
+        fallback
+    }
+
     /// Returns a number of name records.
     pub fn len(&self) -> u16 {
         self.records.len()
@@ -11,10 +11,11 @@ const UNDERLINE_POSITION_OFFSET: usize = 8;
 const UNDERLINE_THICKNESS_OFFSET: usize = 10;
 const IS_FIXED_PITCH_OFFSET: usize = 12;
 
-// https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6post.html
-/// A list of Macintosh glyph names.
+/// A list of the 258 [standard Macintosh glyph names](
+/// https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6post.html),
+/// indexed by their standard glyph index.
 #[cfg(feature = "glyph-names")]
-const MACINTOSH_NAMES: &[&str] = &[
+pub const STANDARD_MAC_GLYPH_NAMES: &[&str] = &[
     ".notdef",
     ".null",
     "nonmarkingreturn",
@@ -383,12 +384,12 @@ impl<'a> Table<'a> {
 
         // 'If the name index is between 0 and 257, treat the name index
         // as a glyph index in the Macintosh standard order.'
-        if usize::from(index) < MACINTOSH_NAMES.len() {
-            Some(MACINTOSH_NAMES[usize::from(index)])
+        if usize::from(index) < STANDARD_MAC_GLYPH_NAMES.len() {
+            Some(STANDARD_MAC_GLYPH_NAMES[usize::from(index)])
         } else {
             // 'If the name index is between 258 and 65535, then subtract 258 and use that
             // to index into the list of Pascal strings at the end of the table.'
-            index -= MACINTOSH_NAMES.len() as u16;
+            index -= STANDARD_MAC_GLYPH_NAMES.len() as u16;
             self.names().nth(usize::from(index))
         }
     }
@@ -396,13 +397,13 @@ impl<'a> Table<'a> {
     /// Returns a glyph ID by a name.
     #[cfg(feature = "glyph-names")]
     pub fn glyph_index_by_name(&self, name: &str) -> Option<GlyphId> {
-        let id = if let Some(index) = MACINTOSH_NAMES.iter().position(|n| *n == name) {
+        let id = if let Some(index) = STANDARD_MAC_GLYPH_NAMES.iter().position(|n| *n == name) {
             self.glyph_indexes
                 .into_iter()
                 .position(|i| usize::from(i) == index)?
         } else {
             let mut index = self.names().position(|n| n == name)?;
-            index += MACINTOSH_NAMES.len();
+            index += STANDARD_MAC_GLYPH_NAMES.len();
             self.glyph_indexes
                 .into_iter()
                 .position(|i| usize::from(i) == index)?
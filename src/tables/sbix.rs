@@ -17,10 +17,12 @@ pub struct Strike<'a> {
     offsets: LazyArray16<'a, Offset32>,
     /// Data from the beginning of the `Strikes` table.
     data: &'a [u8],
+    /// `data` offset from the beginning of the `sbix` table.
+    offset: usize,
 }
 
 impl<'a> Strike<'a> {
-    fn parse(number_of_glyphs: u16, data: &'a [u8]) -> Option<Self> {
+    fn parse(number_of_glyphs: u16, data: &'a [u8], offset: usize) -> Option<Self> {
         let mut s = Stream::new(data);
         let pixels_per_em = s.read::<u16>()?;
         let ppi = s.read::<u16>()?;
@@ -30,10 +32,13 @@ impl<'a> Strike<'a> {
             ppi,
             offsets,
             data,
+            offset,
         })
     }
 
     /// Returns a glyph data.
+    ///
+    /// [`RasterGlyphImage::data_offset`] is relative to the start of the `sbix` table.
     pub fn get(&self, glyph_id: GlyphId) -> Option<RasterGlyphImage<'a>> {
         self.get_inner(glyph_id, 0)
     }
@@ -57,6 +62,7 @@ impl<'a> Strike<'a> {
         let x = s.read::<i16>()?;
         let y = s.read::<i16>()?;
         let image_type = s.read::<Tag>()?;
+        let image_start = self.offset + s.offset();
         let image_data = s.read_bytes(data_len)?;
 
         // We do ignore `pdf` and `mask` intentionally, because Apple docs state that:
@@ -89,6 +95,7 @@ impl<'a> Strike<'a> {
             pixels_per_em: self.pixels_per_em,
             format,
             data: image_data,
+            data_offset: u32::try_from(image_start).ok()?,
         })
     }
 
@@ -127,7 +134,7 @@ impl<'a> Strikes<'a> {
     pub fn get(&self, index: u32) -> Option<Strike<'a>> {
         let offset = self.offsets.get(index)?.to_usize();
         let data = self.data.get(offset..)?;
-        Strike::parse(self.number_of_glyphs, data)
+        Strike::parse(self.number_of_glyphs, data, offset)
     }
 
     /// Returns the number of strikes.
@@ -337,6 +337,88 @@ impl<'a> AxisValueSubtable<'a> {
     }
 }
 
+/// A style attribute, which is an axis value with a resolved axis tag.
+///
+/// Produced by [`Table::style_attributes`].
+#[derive(Clone, Copy, Debug)]
+pub struct StyleAttribute {
+    /// Axis tag.
+    pub axis: Tag,
+    /// Axis value.
+    ///
+    /// For format 2 subtables, this is the nominal value.
+    pub value: Fixed,
+    /// Axis value of a style-linked font, like Bold for Regular.
+    ///
+    /// Set only for format 3 subtables.
+    pub linked_value: Option<Fixed>,
+    /// Axis value flags.
+    pub flags: AxisValueFlags,
+    /// The name ID of the display string.
+    pub name_id: u16,
+}
+
+/// An iterator over [`StyleAttribute`]s.
+#[derive(Clone, Debug)]
+pub struct StyleAttributes<'a> {
+    axes: LazyArray16<'a, AxisRecord>,
+    subtables: AxisValueSubtables<'a>,
+    // Format 4 subtable and the next value index in it.
+    pending: Option<(AxisValueSubtableFormat4<'a>, u16)>,
+}
+
+impl<'a> Iterator for StyleAttributes<'a> {
+    type Item = StyleAttribute;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((subtable, index)) = self.pending {
+                if let Some(value) = subtable.values.get(index) {
+                    self.pending = Some((subtable, index + 1));
+                    let axis = match self.axes.get(value.axis_index) {
+                        Some(v) => v.tag,
+                        None => continue,
+                    };
+
+                    return Some(StyleAttribute {
+                        axis,
+                        value: value.value,
+                        linked_value: None,
+                        flags: subtable.flags,
+                        name_id: subtable.value_name_id,
+                    });
+                }
+
+                self.pending = None;
+            }
+
+            let subtable = self.subtables.next()?;
+            let (axis_index, value, linked_value) = match subtable {
+                AxisValueSubtable::Format1(v) => (v.axis_index, v.value, None),
+                AxisValueSubtable::Format2(v) => (v.axis_index, v.nominal_value, None),
+                AxisValueSubtable::Format3(v) => (v.axis_index, v.value, Some(v.linked_value)),
+                AxisValueSubtable::Format4(v) => {
+                    self.pending = Some((v, 0));
+                    continue;
+                }
+            };
+
+            let axis = match self.axes.get(axis_index) {
+                Some(v) => v.tag,
+                None => continue,
+            };
+
+            return Some(StyleAttribute {
+                axis,
+                value,
+                linked_value,
+                flags: subtable.flags(),
+                name_id: subtable.name_id(),
+            });
+        }
+    }
+}
+
 /// A [Style Attributes Table](https://docs.microsoft.com/en-us/typography/opentype/spec/stat).
 #[derive(Clone, Copy, Debug)]
 pub struct Table<'a> {
@@ -405,6 +487,19 @@ impl<'a> Table<'a> {
         }
     }
 
+    /// Returns an iterator over all axis values with resolved axis tags.
+    ///
+    /// Format 4 subtables produce an attribute for each of their axis values,
+    /// all sharing the same flags and name ID.
+    /// Values referencing a nonexistent axis are skipped.
+    pub fn style_attributes(&self) -> StyleAttributes<'a> {
+        StyleAttributes {
+            axes: self.axes,
+            subtables: self.subtables(),
+            pending: None,
+        }
+    }
+
     /// Returns the first matching subtable for a given axis.
     ///
     /// If no match value is given the first subtable for the axis is returned. If a match value is
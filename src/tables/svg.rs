@@ -22,6 +22,12 @@ impl SvgDocument<'_> {
     pub fn glyphs_range(&self) -> core::ops::RangeInclusive<GlyphId> {
         self.start_glyph_id..=self.end_glyph_id
     }
+
+    /// Checks that the document is gzip compressed, aka SVGZ.
+    #[inline]
+    pub fn is_gzip(&self) -> bool {
+        self.data.starts_with(&[0x1F, 0x8B])
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -130,6 +130,37 @@ impl<'a> TrackData<'a> {
 
         Some(TrackData { tracks, sizes })
     }
+
+    /// Returns a tracking value in font units for the specified point size and track.
+    ///
+    /// `track` must match one of the [`Track::value`] exactly. Usually,
+    /// 0 is normal, -1 is tight and 1 is loose.
+    ///
+    /// Values between sizes are interpolated linearly
+    /// and values outside the sizes range are clamped.
+    pub fn tracking(&self, point_size: f32, track: f32) -> Option<f32> {
+        let track = self.tracks.into_iter().find(|t| t.value == track)?;
+
+        let mut prev: Option<(f32, f32)> = None;
+        for (size, value) in self.sizes.into_iter().zip(track.values) {
+            let size = size.0;
+            let value = f32::from(value);
+            if point_size <= size {
+                return Some(match prev {
+                    Some((prev_size, prev_value)) if size > prev_size => {
+                        let t = (point_size - prev_size) / (size - prev_size);
+                        prev_value + (value - prev_value) * t
+                    }
+                    _ => value,
+                });
+            }
+
+            prev = Some((size, value));
+        }
+
+        // Larger than the last size.
+        prev.map(|(_, value)| value)
+    }
 }
 
 /// A [Tracking Table](
@@ -55,6 +55,22 @@ impl<'a> Table<'a> {
         Some(Table { default_y, metrics })
     }
 
+    /// Returns the default Y origin.
+    ///
+    /// Used by all glyphs that are not listed in `metrics`.
+    #[inline]
+    pub fn default_y(&self) -> i16 {
+        self.default_y
+    }
+
+    /// Returns an iterator over glyphs with an explicit Y origin.
+    ///
+    /// Ordered by glyph ID.
+    #[inline]
+    pub fn entries(&self) -> impl Iterator<Item = (GlyphId, i16)> + 'a {
+        self.metrics.into_iter().map(|m| (m.glyph_id, m.y))
+    }
+
     /// Returns glyph's Y origin.
     pub fn glyph_y_origin(&self, glyph_id: GlyphId) -> i16 {
         self.metrics
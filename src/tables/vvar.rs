@@ -42,23 +42,38 @@ impl<'a> Table<'a> {
         })
     }
 
-    /// Returns the advance height offset for a glyph.
+    /// Checks that the table has an explicit advance height mapping.
+    ///
+    /// Otherwise glyph IDs are used as delta-set indices directly.
     #[inline]
-    pub fn advance_offset(
-        &self,
-        glyph_id: GlyphId,
-        coordinates: &[NormalizedCoordinate],
-    ) -> Option<f32> {
-        let (outer_idx, inner_idx) = if let Some(offset) = self.advance_height_mapping_offset {
-            DeltaSetIndexMap::new(self.data.get(offset.to_usize()..)?).map(glyph_id.0 as u32)?
+    pub fn has_advance_map(&self) -> bool {
+        self.advance_height_mapping_offset.is_some()
+    }
+
+    /// Returns the delta-set outer and inner indices used by the advance height of a glyph.
+    ///
+    /// Returns `None` when the mapping is malformed.
+    #[inline]
+    pub fn advance_index(&self, glyph_id: GlyphId) -> Option<(u16, u16)> {
+        if let Some(offset) = self.advance_height_mapping_offset {
+            DeltaSetIndexMap::new(self.data.get(offset.to_usize()..)?).map(glyph_id.0 as u32)
         } else {
             // 'If there is no delta-set index mapping table for advance widths,
             // then glyph IDs implicitly provide the indices:
             // for a given glyph ID, the delta-set outer-level index is zero,
             // and the glyph ID is the delta-set inner-level index.'
-            (0, glyph_id.0)
-        };
+            Some((0, glyph_id.0))
+        }
+    }
 
+    /// Returns the advance height offset for a glyph.
+    #[inline]
+    pub fn advance_offset(
+        &self,
+        glyph_id: GlyphId,
+        coordinates: &[NormalizedCoordinate],
+    ) -> Option<f32> {
+        let (outer_idx, inner_idx) = self.advance_index(glyph_id)?;
         self.variation_store
             .parse_delta(outer_idx, inner_idx, coordinates)
     }
@@ -0,0 +1,114 @@
+//! Well-known [`Tag`] constants.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ttf_parser::{tags, Tag};
+//!
+//! assert_eq!(tags::WGHT, Tag::from_bytes(b"wght"));
+//! assert_eq!(tags::OS_2, Tag::from_bytes(b"OS/2"));
+//! ```
+
+use crate::Tag;
+
+// Registered variation axes.
+/// The `ital` variation axis.
+pub const ITAL: Tag = Tag::from_bytes(b"ital");
+/// The `opsz` variation axis.
+pub const OPSZ: Tag = Tag::from_bytes(b"opsz");
+/// The `slnt` variation axis.
+pub const SLNT: Tag = Tag::from_bytes(b"slnt");
+/// The `wdth` variation axis.
+pub const WDTH: Tag = Tag::from_bytes(b"wdth");
+/// The `wght` variation axis.
+pub const WGHT: Tag = Tag::from_bytes(b"wght");
+
+// Tables.
+/// The `ankr` table.
+pub const ANKR: Tag = Tag::from_bytes(b"ankr");
+/// The `avar` table.
+pub const AVAR: Tag = Tag::from_bytes(b"avar");
+/// The `BASE` table.
+pub const BASE: Tag = Tag::from_bytes(b"BASE");
+/// The `CBDT` table.
+pub const CBDT: Tag = Tag::from_bytes(b"CBDT");
+/// The `CBLC` table.
+pub const CBLC: Tag = Tag::from_bytes(b"CBLC");
+/// The `CFF` table.
+pub const CFF: Tag = Tag::from_bytes(b"CFF ");
+/// The `CFF2` table.
+pub const CFF2: Tag = Tag::from_bytes(b"CFF2");
+/// The `cmap` table.
+pub const CMAP: Tag = Tag::from_bytes(b"cmap");
+/// The `COLR` table.
+pub const COLR: Tag = Tag::from_bytes(b"COLR");
+/// The `CPAL` table.
+pub const CPAL: Tag = Tag::from_bytes(b"CPAL");
+/// The `DSIG` table.
+pub const DSIG: Tag = Tag::from_bytes(b"DSIG");
+/// The `EBDT` table.
+pub const EBDT: Tag = Tag::from_bytes(b"EBDT");
+/// The `EBLC` table.
+pub const EBLC: Tag = Tag::from_bytes(b"EBLC");
+/// The `feat` table.
+pub const FEAT: Tag = Tag::from_bytes(b"feat");
+/// The `fvar` table.
+pub const FVAR: Tag = Tag::from_bytes(b"fvar");
+/// The `gasp` table.
+pub const GASP: Tag = Tag::from_bytes(b"gasp");
+/// The `GDEF` table.
+pub const GDEF: Tag = Tag::from_bytes(b"GDEF");
+/// The `glyf` table.
+pub const GLYF: Tag = Tag::from_bytes(b"glyf");
+/// The `GPOS` table.
+pub const GPOS: Tag = Tag::from_bytes(b"GPOS");
+/// The `GSUB` table.
+pub const GSUB: Tag = Tag::from_bytes(b"GSUB");
+/// The `gvar` table.
+pub const GVAR: Tag = Tag::from_bytes(b"gvar");
+/// The `head` table.
+pub const HEAD: Tag = Tag::from_bytes(b"head");
+/// The `hhea` table.
+pub const HHEA: Tag = Tag::from_bytes(b"hhea");
+/// The `hmtx` table.
+pub const HMTX: Tag = Tag::from_bytes(b"hmtx");
+/// The `HVAR` table.
+pub const HVAR: Tag = Tag::from_bytes(b"HVAR");
+/// The `kern` table.
+pub const KERN: Tag = Tag::from_bytes(b"kern");
+/// The `kerx` table.
+pub const KERX: Tag = Tag::from_bytes(b"kerx");
+/// The `loca` table.
+pub const LOCA: Tag = Tag::from_bytes(b"loca");
+/// The `MATH` table.
+pub const MATH: Tag = Tag::from_bytes(b"MATH");
+/// The `maxp` table.
+pub const MAXP: Tag = Tag::from_bytes(b"maxp");
+/// The `meta` table.
+pub const META: Tag = Tag::from_bytes(b"meta");
+/// The `morx` table.
+pub const MORX: Tag = Tag::from_bytes(b"morx");
+/// The `MVAR` table.
+pub const MVAR: Tag = Tag::from_bytes(b"MVAR");
+/// The `name` table.
+pub const NAME: Tag = Tag::from_bytes(b"name");
+/// The `OS/2` table.
+pub const OS_2: Tag = Tag::from_bytes(b"OS/2");
+/// The `post` table.
+pub const POST: Tag = Tag::from_bytes(b"post");
+/// The `sbix` table.
+pub const SBIX: Tag = Tag::from_bytes(b"sbix");
+/// The `STAT` table.
+pub const STAT: Tag = Tag::from_bytes(b"STAT");
+/// The `SVG` table.
+pub const SVG: Tag = Tag::from_bytes(b"SVG ");
+/// The `trak` table.
+pub const TRAK: Tag = Tag::from_bytes(b"trak");
+/// The `vhea` table.
+pub const VHEA: Tag = Tag::from_bytes(b"vhea");
+/// The `vmtx` table.
+pub const VMTX: Tag = Tag::from_bytes(b"vmtx");
+/// The `VORG` table.
+pub const VORG: Tag = Tag::from_bytes(b"VORG");
+/// The `VVAR` table.
+pub const VVAR: Tag = Tag::from_bytes(b"VVAR");
@@ -0,0 +1,94 @@
+//! A [WOFF](https://www.w3.org/TR/WOFF/) decoder.
+//!
+//! Unlike the rest of the crate, requires heap allocations.
+
+use std::borrow::Cow;
+use std::vec::Vec;
+
+use crate::parser::{NumFrom, Stream};
+use crate::sfnt;
+use crate::{FaceParsingError, Tag};
+
+const WOFF_MAGIC: u32 = 0x774F4646; // wOFF
+const HEADER_SIZE: usize = 44;
+const TABLE_RECORD_SIZE: usize = 20;
+// zlib cannot compress better than ~1032:1.
+const MAX_COMPRESSION_RATIO: usize = 1032;
+
+/// Checks that the data starts with a WOFF signature.
+pub fn is_woff(data: &[u8]) -> bool {
+    Stream::new(data).read::<u32>() == Some(WOFF_MAGIC)
+}
+
+/// Decodes WOFF data into a plain TrueType/OpenType font.
+///
+/// All tables are decompressed, while metadata and private data blocks are ignored.
+pub fn decode(data: &[u8]) -> Result<Vec<u8>, FaceParsingError> {
+    if !is_woff(data) {
+        return Err(FaceParsingError::UnknownMagic);
+    }
+
+    decode_impl(data).ok_or(FaceParsingError::MalformedFont)
+}
+
+fn decode_impl(data: &[u8]) -> Option<Vec<u8>> {
+    let mut s = Stream::new(data);
+    s.skip::<u32>(); // signature
+    let flavor = s.read::<u32>()?;
+    s.skip::<u32>(); // length
+    let num_tables = s.read::<u16>()?;
+    s.skip::<u16>(); // reserved
+    let total_sfnt_size = usize::num_from(s.read::<u32>()?);
+    s.advance_checked(HEADER_SIZE - s.offset())?;
+
+    // Decompressed tables must fit into the declared font size,
+    // which cannot exceed what zlib can produce from the input.
+    // Prevents decompression bombs, since the tables length is untrusted.
+    if total_sfnt_size > data.len().saturating_mul(MAX_COMPRESSION_RATIO) {
+        return None;
+    }
+    let mut budget = total_sfnt_size;
+
+    // Must fit all table records, which we're about to reserve.
+    if s.tail()?.len() < usize::from(num_tables) * TABLE_RECORD_SIZE {
+        return None;
+    }
+
+    let mut tables = Vec::with_capacity(usize::from(num_tables));
+    for _ in 0..num_tables {
+        let tag = s.read::<Tag>()?;
+        let offset = s.read::<u32>()?;
+        let comp_length = s.read::<u32>()?;
+        let orig_length = s.read::<u32>()?;
+        let orig_checksum = s.read::<u32>()?;
+
+        let start = usize::num_from(offset);
+        let end = start.checked_add(usize::num_from(comp_length))?;
+        let table_data = data.get(start..end)?;
+        budget = budget.checked_sub(usize::num_from(orig_length))?;
+
+        let table_data = if comp_length == orig_length {
+            Cow::Borrowed(table_data)
+        } else if comp_length < orig_length {
+            let orig_length = usize::num_from(orig_length);
+            let table =
+                miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(table_data, orig_length)
+                    .ok()?;
+            if table.len() != orig_length {
+                return None;
+            }
+
+            Cow::Owned(table)
+        } else {
+            return None;
+        };
+
+        tables.push(sfnt::Table {
+            tag,
+            checksum: orig_checksum,
+            data: table_data,
+        });
+    }
+
+    sfnt::write(flavor, &mut tables)
+}
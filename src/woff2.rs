@@ -0,0 +1,675 @@
+//! A [WOFF2](https://www.w3.org/TR/WOFF2/) decoder.
+//!
+//! Unlike the rest of the crate, requires heap allocations.
+
+use core::convert::TryFrom;
+use std::borrow::Cow;
+use std::io::Read;
+use std::vec::Vec;
+
+use crate::parser::{NumFrom, Stream};
+use crate::sfnt;
+use crate::{FaceParsingError, Tag};
+
+const WOFF2_MAGIC: u32 = 0x774F4632; // wOF2
+const COLLECTION_FLAVOR: u32 = 0x74746366; // ttcf
+
+// Unlike zlib, Brotli has no hard compression limit,
+// so use a generous bound that real fonts never come close to.
+const MAX_COMPRESSION_RATIO: usize = 4096;
+
+const GLYF: Tag = Tag::from_bytes(b"glyf");
+const LOCA: Tag = Tag::from_bytes(b"loca");
+const HMTX: Tag = Tag::from_bytes(b"hmtx");
+const HHEA: Tag = Tag::from_bytes(b"hhea");
+const MAXP: Tag = Tag::from_bytes(b"maxp");
+
+// https://www.w3.org/TR/WOFF2/#table_dir_format
+#[rustfmt::skip]
+const KNOWN_TAGS: [&[u8; 4]; 63] = [
+    b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post",
+    b"cvt ", b"fpgm", b"glyf", b"loca", b"prep", b"CFF ", b"VORG", b"EBDT",
+    b"EBLC", b"gasp", b"hdmx", b"kern", b"LTSH", b"PCLT", b"VDMX", b"vhea",
+    b"vmtx", b"BASE", b"GDEF", b"GPOS", b"GSUB", b"EBSC", b"JSTF", b"MATH",
+    b"CBDT", b"CBLC", b"COLR", b"CPAL", b"SVG ", b"sbix", b"acnt", b"avar",
+    b"bdat", b"bloc", b"bsln", b"cvar", b"fdsc", b"feat", b"fmtx", b"fvar",
+    b"gvar", b"hsty", b"just", b"lcar", b"mort", b"morx", b"opbd", b"prop",
+    b"trak", b"Zapf", b"Silf", b"Glat", b"Gloc", b"Feat", b"Sill",
+];
+
+struct TableEntry {
+    tag: Tag,
+    orig_length: u32,
+    is_transformed: bool,
+    // A range in the decompressed stream.
+    range: core::ops::Range<usize>,
+}
+
+struct Font {
+    flavor: u32,
+    tables: Vec<u16>,
+}
+
+struct Directory<'a> {
+    tables: Vec<TableEntry>,
+    fonts: Vec<Font>,
+    compressed: &'a [u8],
+    uncompressed_size: usize,
+}
+
+/// Checks that the data starts with a WOFF2 signature.
+pub fn is_woff2(data: &[u8]) -> bool {
+    Stream::new(data).read::<u32>() == Some(WOFF2_MAGIC)
+}
+
+/// Decodes WOFF2 data into a plain TrueType/OpenType font.
+///
+/// `index` indicates the specific font face in a font collection.
+/// Set to 0 if unsure.
+///
+/// Transformed `glyf`, `loca` and `hmtx` tables are reconstructed.
+/// Metadata and private data blocks are ignored.
+pub fn decode(data: &[u8], index: u32) -> Result<Vec<u8>, FaceParsingError> {
+    if !is_woff2(data) {
+        return Err(FaceParsingError::UnknownMagic);
+    }
+
+    let directory = Directory::parse(data).ok_or(FaceParsingError::MalformedFont)?;
+    let font = usize::try_from(index)
+        .ok()
+        .and_then(|index| directory.fonts.get(index))
+        .ok_or(FaceParsingError::FaceIndexOutOfBounds)?;
+
+    let mut stream = Vec::new();
+    brotli_decompressor::Decompressor::new(directory.compressed, 4096)
+        .take(directory.uncompressed_size as u64)
+        .read_to_end(&mut stream)
+        .map_err(|_| FaceParsingError::MalformedFont)?;
+
+    if stream.len() != directory.uncompressed_size {
+        return Err(FaceParsingError::MalformedFont);
+    }
+
+    decode_font(&directory, font, &stream).ok_or(FaceParsingError::MalformedFont)
+}
+
+impl<'a> Directory<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut s = Stream::new(data);
+        s.skip::<u32>(); // signature
+        let flavor = s.read::<u32>()?;
+        s.skip::<u32>(); // length
+        let num_tables = s.read::<u16>()?;
+        s.skip::<u16>(); // reserved
+        let total_sfnt_size = usize::num_from(s.read::<u32>()?);
+        let total_compressed_size = s.read::<u32>()?;
+        s.skip::<u16>(); // majorVersion
+        s.skip::<u16>(); // minorVersion
+        s.skip::<u32>(); // metaOffset
+        s.skip::<u32>(); // metaLength
+        s.skip::<u32>(); // metaOrigLength
+        s.skip::<u32>(); // privOffset
+        s.skip::<u32>(); // privLength
+
+        let mut tables = Vec::with_capacity(usize::from(num_tables));
+        let mut offset = 0usize;
+        for _ in 0..num_tables {
+            let flags = s.read::<u8>()?;
+            let tag = match KNOWN_TAGS.get(usize::from(flags & 0x3F)) {
+                Some(tag) => Tag::from_bytes(tag),
+                None => s.read::<Tag>()?,
+            };
+
+            // For `glyf` and `loca`, the transform version 0 indicates a transformed table,
+            // while for all other tables it indicates a null transform.
+            let transform_version = flags >> 6;
+            let is_transformed = if tag == GLYF || tag == LOCA {
+                transform_version == 0
+            } else {
+                transform_version != 0
+            };
+
+            let orig_length = read_uint_base128(&mut s)?;
+            let length = if is_transformed {
+                read_uint_base128(&mut s)?
+            } else {
+                orig_length
+            };
+
+            let end = offset.checked_add(usize::num_from(length))?;
+            tables.push(TableEntry {
+                tag,
+                orig_length,
+                is_transformed,
+                range: offset..end,
+            });
+            offset = end;
+        }
+
+        // The decompressed stream must fit into the declared font size,
+        // which cannot exceed what Brotli can reasonably produce from the input.
+        // Prevents decompression bombs, since the tables length is untrusted.
+        if offset > total_sfnt_size
+            || total_sfnt_size > data.len().saturating_mul(MAX_COMPRESSION_RATIO)
+        {
+            return None;
+        }
+
+        let fonts = if flavor == COLLECTION_FLAVOR {
+            s.skip::<u32>(); // version
+            let num_fonts = read_255_u16(&mut s)?;
+            let mut fonts = Vec::with_capacity(usize::from(num_fonts));
+            for _ in 0..num_fonts {
+                let num_tables = read_255_u16(&mut s)?;
+                let flavor = s.read::<u32>()?;
+                let mut font_tables = Vec::with_capacity(usize::from(num_tables));
+                for _ in 0..num_tables {
+                    let index = read_255_u16(&mut s)?;
+                    if usize::from(index) >= tables.len() {
+                        return None;
+                    }
+
+                    font_tables.push(index);
+                }
+
+                fonts.push(Font {
+                    flavor,
+                    tables: font_tables,
+                });
+            }
+
+            fonts
+        } else {
+            std::vec![Font {
+                flavor,
+                tables: (0..num_tables).collect(),
+            }]
+        };
+
+        let compressed = s.read_bytes(usize::num_from(total_compressed_size))?;
+
+        Some(Directory {
+            tables,
+            fonts,
+            compressed,
+            uncompressed_size: offset,
+        })
+    }
+}
+
+fn decode_font(directory: &Directory, font: &Font, stream: &[u8]) -> Option<Vec<u8>> {
+    let mut tables = Vec::with_capacity(font.tables.len());
+    let mut x_mins = None;
+    let mut transformed_hmtx = None;
+    for &index in &font.tables {
+        let entry = directory.tables.get(usize::from(index))?;
+        let data = stream.get(entry.range.clone())?;
+
+        if !entry.is_transformed {
+            if data.len() != usize::num_from(entry.orig_length) {
+                return None;
+            }
+
+            tables.push(sfnt::Table::new(entry.tag, Cow::Borrowed(data)));
+            continue;
+        }
+
+        match entry.tag {
+            GLYF => {
+                let glyf = reconstruct_glyf(data)?;
+                tables.push(sfnt::Table::new(GLYF, Cow::Owned(glyf.glyf)));
+                tables.push(sfnt::Table::new(LOCA, Cow::Owned(glyf.loca)));
+                x_mins = Some(glyf.x_mins);
+            }
+            LOCA => {
+                // Reconstructed together with `glyf`.
+                if !entry.range.is_empty() {
+                    return None;
+                }
+            }
+            HMTX => transformed_hmtx = Some(data),
+            _ => return None,
+        }
+    }
+
+    if let Some(data) = transformed_hmtx {
+        let x_mins = x_mins.as_deref()?;
+        let hhea = tables.iter().find(|t| t.tag == HHEA)?;
+        let maxp = tables.iter().find(|t| t.tag == MAXP)?;
+        let number_of_h_metrics = Stream::read_at::<u16>(&hhea.data, 34)?;
+        let number_of_glyphs = Stream::read_at::<u16>(&maxp.data, 4)?;
+        let hmtx = reconstruct_hmtx(data, number_of_glyphs, number_of_h_metrics, x_mins)?;
+        tables.push(sfnt::Table::new(HMTX, Cow::Owned(hmtx)));
+    }
+
+    sfnt::write(font.flavor, &mut tables)
+}
+
+struct Glyf {
+    glyf: Vec<u8>,
+    loca: Vec<u8>,
+    x_mins: Vec<i16>,
+}
+
+// https://www.w3.org/TR/WOFF2/#glyf_table_format
+fn reconstruct_glyf(data: &[u8]) -> Option<Glyf> {
+    let mut s = Stream::new(data);
+    s.skip::<u16>(); // reserved
+    let option_flags = s.read::<u16>()?;
+    let number_of_glyphs = s.read::<u16>()?;
+    let index_format = s.read::<u16>()?;
+    let n_contour_stream_size = s.read::<u32>()?;
+    let n_points_stream_size = s.read::<u32>()?;
+    let flag_stream_size = s.read::<u32>()?;
+    let glyph_stream_size = s.read::<u32>()?;
+    let composite_stream_size = s.read::<u32>()?;
+    let bbox_stream_size = s.read::<u32>()?;
+    let instruction_stream_size = s.read::<u32>()?;
+
+    let mut n_contour_stream = Stream::new(s.read_bytes(usize::num_from(n_contour_stream_size))?);
+    let mut n_points_stream = Stream::new(s.read_bytes(usize::num_from(n_points_stream_size))?);
+    let mut flag_stream = Stream::new(s.read_bytes(usize::num_from(flag_stream_size))?);
+    let mut glyph_stream = Stream::new(s.read_bytes(usize::num_from(glyph_stream_size))?);
+    let composite_data = s.read_bytes(usize::num_from(composite_stream_size))?;
+    let mut bbox_stream = Stream::new(s.read_bytes(usize::num_from(bbox_stream_size))?);
+    let mut instruction_stream =
+        Stream::new(s.read_bytes(usize::num_from(instruction_stream_size))?);
+
+    let overlap_bitmap = if option_flags & 0x0001 != 0 {
+        Some(s.read_bytes((usize::from(number_of_glyphs) + 7) / 8)?)
+    } else {
+        None
+    };
+
+    let bbox_bitmap = bbox_stream.read_bytes((usize::from(number_of_glyphs) + 31) / 32 * 4)?;
+
+    let mut composite_offset = 0;
+    let mut glyf = Vec::new();
+    let mut offsets = Vec::with_capacity(usize::from(number_of_glyphs) + 1);
+    let mut x_mins = Vec::with_capacity(usize::from(number_of_glyphs));
+    for glyph_id in 0..usize::from(number_of_glyphs) {
+        offsets.push(glyf.len());
+
+        let has_bbox = is_bit_set(bbox_bitmap, glyph_id);
+        let number_of_contours = n_contour_stream.read::<i16>()?;
+        if number_of_contours == 0 {
+            // An empty glyph must not have a bbox.
+            if has_bbox {
+                return None;
+            }
+
+            x_mins.push(0);
+            continue;
+        }
+
+        if number_of_contours == -1 {
+            // A composite glyph must have an explicit bbox.
+            if !has_bbox {
+                return None;
+            }
+
+            let bbox = bbox_stream.read_bytes(8)?;
+            let (components, has_instructions) =
+                read_composite_glyph(composite_data, &mut composite_offset)?;
+
+            glyf.extend_from_slice(&number_of_contours.to_be_bytes());
+            glyf.extend_from_slice(bbox);
+            glyf.extend_from_slice(components);
+            if has_instructions {
+                let len = read_255_u16(&mut glyph_stream)?;
+                let instructions = instruction_stream.read_bytes(usize::from(len))?;
+                glyf.extend_from_slice(&len.to_be_bytes());
+                glyf.extend_from_slice(instructions);
+            }
+
+            x_mins.push(Stream::read_at::<i16>(bbox, 0)?);
+        } else if number_of_contours > 0 {
+            let has_overlap = overlap_bitmap
+                .map(|bitmap| is_bit_set(bitmap, glyph_id))
+                .unwrap_or(false);
+
+            let x_min = write_simple_glyph(
+                number_of_contours,
+                has_bbox,
+                has_overlap,
+                &mut n_points_stream,
+                &mut flag_stream,
+                &mut glyph_stream,
+                &mut bbox_stream,
+                &mut instruction_stream,
+                &mut glyf,
+            )?;
+            x_mins.push(x_min);
+        } else {
+            return None;
+        }
+
+        // Glyphs are 4-byte aligned.
+        while glyf.len() % 4 != 0 {
+            glyf.push(0);
+        }
+    }
+
+    offsets.push(glyf.len());
+
+    let mut loca = Vec::new();
+    if index_format == 0 {
+        loca.reserve(offsets.len() * 2);
+        for offset in offsets {
+            let offset = u16::try_from(offset / 2).ok()?;
+            loca.extend_from_slice(&offset.to_be_bytes());
+        }
+    } else {
+        loca.reserve(offsets.len() * 4);
+        for offset in offsets {
+            let offset = u32::try_from(offset).ok()?;
+            loca.extend_from_slice(&offset.to_be_bytes());
+        }
+    }
+
+    Some(Glyf { glyf, loca, x_mins })
+}
+
+/// Writes a simple glyph and returns its `x_min`.
+#[allow(clippy::too_many_arguments)]
+fn write_simple_glyph(
+    number_of_contours: i16,
+    has_bbox: bool,
+    has_overlap: bool,
+    n_points_stream: &mut Stream,
+    flag_stream: &mut Stream,
+    glyph_stream: &mut Stream,
+    bbox_stream: &mut Stream,
+    instruction_stream: &mut Stream,
+    glyf: &mut Vec<u8>,
+) -> Option<i16> {
+    const ON_CURVE_POINT: u8 = 0x01;
+    const X_SHORT_VECTOR: u8 = 0x02;
+    const Y_SHORT_VECTOR: u8 = 0x04;
+    const X_IS_SAME_OR_POSITIVE: u8 = 0x10;
+    const Y_IS_SAME_OR_POSITIVE: u8 = 0x20;
+    const OVERLAP_SIMPLE: u8 = 0x40;
+
+    let mut end_points = Vec::with_capacity(number_of_contours as usize);
+    let mut number_of_points = 0u32;
+    for _ in 0..number_of_contours {
+        let n = read_255_u16(n_points_stream)?;
+        if n == 0 {
+            return None;
+        }
+
+        number_of_points += u32::from(n);
+        end_points.push(u16::try_from(number_of_points - 1).ok()?);
+    }
+
+    let mut flags = Vec::with_capacity(usize::num_from(number_of_points));
+    let mut x_coords = Vec::new();
+    let mut y_coords = Vec::new();
+    let (mut x, mut y) = (0i32, 0i32);
+    let (mut x_min, mut y_min, mut x_max, mut y_max) = (i32::MAX, i32::MAX, i32::MIN, i32::MIN);
+    for _ in 0..number_of_points {
+        let flag = flag_stream.read::<u8>()?;
+        let (dx, dy) = read_triplet(flag & 0x7F, glyph_stream)?;
+        // Deltas are untrusted, so the accumulated coordinates can overflow.
+        x = x.checked_add(dx)?;
+        y = y.checked_add(dy)?;
+        x_min = x_min.min(x);
+        y_min = y_min.min(y);
+        x_max = x_max.max(x);
+        y_max = y_max.max(y);
+
+        let mut out_flag = if flag & 0x80 == 0 { ON_CURVE_POINT } else { 0 };
+        if flags.is_empty() && has_overlap {
+            out_flag |= OVERLAP_SIMPLE;
+        }
+
+        out_flag |= write_delta(dx, X_SHORT_VECTOR, X_IS_SAME_OR_POSITIVE, &mut x_coords);
+        out_flag |= write_delta(dy, Y_SHORT_VECTOR, Y_IS_SAME_OR_POSITIVE, &mut y_coords);
+        flags.push(out_flag);
+    }
+
+    let instructions_len = read_255_u16(glyph_stream)?;
+    let instructions = instruction_stream.read_bytes(usize::from(instructions_len))?;
+
+    glyf.extend_from_slice(&number_of_contours.to_be_bytes());
+    let x_min = if has_bbox {
+        let bbox = bbox_stream.read_bytes(8)?;
+        glyf.extend_from_slice(bbox);
+        Stream::read_at::<i16>(bbox, 0)?
+    } else {
+        let x_min = i16::try_from(x_min).ok()?;
+        glyf.extend_from_slice(&x_min.to_be_bytes());
+        glyf.extend_from_slice(&i16::try_from(y_min).ok()?.to_be_bytes());
+        glyf.extend_from_slice(&i16::try_from(x_max).ok()?.to_be_bytes());
+        glyf.extend_from_slice(&i16::try_from(y_max).ok()?.to_be_bytes());
+        x_min
+    };
+
+    for end_point in end_points {
+        glyf.extend_from_slice(&end_point.to_be_bytes());
+    }
+
+    glyf.extend_from_slice(&instructions_len.to_be_bytes());
+    glyf.extend_from_slice(instructions);
+    glyf.extend_from_slice(&flags);
+    glyf.extend_from_slice(&x_coords);
+    glyf.extend_from_slice(&y_coords);
+
+    Some(x_min)
+}
+
+/// Writes a coordinate delta and returns its flags.
+fn write_delta(delta: i32, short_flag: u8, same_or_positive_flag: u8, data: &mut Vec<u8>) -> u8 {
+    if delta == 0 {
+        same_or_positive_flag
+    } else if (-255..=255).contains(&delta) {
+        data.push(delta.unsigned_abs() as u8);
+        if delta > 0 {
+            short_flag | same_or_positive_flag
+        } else {
+            short_flag
+        }
+    } else {
+        // Coordinates are stored as wrapping 16-bit deltas.
+        data.extend_from_slice(&(delta as i16).to_be_bytes());
+        0
+    }
+}
+
+// https://www.w3.org/TR/WOFF2/#triplet_decoding
+fn read_triplet(flag: u8, s: &mut Stream) -> Option<(i32, i32)> {
+    #[inline]
+    fn with_sign(flag: u8, value: i32) -> i32 {
+        if flag & 1 != 0 {
+            value
+        } else {
+            -value
+        }
+    }
+
+    let flag_i = i32::from(flag);
+    if flag < 10 {
+        let b0 = i32::from(s.read::<u8>()?);
+        Some((0, with_sign(flag, ((flag_i & 14) << 7) + b0)))
+    } else if flag < 20 {
+        let b0 = i32::from(s.read::<u8>()?);
+        Some((with_sign(flag, (((flag_i - 10) & 14) << 7) + b0), 0))
+    } else if flag < 84 {
+        let b0 = flag_i - 20;
+        let b1 = i32::from(s.read::<u8>()?);
+        Some((
+            with_sign(flag, 1 + (b0 & 0x30) + (b1 >> 4)),
+            with_sign(flag >> 1, 1 + ((b0 & 0x0C) << 2) + (b1 & 0x0F)),
+        ))
+    } else if flag < 120 {
+        let b0 = flag_i - 84;
+        let b1 = i32::from(s.read::<u8>()?);
+        let b2 = i32::from(s.read::<u8>()?);
+        Some((
+            with_sign(flag, 1 + ((b0 / 12) << 8) + b1),
+            with_sign(flag >> 1, 1 + (((b0 % 12) >> 2) << 8) + b2),
+        ))
+    } else if flag < 124 {
+        let b1 = i32::from(s.read::<u8>()?);
+        let b2 = i32::from(s.read::<u8>()?);
+        let b3 = i32::from(s.read::<u8>()?);
+        Some((
+            with_sign(flag, (b1 << 4) + (b2 >> 4)),
+            with_sign(flag >> 1, ((b2 & 0x0F) << 8) + b3),
+        ))
+    } else {
+        let dx = i32::from(s.read::<u16>()?);
+        let dy = i32::from(s.read::<u16>()?);
+        Some((with_sign(flag, dx), with_sign(flag >> 1, dy)))
+    }
+}
+
+/// Reads composite glyph components and checks whether it has instructions.
+fn read_composite_glyph<'a>(data: &'a [u8], offset: &mut usize) -> Option<(&'a [u8], bool)> {
+    const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+    const WE_HAVE_A_SCALE: u16 = 0x0008;
+    const MORE_COMPONENTS: u16 = 0x0020;
+    const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+    const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+    const WE_HAVE_INSTRUCTIONS: u16 = 0x0100;
+
+    let mut s = Stream::new_at(data, *offset)?;
+    let mut has_instructions = false;
+    loop {
+        let flags = s.read::<u16>()?;
+        s.skip::<u16>(); // glyph index
+
+        let mut len = if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+            4
+        } else {
+            2
+        };
+
+        if flags & WE_HAVE_A_SCALE != 0 {
+            len += 2;
+        } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+            len += 4;
+        } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+            len += 8;
+        }
+
+        s.advance_checked(len)?;
+        has_instructions |= flags & WE_HAVE_INSTRUCTIONS != 0;
+
+        if flags & MORE_COMPONENTS == 0 {
+            break;
+        }
+    }
+
+    let components = data.get(*offset..s.offset())?;
+    *offset = s.offset();
+    Some((components, has_instructions))
+}
+
+// https://www.w3.org/TR/WOFF2/#hmtx_table_format
+fn reconstruct_hmtx(
+    data: &[u8],
+    number_of_glyphs: u16,
+    number_of_h_metrics: u16,
+    x_mins: &[i16],
+) -> Option<Vec<u8>> {
+    if number_of_h_metrics == 0
+        || number_of_h_metrics > number_of_glyphs
+        || x_mins.len() != usize::from(number_of_glyphs)
+    {
+        return None;
+    }
+
+    let mut s = Stream::new(data);
+    let flags = s.read::<u8>()?;
+    // Reserved bits must be zero.
+    if flags & 0xFC != 0 {
+        return None;
+    }
+
+    let number_of_monospaced = number_of_glyphs - number_of_h_metrics;
+    let advances = s.read_array16::<u16>(number_of_h_metrics)?;
+    let side_bearings = if flags & 0x01 == 0 {
+        Some(s.read_array16::<i16>(number_of_h_metrics)?)
+    } else {
+        None
+    };
+    let monospaced_side_bearings = if flags & 0x02 == 0 {
+        Some(s.read_array16::<i16>(number_of_monospaced)?)
+    } else {
+        None
+    };
+
+    let mut hmtx = Vec::with_capacity(
+        usize::from(number_of_h_metrics) * 4 + usize::from(number_of_monospaced) * 2,
+    );
+    for i in 0..number_of_h_metrics {
+        let side_bearing = match side_bearings {
+            Some(ref list) => list.get(i)?,
+            None => *x_mins.get(usize::from(i))?,
+        };
+
+        hmtx.extend_from_slice(&advances.get(i)?.to_be_bytes());
+        hmtx.extend_from_slice(&side_bearing.to_be_bytes());
+    }
+
+    for i in 0..number_of_monospaced {
+        let side_bearing = match monospaced_side_bearings {
+            Some(ref list) => list.get(i)?,
+            None => *x_mins.get(usize::from(number_of_h_metrics + i))?,
+        };
+
+        hmtx.extend_from_slice(&side_bearing.to_be_bytes());
+    }
+
+    Some(hmtx)
+}
+
+#[inline]
+fn is_bit_set(bitmap: &[u8], index: usize) -> bool {
+    bitmap
+        .get(index / 8)
+        .map(|byte| byte & (0x80 >> (index % 8)) != 0)
+        .unwrap_or(false)
+}
+
+// https://www.w3.org/TR/WOFF2/#DataTypes
+fn read_uint_base128(s: &mut Stream) -> Option<u32> {
+    let mut value = 0u32;
+    for i in 0..5 {
+        let byte = s.read::<u8>()?;
+        // No leading zeros.
+        if i == 0 && byte == 0x80 {
+            return None;
+        }
+
+        // Would overflow.
+        if value & 0xFE00_0000 != 0 {
+            return None;
+        }
+
+        value = (value << 7) | u32::from(byte & 0x7F);
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+
+    // Longer than 5 bytes.
+    None
+}
+
+// https://www.w3.org/TR/WOFF2/#DataTypes
+fn read_255_u16(s: &mut Stream) -> Option<u16> {
+    const WORD_CODE: u8 = 253;
+    const ONE_MORE_BYTE_CODE_2: u8 = 254;
+    const ONE_MORE_BYTE_CODE_1: u8 = 255;
+    const LOWEST_U_CODE: u16 = 253;
+
+    let code = s.read::<u8>()?;
+    match code {
+        WORD_CODE => s.read::<u16>(),
+        ONE_MORE_BYTE_CODE_1 => Some(u16::from(s.read::<u8>()?) + LOWEST_U_CODE),
+        ONE_MORE_BYTE_CODE_2 => Some(u16::from(s.read::<u8>()?) + LOWEST_U_CODE * 2),
+        _ => Some(u16::from(code)),
+    }
+}
@@ -29,7 +29,8 @@ fn bitmap_font() {
                 B, W, W, B,
                 B, W, W, B,
                 W, B, B, B
-            ]
+            ],
+            data_offset: 161,
         })
     );
     assert_eq!(
@@ -49,7 +50,8 @@ fn bitmap_font() {
                 B, W, W, B,
                 B, W, W, B,
                 W, B, B, B
-            ]
+            ],
+            data_offset: 232,
         })
     );
     assert_eq!(
@@ -65,7 +67,134 @@ fn bitmap_font() {
             data: &[
                 B, W, B,
                 B, W, B,
-            ]
+            ],
+            data_offset: 29,
         })
     );
 }
+
+#[test]
+fn image_size() {
+    let face = ttf_parser::Face::parse(FONT_DATA, 0).unwrap();
+    let image = face
+        .glyph_raster_image(face.glyph_index('d').unwrap(), 1)
+        .unwrap();
+    assert!(!image.is_empty());
+    assert_eq!(image.stride(), Some(4));
+    assert_eq!(image.expected_data_len(), Some(image.data.len()));
+}
+
+#[test]
+fn image_format_size() {
+    let mut image = RasterGlyphImage {
+        x: 0,
+        y: 0,
+        width: 3,
+        height: 3,
+        pixels_per_em: 8,
+        format: RasterImageFormat::BitmapMono,
+        data: &[],
+        data_offset: 0,
+    };
+    assert!(image.is_empty());
+    // 3 bits per row, padded to a byte.
+    assert_eq!(image.stride(), Some(1));
+    assert_eq!(image.expected_data_len(), Some(3));
+
+    // 9 bits in total.
+    image.format = RasterImageFormat::BitmapMonoPacked;
+    assert_eq!(image.stride(), None);
+    assert_eq!(image.expected_data_len(), Some(2));
+
+    image.format = RasterImageFormat::BitmapPremulBgra32;
+    assert_eq!(image.stride(), Some(12));
+    assert_eq!(image.expected_data_len(), Some(36));
+
+    image.format = RasterImageFormat::PNG;
+    assert_eq!(image.stride(), None);
+    assert_eq!(image.expected_data_len(), None);
+}
+
+#[test]
+fn glyph_extents() {
+    use ttf_parser::Rect;
+
+    let face = ttf_parser::Face::parse(FONT_DATA, 0).unwrap();
+    let glyph_id = face.glyph_index('a').unwrap();
+    assert_eq!(face.glyph_bounding_box(glyph_id), None);
+    assert_eq!(
+        face.glyph_extents(glyph_id),
+        Some(Rect {
+            x_min: 0,
+            y_min: 0,
+            x_max: 400,
+            y_max: 400
+        })
+    );
+    assert_eq!(
+        face.glyph_extents(face.glyph_index('d').unwrap()),
+        Some(Rect {
+            x_min: 0,
+            y_min: 0,
+            x_max: 400,
+            y_max: 600
+        })
+    );
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+    let glyph_id = face.glyph_index('A').unwrap();
+    assert_eq!(
+        face.glyph_extents(glyph_id),
+        face.glyph_bounding_box(glyph_id)
+    );
+    assert!(face.glyph_extents(glyph_id).is_some());
+}
+
+#[test]
+fn bitmap_strikes() {
+    let face = ttf_parser::Face::parse(FONT_DATA, 0).unwrap();
+    assert_eq!(face.bitmap_strikes().collect::<Vec<_>>(), [8]);
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+    assert_eq!(face.bitmap_strikes().count(), 0);
+}
+
+#[test]
+fn glyph_rendering() {
+    use ttf_parser::{GlyphId, GlyphRendering, OutlineFormat};
+
+    let face = ttf_parser::Face::parse(FONT_DATA, 0).unwrap();
+    let glyph_id = face.glyph_index('a').unwrap();
+    match face.glyph_rendering(glyph_id, 8) {
+        Some(GlyphRendering::Raster(image)) => assert_eq!(image.pixels_per_em, 8),
+        other => panic!("unexpected rendering: {:?}", other),
+    }
+    assert!(face.glyph_rendering(GlyphId(u16::MAX), 8).is_none());
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+    assert!(matches!(
+        face.glyph_rendering(GlyphId(1), 8),
+        Some(GlyphRendering::Outline(OutlineFormat::TrueType))
+    ));
+
+    let data = std::fs::read("tests/fonts/colr_1.ttf").unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+    let color_glyph = face.glyphs().find(|id| face.is_color_glyph(*id)).unwrap();
+    assert!(matches!(
+        face.glyph_rendering(color_glyph, 8),
+        Some(GlyphRendering::Color)
+    ));
+}
+
+#[test]
+fn image_data_range() {
+    let face = ttf_parser::Face::parse(FONT_DATA, 0).unwrap();
+    let glyph_id = face.glyph_index('d').unwrap();
+    let image = face.glyph_raster_image(glyph_id, 1).unwrap();
+    let range = face.glyph_raster_image_data_range(glyph_id, 1).unwrap();
+    assert_eq!(range, 892..916);
+    assert_eq!(&FONT_DATA[range], image.data);
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "serde")]
+
+use ttf_parser::{Face, Rect, Tag};
+
+static TTF_DATA: &[u8] = include_bytes!("fonts/demo.ttf");
+
+#[test]
+fn rect() {
+    let face = Face::parse(TTF_DATA, 0).unwrap();
+    let rect = face.global_bounding_box();
+    let json = serde_json::to_string(&rect).unwrap();
+    let rect2: Rect = serde_json::from_str(&json).unwrap();
+    assert_eq!(rect, rect2);
+}
+
+#[test]
+fn tag() {
+    let tag = Tag::from_bytes(b"wght");
+    let json = serde_json::to_string(&tag).unwrap();
+    assert_eq!(json, tag.0.to_string());
+    assert_eq!(serde_json::from_str::<Tag>(&json).unwrap(), tag);
+}
+
+#[test]
+fn metrics() {
+    let face = Face::parse(TTF_DATA, 0).unwrap();
+    let width = face.width();
+    let json = serde_json::to_string(&width).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ttf_parser::Width>(&json).unwrap(),
+        width
+    );
+
+    if let Some(metrics) = face.underline_metrics() {
+        let json = serde_json::to_string(&metrics).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ttf_parser::LineMetrics>(&json).unwrap(),
+            metrics
+        );
+    }
+}
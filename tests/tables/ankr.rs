@@ -1,7 +1,7 @@
 use std::num::NonZeroU16;
 use ttf_parser::GlyphId;
 use ttf_parser::ankr::{Table, Point};
-use crate::{convert, Unit::*};
+use crate::{convert, face_with_tables, Unit::*};
 
 #[test]
 fn empty() {
@@ -132,3 +132,42 @@ fn two_glyphs() {
     let points = table.points(GlyphId(1)).unwrap();
     assert_eq!(points.get(0).unwrap(), Point { x: 40, y: 10 });
 }
+
+#[test]
+fn face_anchor_points() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(0), // reserved
+        UInt32(12), // offset to lookup table
+        UInt32(12 + 16), // offset to glyphs data
+
+        // Lookup Table
+        UInt16(6), // format
+
+        // Binary Search Table
+        UInt16(4), // segment size
+        UInt16(1), // number of segments
+        UInt16(0), // search range: we don't use it
+        UInt16(0), // entry selector: we don't use it
+        UInt16(0), // range shift: we don't use it
+
+        // Segment [0]
+        UInt16(1), // glyph
+        UInt16(0), // offset
+
+        // Glyphs Data
+        UInt32(2), // number of points
+        // Point [0]
+        Int16(-5), // x
+        Int16(11), // y
+        // Point [1]
+        Int16(10), // x
+        Int16(-40), // y
+    ]);
+
+    let face = face_with_tables(|t| t.ankr = Some(&data));
+
+    let points: Vec<_> = face.apple_anchor_points(GlyphId(1)).collect();
+    assert_eq!(points, [Point { x: -5, y: 11 }, Point { x: 10, y: -40 }]);
+    assert_eq!(face.apple_anchor_points(GlyphId(0)).count(), 0);
+}
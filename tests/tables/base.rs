@@ -0,0 +1,127 @@
+use ttf_parser::base::Table;
+use ttf_parser::gpos::Device;
+use ttf_parser::{GlyphId, Tag};
+use crate::{convert, face_with_tables, Unit::*};
+
+fn base_data() -> Vec<u8> {
+    convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(8), // horizontal axis offset
+        UInt16(0), // vertical axis offset
+        // Axis
+        UInt16(4), // base tag list offset
+        UInt16(14), // base script list offset
+        // Base tag list
+        UInt16(2), // count
+        Raw(b"ideo"), // baseline tag [0]
+        Raw(b"romn"), // baseline tag [1]
+        // Base script list
+        UInt16(2), // count
+        Raw(b"DFLT"), // script tag [0]
+        UInt16(14), // base script offset [0]
+        Raw(b"latn"), // script tag [1]
+        UInt16(36), // base script offset [1]
+        // Base script [0]
+        UInt16(6), // base values offset
+        UInt16(0), // default min max offset
+        UInt16(0), // base lang sys count
+        // Base values
+        UInt16(1), // default baseline index
+        UInt16(2), // base coord count
+        UInt16(8), // base coord offset [0]
+        UInt16(12), // base coord offset [1]
+        // Base coord [0]
+        UInt16(1), // format
+        Int16(-120), // coordinate
+        // Base coord [1]
+        UInt16(1), // format
+        Int16(0), // coordinate
+        // Base script [1]
+        UInt16(6), // base values offset
+        UInt16(0), // default min max offset
+        UInt16(0), // base lang sys count
+        // Base values
+        UInt16(0), // default baseline index
+        UInt16(2), // base coord count
+        UInt16(8), // base coord offset [0]
+        UInt16(16), // base coord offset [1]
+        // Base coord [0]
+        UInt16(2), // format
+        Int16(-100), // coordinate
+        UInt16(1), // reference glyph
+        UInt16(3), // base coord point
+        // Base coord [1]
+        UInt16(3), // format
+        Int16(5), // coordinate
+        UInt16(6), // device offset
+        // Device
+        UInt16(12), // start size
+        UInt16(12), // end size
+        UInt16(1), // delta format
+        UInt16(0x4000), // delta value
+    ])
+}
+
+#[test]
+fn basic() {
+    let data = base_data();
+    let table = Table::parse(&data).unwrap();
+    assert!(table.vertical_axis.is_none());
+
+    let axis = table.horizontal_axis.unwrap();
+    assert_eq!(axis.baseline_tags.len(), 2);
+    assert_eq!(axis.baseline_tags.get(1), Some(Tag::from_bytes(b"romn")));
+
+    let latn = Tag::from_bytes(b"latn");
+    let values = axis.script_values(latn).unwrap();
+    assert_eq!(values.default_baseline_index, 0);
+    assert_eq!(values.len(), 2);
+
+    let coord = axis.baseline(latn, Tag::from_bytes(b"ideo")).unwrap();
+    assert_eq!(coord.coordinate, -100);
+    assert_eq!(coord.reference_point, Some((GlyphId(1), 3)));
+    assert!(coord.device.is_none());
+
+    let coord = axis.baseline(latn, Tag::from_bytes(b"romn")).unwrap();
+    assert_eq!(coord.coordinate, 5);
+    assert!(coord.reference_point.is_none());
+    assert!(matches!(coord.device, Some(Device::Hinting(_))));
+
+    assert!(axis.baseline(latn, Tag::from_bytes(b"hang")).is_none());
+}
+
+#[test]
+fn default_script_fallback() {
+    let data = base_data();
+    let axis = Table::parse(&data).unwrap().horizontal_axis.unwrap();
+    let cyrl = Tag::from_bytes(b"cyrl");
+    assert!(axis.script_values(cyrl).is_none());
+    let coord = axis.baseline(cyrl, Tag::from_bytes(b"ideo")).unwrap();
+    assert_eq!(coord.coordinate, -120);
+}
+
+#[test]
+fn invalid_version() {
+    let mut data = base_data();
+    data[1] = 2;
+    assert!(Table::parse(&data).is_none());
+}
+
+#[test]
+fn face_baseline() {
+    use ttf_parser::Face;
+
+    let demo_data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let data = base_data();
+    let face = face_with_tables(|t| t.base = Some(&data));
+
+    let latn = Tag::from_bytes(b"latn");
+    assert_eq!(face.baseline(latn, Tag::from_bytes(b"romn")), Some(5));
+    assert_eq!(face.baseline(latn, Tag::from_bytes(b"ideo")), Some(-100));
+    assert_eq!(face.baseline(Tag::from_bytes(b"grek"), Tag::from_bytes(b"ideo")), Some(-120));
+    assert_eq!(face.baseline(latn, Tag::from_bytes(b"math")), None);
+
+    let face = Face::parse(&demo_data, 0).unwrap();
+    assert_eq!(face.baseline(latn, Tag::from_bytes(b"romn")), None);
+}
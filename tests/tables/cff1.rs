@@ -1,8 +1,92 @@
+#[cfg(feature = "glyph-names")]
+#[test]
+fn cid_font() {
+    let data = convert(&[
+        // Header
+        UInt8(1), // major version
+        UInt8(0), // minor version
+        UInt8(4), // header size
+        UInt8(0), // absolute offset
+
+        // Name INDEX
+        UInt16(0), // count
+
+        // Top DICT
+        // INDEX
+        UInt16(1), // count
+        UInt8(1), // offset size
+        UInt8(1), // index[0]
+        UInt8(16), // index[1]
+        // Data
+        CFFInt(0), // registry
+        CFFInt(0), // ordering
+        CFFInt(0), // supplement
+        UInt8(12), UInt8((top_dict_operator::ROS - 1200) as u8),
+        CFFInt(40),
+        UInt8(top_dict_operator::CHARSET_OFFSET as u8),
+        CFFInt(30),
+        UInt8(top_dict_operator::CHAR_STRINGS_OFFSET as u8),
+        CFFInt(49),
+        UInt8(12), UInt8((top_dict_operator::FD_ARRAY - 1200) as u8),
+        CFFInt(45),
+        UInt8(12), UInt8((top_dict_operator::FD_SELECT - 1200) as u8),
+
+        // String INDEX
+        UInt16(0), // count
+
+        // Global Subroutines INDEX
+        UInt16(0), // count
+
+        // CharString INDEX
+        UInt16(3), // count
+        UInt8(1), // offset size
+        UInt8(1), // index[0]
+        UInt8(2), // index[1]
+        UInt8(3), // index[2]
+        UInt8(4), // index[3]
+        // Data
+        UInt8(operator::ENDCHAR),
+        UInt8(operator::ENDCHAR),
+        UInt8(operator::ENDCHAR),
+
+        // Charset
+        UInt8(0), // format
+        UInt16(100), // CID [1]
+        UInt16(2000), // CID [2]
+
+        // FDSelect
+        UInt8(0), // format
+        UInt8(0), UInt8(0), UInt8(0), // font DICT indices
+
+        // FDArray INDEX
+        UInt16(1), // count
+        UInt8(1), // offset size
+        UInt8(1), // index[0]
+        UInt8(1), // index[1]
+    ]);
+
+    let table = cff::Table::parse(&data).unwrap();
+    assert_eq!(table.number_of_glyphs(), 3);
+    assert_eq!(table.glyph_name(GlyphId(1)), None);
+    assert_eq!(table.glyph_cid(GlyphId(0)), Some(0));
+    assert_eq!(table.glyph_cid(GlyphId(1)), Some(100));
+    assert_eq!(table.glyph_cid(GlyphId(2)), Some(2000));
+    assert_eq!(table.glyph_cid(GlyphId(3)), None);
+
+    let font_data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = face_with_tables(|t| t.cff = Some(&data));
+    assert_eq!(face.glyph_cid(GlyphId(1)), Some(100));
+
+    let face = ttf_parser::Face::parse(&font_data, 0).unwrap();
+    assert_eq!(face.glyph_cid(GlyphId(1)), None);
+}
+
 // TODO: simplify/rewrite
 
 use std::fmt::Write;
 
 use ttf_parser::{cff, GlyphId, CFFError, Rect};
+use crate::face_with_tables;
 
 struct Builder(String);
 impl ttf_parser::OutlineBuilder for Builder {
@@ -308,6 +308,124 @@ mod format4 {
         assert_eq!(subtable.glyph_index(0x300), None);
     }
 
+    #[test]
+    fn mixed_deltas_and_range_offsets() {
+        let data = convert(&[
+            UInt16(4), // format
+            UInt16(58), // subtable size
+            UInt16(0), // language ID
+            UInt16(8), // 2 x segCount
+            UInt16(8), // search range
+            UInt16(2), // entry selector
+            UInt16(0), // range shift
+            // End character codes
+            UInt16(67), // char code [0]
+            UInt16(99), // char code [1]
+            UInt16(121), // char code [2]
+            UInt16(65535), // char code [3]
+            UInt16(0), // reserved
+            // Start character codes
+            UInt16(65), // char code [0]
+            UInt16(97), // char code [1]
+            UInt16(120), // char code [2]
+            UInt16(65535), // char code [3]
+            // Deltas
+            Int16(-60), // delta [0]
+            Int16(-1), // delta [1]
+            Int16(0), // delta [2]
+            Int16(1), // delta [3]
+            // Offsets into Glyph index array
+            UInt16(0), // offset [0]
+            UInt16(6), // offset [1]: glyph ID [0]
+            UInt16(10), // offset [2]: glyph ID [3]
+            UInt16(0), // offset [3]
+            // Glyph index array
+            UInt16(10), // glyph ID [0]
+            UInt16(0), // glyph ID [1]
+            UInt16(0x8001), // glyph ID [2]
+            UInt16(20), // glyph ID [3]
+            UInt16(21), // glyph ID [4]
+        ]);
+
+        let subtable = cmap::Subtable4::parse(&data).unwrap();
+        assert_eq!(subtable.glyph_index(0x41), Some(GlyphId(5)));
+        assert_eq!(subtable.glyph_index(0x43), Some(GlyphId(7)));
+        assert_eq!(subtable.glyph_index(0x61), Some(GlyphId(9)));
+        assert_eq!(subtable.glyph_index(0x62), None);
+        assert_eq!(subtable.glyph_index(0x63), Some(GlyphId(0x8000)));
+        assert_eq!(subtable.glyph_index(0x78), Some(GlyphId(20)));
+        assert_eq!(subtable.glyph_index(0x79), Some(GlyphId(21)));
+        assert_eq!(subtable.glyph_index(0x7A), None);
+    }
+
+    #[test]
+    fn range_offset_delta_wraps() {
+        let data = convert(&[
+            UInt16(4), // format
+            UInt16(34), // subtable size
+            UInt16(0), // language ID
+            UInt16(4), // 2 x segCount
+            UInt16(2), // search range
+            UInt16(0), // entry selector
+            UInt16(2), // range shift
+            // End character codes
+            UInt16(65), // char code [0]
+            UInt16(65535), // char code [1]
+            UInt16(0), // reserved
+            // Start character codes
+            UInt16(65), // char code [0]
+            UInt16(65535), // char code [1]
+            // Deltas
+            Int16(-2), // delta [0]
+            Int16(1), // delta [1]
+            // Offsets into Glyph index array
+            UInt16(4), // offset [0]
+            UInt16(0), // offset [1]
+            // Glyph index array
+            UInt16(1), // glyph ID [0]
+        ]);
+
+        let subtable = cmap::Subtable4::parse(&data).unwrap();
+        assert_eq!(subtable.glyph_index(0x41), Some(GlyphId(0xFFFF)));
+    }
+
+    #[test]
+    fn glyph_index_array_beyond_64k() {
+        let mut data = convert(&[
+            UInt16(4), // format
+            UInt16(0), // subtable size: overflowed
+            UInt16(0), // language ID
+            UInt16(4), // 2 x segCount
+            UInt16(2), // search range
+            UInt16(0), // entry selector
+            UInt16(2), // range shift
+            // End character codes
+            UInt16(0x7FFF), // char code [0]
+            UInt16(65535), // char code [1]
+            UInt16(0), // reserved
+            // Start character codes
+            UInt16(0), // char code [0]
+            UInt16(65535), // char code [1]
+            // Deltas
+            Int16(0), // delta [0]
+            Int16(1), // delta [1]
+            // Offsets into Glyph index array
+            UInt16(4), // offset [0]
+            UInt16(0), // offset [1]
+        ]);
+
+        // A glyph index array with 0x8000 entries, which ends after 64 KiB.
+        data.extend(std::iter::repeat(0).take(0x8000 * 2));
+        data[32..34].copy_from_slice(&3u16.to_be_bytes());
+        let len = data.len();
+        data[len - 2..].copy_from_slice(&7u16.to_be_bytes());
+
+        let subtable = cmap::Subtable4::parse(&data).unwrap();
+        assert_eq!(subtable.glyph_index(0), Some(GlyphId(3)));
+        assert_eq!(subtable.glyph_index(1), None);
+        assert_eq!(subtable.glyph_index(0x7FFF), Some(GlyphId(7)));
+    }
+
     #[test]
     fn no_end_codes() {
         let data = convert(&[
@@ -553,3 +671,366 @@ mod format4 {
         assert_eq!(vec, [27, 28, 29, 30, 31, 32, 33, 34, 65533, 65534, 65535]);
     }
 }
+
+mod subtables {
+    use ttf_parser::cmap;
+    use ttf_parser::name::PlatformId;
+    use crate::{convert, Unit::*};
+
+    fn cmap_with_records(records: &[(u16, u16)]) -> Vec<u8> {
+        let mut data = convert(&[
+            UInt16(0), // version
+            UInt16(records.len() as u16), // number of subtables
+        ]);
+
+        // All records point to a format 0 subtable and a format 12 subtable
+        // at the end, which are shared.
+        let format0_offset = 4 + records.len() as u32 * 8;
+        let format12_offset = format0_offset + 262;
+        for (i, &(platform_id, encoding_id)) in records.iter().enumerate() {
+            let offset = if i % 2 == 0 { format0_offset } else { format12_offset };
+            data.extend(convert(&[
+                UInt16(platform_id), // platform ID
+                UInt16(encoding_id), // encoding ID
+                UInt32(offset), // offset
+            ]));
+        }
+
+        data.extend(convert(&[
+            // Format 0
+            UInt16(0), // format
+            UInt16(262), // subtable size
+            UInt16(0), // language ID
+        ]));
+        data.extend(std::iter::repeat(0).take(256));
+
+        data.extend(convert(&[
+            // Format 12
+            UInt16(12), // format
+            UInt16(0), // reserved
+            UInt32(28), // length
+            UInt32(0), // language
+            UInt32(1), // number of groups
+            // Group [0]
+            UInt32(0x41), // start char code
+            UInt32(0x42), // end char code
+            UInt32(1), // start glyph ID
+        ]));
+
+        data
+    }
+
+    #[test]
+    fn best_unicode() {
+        // Unicode format 0, Windows format 12.
+        let data = cmap_with_records(&[(0, 3), (3, 10)]);
+        let table = cmap::Table::parse(&data).unwrap();
+        let subtable = table.subtables.best_unicode().unwrap();
+        assert_eq!(subtable.platform_id, PlatformId::Windows);
+        assert_eq!(subtable.format.number(), 12);
+
+        // Mac format 0, Unicode format 12.
+        let data = cmap_with_records(&[(1, 0), (0, 4)]);
+        let table = cmap::Table::parse(&data).unwrap();
+        let subtable = table.subtables.best_unicode().unwrap();
+        assert_eq!(subtable.platform_id, PlatformId::Unicode);
+        assert_eq!(subtable.format.number(), 12);
+
+        // Unicode format 0.
+        let data = cmap_with_records(&[(0, 3)]);
+        let table = cmap::Table::parse(&data).unwrap();
+        let subtable = table.subtables.best_unicode().unwrap();
+        assert_eq!(subtable.format.number(), 0);
+
+        // Mac format 0.
+        let data = cmap_with_records(&[(1, 0)]);
+        let table = cmap::Table::parse(&data).unwrap();
+        assert!(table.subtables.best_unicode().is_none());
+    }
+}
+
+mod face {
+    use ttf_parser::{Face, GlyphId};
+    use crate::{convert, face_with_tables, Unit::*};
+
+    fn format0_cmap(platform_id: u16, encoding_id: u16) -> Vec<u8> {
+        let mut data = convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of subtables
+            UInt16(platform_id), // platform ID
+            UInt16(encoding_id), // encoding ID
+            UInt32(12), // offset
+            UInt16(0), // format
+            UInt16(262), // subtable size
+            UInt16(0), // language ID
+        ]);
+
+        // Map (only) Mac Roman 0x8A (a with diaeresis) to 1.
+        data.extend(std::iter::repeat(0).take(256));
+        data[18 + 0x8A] = 1;
+        data
+    }
+
+    #[test]
+    fn mac_roman() {
+        let data = format0_cmap(1, 0);
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+        assert_eq!(face.glyph_index_mac_roman(0x8A), Some(GlyphId(1)));
+        assert_eq!(face.glyph_index_mac_roman(0x41), None);
+        assert_eq!(face.glyph_index('\u{8A}'), None);
+    }
+
+    #[test]
+    fn mac_roman_no_subtable() {
+        let data = format0_cmap(1, 1); // Japanese
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+        assert_eq!(face.glyph_index_mac_roman(0x8A), None);
+    }
+
+    #[test]
+    fn glyph_index_any_mac_roman() {
+        let mut data = format0_cmap(1, 0);
+        data[18 + 0x41] = 2;
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+        assert_eq!(face.glyph_index_any('\u{E4}'), Some(GlyphId(1)));
+        assert_eq!(face.glyph_index_any('A'), Some(GlyphId(2)));
+        assert_eq!(face.glyph_index_any('\u{8A}'), None);
+        assert_eq!(face.glyph_index_any('\u{2122}'), None);
+
+        let subtable = face.tables().cmap.unwrap().subtables.get(0).unwrap();
+        assert_eq!(face.glyph_index_via(subtable, 0x8A), Some(GlyphId(1)));
+    }
+
+    #[test]
+    fn variation_selectors() {
+        let data = convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of subtables
+            UInt16(0), // platform ID
+            UInt16(5), // encoding ID
+            UInt32(12), // offset
+            // Format 14
+            UInt16(14), // format
+            UInt32(32), // length
+            UInt32(2), // number of records
+            // Record [0]
+            Raw(&[0x00, 0xFE, 0x0E]), // variation selector
+            UInt32(0), // default UVS offset
+            UInt32(0), // non-default UVS offset
+            // Record [1]
+            Raw(&[0x00, 0xFE, 0x0F]), // variation selector
+            UInt32(0), // default UVS offset
+            UInt32(0), // non-default UVS offset
+        ]);
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+        let selectors: Vec<_> = face.variation_selectors().collect();
+        assert_eq!(selectors, ['\u{FE0E}', '\u{FE0F}']);
+
+        let data = format0_cmap(1, 0);
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+        assert_eq!(face.variation_selectors().count(), 0);
+    }
+
+    #[test]
+    fn glyph_index_any_symbol() {
+        let mut data = convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of subtables
+            UInt16(3), // platform ID
+            UInt16(0), // encoding ID
+            UInt32(12), // offset
+            // Format 6
+            UInt16(6), // format
+            UInt16(14), // subtable size
+            UInt16(0), // language ID
+            UInt16(0xF041), // first code
+            UInt16(2), // entry count
+            UInt16(1), // glyph ID [0]
+            UInt16(2), // glyph ID [1]
+        ]);
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+        assert_eq!(face.glyph_index('A'), None);
+        assert_eq!(face.glyph_index_any('A'), Some(GlyphId(1)));
+        assert_eq!(face.glyph_index_any('B'), Some(GlyphId(2)));
+        assert_eq!(face.glyph_index_any('C'), None);
+
+        // Symbol fonts may also use the plain 0x20..=0xFF range.
+        data[18..20].copy_from_slice(&0x41u16.to_be_bytes());
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+        assert_eq!(face.glyph_index_any('A'), Some(GlyphId(1)));
+    }
+
+    #[test]
+    fn subtables_indexing() {
+        let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+        let face = Face::parse(&data, 0).unwrap();
+        let subtables = face.tables().cmap.unwrap().subtables;
+        assert!(!subtables.is_empty());
+        assert_eq!(usize::from(subtables.len()), subtables.into_iter().count());
+
+        for (index, subtable) in subtables.into_iter().enumerate() {
+            let indexed = subtables.get(index as u16).unwrap();
+            assert_eq!(indexed.platform_id, subtable.platform_id);
+            assert_eq!(indexed.encoding_id, subtable.encoding_id);
+            assert_eq!(indexed.format.number(), subtable.format.number());
+        }
+
+        assert!(subtables.get(subtables.len()).is_none());
+
+        let data = format0_cmap(1, 0);
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+        let subtable = face.tables().cmap.unwrap().subtables.get(0).unwrap();
+        assert_eq!(subtable.format.number(), 0);
+    }
+
+    #[test]
+    fn covered_unicode_ranges() {
+        let mut data = format0_cmap(0, 3);
+        data[18 + 0x8A] = 0;
+        data[18 + 0x41] = 2;
+        data[18 + 0x42] = 1;
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+        let ranges = face.covered_unicode_ranges();
+        assert_eq!(ranges.0, 1);
+        assert!(ranges.contains_char('z'));
+        assert!(!ranges.contains_char('\u{E4}'));
+
+        data[18 + 0xE4] = 1;
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+        assert_eq!(face.covered_unicode_ranges().0, 0b11);
+
+        // Not a Unicode subtable.
+        let data = format0_cmap(1, 0);
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+        assert_eq!(face.covered_unicode_ranges().0, 0);
+    }
+
+    #[test]
+    fn covered_unicode_ranges_segmented() {
+        let cmap = |format: u16, start_glyph_id: u32| convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of subtables
+            UInt16(3), // platform ID
+            UInt16(10), // encoding ID
+            UInt32(12), // offset
+            // Format 12/13
+            UInt16(format), // format
+            UInt16(0), // reserved
+            UInt32(28), // length
+            UInt32(0), // language
+            UInt32(1), // number of groups
+            // Group [0]
+            UInt32(0), // start char code
+            UInt32(u32::MAX), // end char code
+            UInt32(start_glyph_id), // start glyph ID
+        ]);
+
+        // Only the code points mapped to 1..=65535 glyph IDs are covered.
+        let data = cmap(12, 1);
+        let ranges = face_with_tables(|t| t.cmap = Some(&data)).covered_unicode_ranges();
+        assert!(ranges.contains_char('A'));
+        assert!(ranges.contains_char('\u{FB50}'));
+        assert!(!ranges.contains_char('\u{10190}'));
+
+        let data = cmap(13, 1);
+        let ranges = face_with_tables(|t| t.cmap = Some(&data)).covered_unicode_ranges();
+        assert!(ranges.contains_char('A'));
+        assert!(ranges.contains_char('\u{10190}'));
+
+        let data = cmap(13, 0x10000);
+        assert_eq!(face_with_tables(|t| t.cmap = Some(&data)).covered_unicode_ranges().0, 0);
+    }
+
+    #[cfg(feature = "fuzzing")]
+    #[test]
+    fn exercise_all_segmented() {
+        let cmap = |format: u16| convert(&[
+            UInt16(0), // version
+            UInt16(1), // number of subtables
+            UInt16(3), // platform ID
+            UInt16(10), // encoding ID
+            UInt32(12), // offset
+            // Format 12/13
+            UInt16(format), // format
+            UInt16(0), // reserved
+            UInt32(28), // length
+            UInt32(0), // language
+            UInt32(1), // number of groups
+            // Group [0]
+            UInt32(0), // start char code
+            UInt32(u32::MAX), // end char code
+            UInt32(1), // start glyph ID
+        ]);
+
+        // Must not iterate over the whole group.
+        let data = cmap(12);
+        let summary = face_with_tables(|t| t.cmap = Some(&data)).exercise_all();
+        assert_eq!(summary.code_points, 65535);
+
+        let data = cmap(13);
+        let summary = face_with_tables(|t| t.cmap = Some(&data)).exercise_all();
+        assert_eq!(summary.code_points, u32::MAX);
+    }
+
+    #[test]
+    fn referenced_glyphs() {
+        let mut data = format0_cmap(0, 3);
+        data[18 + 0x41] = 2;
+        data[18 + 0x42] = 1;
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+
+        let mut glyphs = vec![];
+        face.referenced_glyphs(|id| glyphs.push(id));
+        assert_eq!(glyphs, [GlyphId(2), GlyphId(1)]);
+    }
+
+    #[test]
+    fn referenced_glyphs_prefer_full_repertoire() {
+        let mut data = convert(&[
+            UInt16(0), // version
+            UInt16(2), // number of subtables
+            UInt16(3), // platform ID
+            UInt16(1), // encoding ID
+            UInt32(20), // offset
+            UInt16(3), // platform ID
+            UInt16(10), // encoding ID
+            UInt32(282), // offset
+            // Format 0
+            UInt16(0), // format
+            UInt16(262), // subtable size
+            UInt16(0), // language ID
+        ]);
+
+        data.extend(std::iter::repeat(0).take(256));
+        data[26 + 0x41] = 1;
+
+        data.extend(convert(&[
+            // Format 12
+            UInt16(12), // format
+            UInt16(0), // reserved
+            UInt32(28), // length
+            UInt32(0), // language
+            UInt32(1), // number of groups
+            // Group [0]
+            UInt32(0x41), // start char code
+            UInt32(0x42), // end char code
+            UInt32(1), // start glyph ID
+        ]));
+
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+
+        let mut glyphs = vec![];
+        face.referenced_glyphs(|id| glyphs.push(id));
+        assert_eq!(glyphs, [GlyphId(1), GlyphId(2)]);
+    }
+
+    #[test]
+    fn referenced_glyphs_no_unicode_subtable() {
+        let data = format0_cmap(1, 0);
+        let face = face_with_tables(|t| t.cmap = Some(&data));
+
+        let mut glyphs = vec![];
+        face.referenced_glyphs(|id| glyphs.push(id));
+        assert!(glyphs.is_empty());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{convert, Unit::*};
-use ttf_parser::colr::{self, ClipBox, CompositeMode, GradientExtend, Paint, Painter};
+use ttf_parser::colr::{self, ClipBox, ColorLayer, CompositeMode, GradientExtend, Paint, Painter};
 use ttf_parser::{cpal, GlyphId, RgbaColor};
 
 #[test]
@@ -85,6 +85,51 @@ fn basic() {
     ]);
 }
 
+#[test]
+fn layers() {
+    let cpal_data = convert(&[
+        UInt16(0),  // version
+        UInt16(2),  // number of palette entries
+        UInt16(1),  // number of palettes
+        UInt16(2),  // number of colors
+        UInt32(14), // offset to colors
+        UInt16(0),  // index of palette 0's first color
+        UInt8(10), UInt8(15), UInt8(20), UInt8(25), // color 0
+        UInt8(30), UInt8(35), UInt8(40), UInt8(45), // color 1
+    ]);
+
+    let colr_data = convert(&[
+        UInt16(0),  // version
+        UInt16(2),  // number of base glyphs
+        UInt32(14), // offset to base glyphs
+        UInt32(26), // offset to layers
+        UInt16(5),  // number of layers
+        UInt16(2), UInt16(0), UInt16(3), // base glyph 0 (id 2)
+        UInt16(3), UInt16(3), UInt16(2), // base glyph 1 (id 3)
+        UInt16(10), UInt16(1), // layer 0
+        UInt16(11), UInt16(0xFFFF), // layer 1
+        UInt16(12), UInt16(0), // layer 2
+        UInt16(13), UInt16(0), // layer 3
+        UInt16(14), UInt16(5), // layer 4
+    ]);
+
+    let cpal = cpal::Table::parse(&cpal_data).unwrap();
+    let colr = colr::Table::parse(cpal, &colr_data).unwrap();
+
+    let layers: Vec<_> = colr.layers(GlyphId(2), 0).unwrap().collect();
+    assert_eq!(layers, [
+        ColorLayer { glyph_id: GlyphId(10), color: Some(RgbaColor::new(40, 35, 30, 45)) },
+        ColorLayer { glyph_id: GlyphId(11), color: None },
+        ColorLayer { glyph_id: GlyphId(12), color: Some(RgbaColor::new(20, 15, 10, 25)) },
+    ]);
+
+    // Stops at an invalid palette entry.
+    assert_eq!(colr.layers(GlyphId(3), 0).unwrap().count(), 1);
+    // Invalid palette.
+    assert_eq!(colr.layers(GlyphId(2), 1).unwrap().count(), 0);
+    assert!(colr.layers(GlyphId(4), 0).is_none());
+}
+
 #[derive(Clone, Debug, PartialEq)]
 struct CustomStop(f32, RgbaColor);
 
@@ -0,0 +1,66 @@
+use ttf_parser::cpal::Table;
+use ttf_parser::RgbaColor;
+use crate::{convert, Unit::*};
+
+#[test]
+fn palettes() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(2), // number of palette entries
+        UInt16(2), // number of palettes
+        UInt16(3), // number of colors
+        UInt32(16), // offset to colors
+        UInt16(0), // index of palette 0's first color
+        UInt16(2), // index of palette 1's first color
+        UInt8(10), UInt8(15), UInt8(20), UInt8(25), // color 0
+        UInt8(30), UInt8(35), UInt8(40), UInt8(45), // color 1
+        UInt8(50), UInt8(55), UInt8(60), UInt8(65), // color 2
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.palettes().get(), 2);
+    assert_eq!(table.palette_entries(), 2);
+    assert_eq!(
+        table.palette(0).unwrap().collect::<Vec<_>>(),
+        [RgbaColor::new(20, 15, 10, 25), RgbaColor::new(40, 35, 30, 45)]
+    );
+    // Palette 1 is out of bounds.
+    assert!(table.palette(1).is_none());
+    assert!(table.palette(2).is_none());
+    assert_eq!(table.palette_label(0), None);
+}
+
+#[test]
+fn labels() {
+    let data = convert(&[
+        UInt16(1), // version
+        UInt16(2), // number of palette entries
+        UInt16(2), // number of palettes
+        UInt16(2), // number of colors
+        UInt32(28), // offset to colors
+        UInt16(0), // index of palette 0's first color
+        UInt16(0), // index of palette 1's first color
+        UInt32(0), // offset to palette types
+        UInt32(36), // offset to palette labels
+        UInt32(40), // offset to palette entry labels
+        UInt8(10), UInt8(15), UInt8(20), UInt8(25), // color 0
+        UInt8(30), UInt8(35), UInt8(40), UInt8(45), // color 1
+        UInt16(256), // palette 0 label
+        UInt16(0xFFFF), // palette 1 label
+        UInt16(258), // entry 0 label
+        UInt16(259), // entry 1 label
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.palette(1).unwrap().count(), 2);
+    assert_eq!(table.palette_label(0), Some(256));
+    assert_eq!(table.palette_label(1), None);
+    assert_eq!(table.palette_entry_label(0), Some(258));
+    assert_eq!(table.palette_entry_label(1), Some(259));
+    assert_eq!(table.palette_entry_label(2), None);
+
+    // Truncated labels are ignored.
+    let table = Table::parse(&data[..42]).unwrap();
+    assert_eq!(table.palette_label(0), Some(256));
+    assert_eq!(table.palette_entry_label(0), None);
+}
@@ -0,0 +1,74 @@
+use ttf_parser::dsig::Table;
+use ttf_parser::Face;
+use crate::{convert, face_with_tables, Unit::*};
+
+fn signed_table() -> Vec<u8> {
+    convert(&[
+        UInt32(1), // version
+        UInt16(2), // number of signatures
+        UInt16(1), // flags
+        // Signature record [0]
+        UInt32(1), // format
+        UInt32(12), // length
+        UInt32(32), // offset
+        // Signature record [1]
+        UInt32(1), // format
+        UInt32(10), // length
+        UInt32(44), // offset
+        // Signature block [0]
+        UInt16(0), // reserved
+        UInt16(0), // reserved
+        UInt32(4), // signature length
+        Raw(&[1, 2, 3, 4]), // signature
+        // Signature block [1], truncated
+        UInt16(0), // reserved
+    ])
+}
+
+#[test]
+fn signatures() {
+    let data = signed_table();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.flags, 1);
+    assert_eq!(table.len(), 2);
+
+    // The second block is out of bounds.
+    let signatures: Vec<_> = table.signatures().collect();
+    assert_eq!(signatures.len(), 1);
+    assert_eq!(signatures[0].format, 1);
+    assert_eq!(signatures[0].data, &[0, 0, 0, 0, 0, 0, 0, 4, 1, 2, 3, 4]);
+}
+
+#[test]
+fn unsupported_version() {
+    let data = convert(&[
+        UInt32(2), // version
+        UInt16(0), // number of signatures
+        UInt16(0), // flags
+    ]);
+
+    assert!(Table::parse(&data).is_none());
+}
+
+#[test]
+fn face() {
+    let font_data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+
+    let face = Face::parse(&font_data, 0).unwrap();
+    assert!(!face.has_digital_signature());
+    assert_eq!(face.digital_signatures().count(), 0);
+
+    let data = signed_table();
+    let face = face_with_tables(|t| t.dsig = Some(&data));
+    assert!(face.has_digital_signature());
+    assert_eq!(face.digital_signatures().count(), 1);
+
+    // A placeholder table.
+    let data = convert(&[
+        UInt32(1), // version
+        UInt16(0), // number of signatures
+        UInt16(0), // flags
+    ]);
+    let face = face_with_tables(|t| t.dsig = Some(&data));
+    assert!(!face.has_digital_signature());
+}
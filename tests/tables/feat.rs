@@ -1,7 +1,7 @@
 #![allow(clippy::bool_assert_comparison)]
 
 use ttf_parser::feat::Table;
-use crate::{convert, Unit::*};
+use crate::{convert, face_with_tables, Unit::*};
 
 #[test]
 fn basic() {
@@ -83,3 +83,57 @@ fn basic() {
     assert_eq!(feature3.default_setting_index, 1);
     assert_eq!(feature3.exclusive, true);
 }
+
+#[test]
+fn face_apple_features() {
+    let data = convert(&[
+        Fixed(1.0), // version
+        UInt16(2), // number of features
+        UInt16(0), // reserved
+        UInt32(0), // reserved
+
+        // Feature Name [0]
+        UInt16(1), // feature
+        UInt16(1), // number of settings
+        UInt32(36), // offset to settings table
+        UInt16(0), // flags: none
+        UInt16(256), // name index
+
+        // Feature Name [1]
+        UInt16(3), // feature
+        UInt16(2), // number of settings
+        UInt32(40), // offset to settings table
+        Raw(&[0x80, 0x00]), // flags: exclusive
+        UInt16(257), // name index
+
+        // Setting Name [0]
+        UInt16(0), // setting
+        UInt16(258), // name index
+
+        // Setting Name [1]
+        UInt16(0), // setting
+        UInt16(259), // name index
+        UInt16(3), // setting
+        UInt16(260), // name index
+    ]);
+
+    let face = face_with_tables(|t| t.feat = Some(&data));
+
+    let features: Vec<_> = face.apple_features().map(|f| {
+        let settings: Vec<_> = f.setting_names.into_iter()
+            .map(|s| (s.setting, s.name_index))
+            .collect();
+        (f.feature, f.name_index, settings)
+    }).collect();
+    assert_eq!(features, vec![
+        (1, 256, vec![(0, 258)]),
+        (3, 257, vec![(0, 259), (3, 260)]),
+    ]);
+}
+
+#[test]
+fn face_apple_features_no_table() {
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+    assert_eq!(face.apple_features().count(), 0);
+}
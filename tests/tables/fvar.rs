@@ -0,0 +1,40 @@
+#![cfg(feature = "variable-fonts")]
+
+use ttf_parser::fvar::Table;
+use ttf_parser::Tag;
+use crate::{convert, Unit::*};
+
+#[test]
+fn hidden_axis() {
+    let data = convert(&[
+        UInt32(0x00010000), // version
+        UInt16(16), // axes array offset
+        UInt16(2), // reserved
+        UInt16(2), // axis count
+        UInt16(20), // axis size
+        UInt16(0), // instance count
+        UInt16(8), // instance size
+        // Axis [0]
+        Raw(b"wght"), // tag
+        Fixed(100.0), // min value
+        Fixed(400.0), // default value
+        Fixed(900.0), // max value
+        UInt16(0x0008), // flags, reserved bit
+        UInt16(256), // name ID
+        // Axis [1]
+        Raw(b"XOPQ"), // tag
+        Fixed(10.0), // min value
+        Fixed(20.0), // default value
+        Fixed(30.0), // max value
+        UInt16(0x0001), // flags, HIDDEN_AXIS
+        UInt16(257), // name ID
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let axis = table.axes.get(0).unwrap();
+    assert_eq!(axis.tag, Tag::from_bytes(b"wght"));
+    assert!(!axis.hidden);
+    let axis = table.axes.get(1).unwrap();
+    assert_eq!(axis.tag, Tag::from_bytes(b"XOPQ"));
+    assert!(axis.hidden);
+}
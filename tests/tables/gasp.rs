@@ -0,0 +1,68 @@
+use ttf_parser::gasp::{Range, Table};
+use crate::{convert, Unit::*};
+
+#[test]
+fn version_0() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(2), // number of ranges
+        // Range [0]
+        UInt16(8), // max PPEM
+        UInt16(0x0002), // behavior: grayscale
+        // Range [1]
+        UInt16(0xFFFF), // max PPEM
+        UInt16(0x000F), // behavior: all flags
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.ranges.len(), 2);
+
+    let range = table.find(8).unwrap();
+    assert_eq!(range, Range { max_ppem: 8, behavior: 0x0002 });
+    assert!(!range.gridfit());
+    assert!(range.grayscale());
+
+    // Version 1 flags are cleared.
+    let range = table.find(9).unwrap();
+    assert_eq!(range, Range { max_ppem: 0xFFFF, behavior: 0x0003 });
+    assert!(range.gridfit());
+    assert!(!range.symmetric_gridfit());
+    assert!(!range.symmetric_smoothing());
+}
+
+#[test]
+fn version_1() {
+    let data = convert(&[
+        UInt16(1), // version
+        UInt16(2), // number of ranges
+        // Range [0]
+        UInt16(8), // max PPEM
+        UInt16(0x000A), // behavior: grayscale + symmetric smoothing
+        // Range [1]
+        UInt16(20), // max PPEM
+        UInt16(0x0007), // behavior: gridfit + grayscale + symmetric gridfit
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+
+    let range = table.find(0).unwrap();
+    assert!(range.grayscale());
+    assert!(range.symmetric_smoothing());
+    assert!(!range.symmetric_gridfit());
+
+    let range = table.find(20).unwrap();
+    assert!(range.gridfit());
+    assert!(range.symmetric_gridfit());
+
+    assert_eq!(table.find(21), None);
+}
+
+#[test]
+fn unknown_version() {
+    let data = convert(&[
+        UInt16(2), // version
+        UInt16(0), // number of ranges
+    ]);
+
+    assert!(Table::parse(&data).is_none());
+}
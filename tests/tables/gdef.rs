@@ -0,0 +1,190 @@
+use ttf_parser::gdef::{GlyphClass, Table};
+use ttf_parser::GlyphId;
+use crate::{convert, face_with_tables, Unit::*};
+
+fn gdef_data() -> Vec<u8> {
+    convert(&[
+        UInt32(0x00010002), // version
+        UInt16(14), // glyph class def offset
+        UInt16(0), // attach list offset
+        UInt16(0), // lig caret list offset
+        UInt16(0), // mark attach class def offset
+        UInt16(42), // mark glyph sets def offset
+        // Glyph class def
+        UInt16(2), // format
+        UInt16(4), // range count
+        UInt16(1), // start glyph [0]
+        UInt16(3), // end glyph [0]
+        UInt16(1), // class [0]
+        UInt16(5), // start glyph [1]
+        UInt16(5), // end glyph [1]
+        UInt16(3), // class [1]
+        UInt16(7), // start glyph [2]
+        UInt16(8), // end glyph [2]
+        UInt16(0), // class [2]
+        UInt16(10), // start glyph [3]
+        UInt16(10), // end glyph [3]
+        UInt16(9), // class [3], invalid
+        // Mark glyph sets def
+        UInt16(1), // format
+        UInt16(2), // count
+        UInt32(12), // coverage offset [0]
+        UInt32(20), // coverage offset [1]
+        // Coverage [0]
+        UInt16(1), // format
+        UInt16(2), // count
+        UInt16(5), // glyph [0]
+        UInt16(6), // glyph [1]
+        // Coverage [1]
+        UInt16(2), // format
+        UInt16(1), // count
+        UInt16(10), // start glyph
+        UInt16(12), // end glyph
+        UInt16(0), // start coverage index
+    ])
+}
+
+#[test]
+fn glyph_classes_ranges() {
+    let data = gdef_data();
+    let table = Table::parse(&data).unwrap();
+    let classes: Vec<_> = table.glyph_classes().collect();
+    assert_eq!(
+        classes,
+        [
+            (GlyphId(1), GlyphClass::Base),
+            (GlyphId(2), GlyphClass::Base),
+            (GlyphId(3), GlyphClass::Base),
+            (GlyphId(5), GlyphClass::Mark),
+        ]
+    );
+
+    for (glyph_id, class) in classes {
+        assert_eq!(table.glyph_class(glyph_id), Some(class));
+    }
+}
+
+#[test]
+fn glyph_classes_array() {
+    let data = convert(&[
+        UInt32(0x00010000), // version
+        UInt16(12), // glyph class def offset
+        UInt16(0), // attach list offset
+        UInt16(0), // lig caret list offset
+        UInt16(0), // mark attach class def offset
+        // Glyph class def
+        UInt16(1), // format
+        UInt16(3), // start glyph
+        UInt16(4), // count
+        UInt16(1), // class [0]
+        UInt16(0), // class [1]
+        UInt16(2), // class [2]
+        UInt16(4), // class [3]
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let classes: Vec<_> = table.glyph_classes().collect();
+    assert_eq!(
+        classes,
+        [
+            (GlyphId(3), GlyphClass::Base),
+            (GlyphId(5), GlyphClass::Ligature),
+            (GlyphId(6), GlyphClass::Component),
+        ]
+    );
+    assert_eq!(table.mark_glyph_sets().count(), 0);
+}
+
+#[test]
+fn mark_glyph_sets() {
+    let data = gdef_data();
+    let table = Table::parse(&data).unwrap();
+    let sets: Vec<Vec<_>> = table
+        .mark_glyph_sets()
+        .map(|set| set.glyphs().collect())
+        .collect();
+    assert_eq!(
+        sets,
+        [
+            vec![GlyphId(5), GlyphId(6)],
+            vec![GlyphId(10), GlyphId(11), GlyphId(12)],
+        ]
+    );
+}
+
+#[test]
+fn mark_glyph_sets_malformed() {
+    let mut data = gdef_data();
+    // Set coverage [0] format to an unknown one.
+    data[55] = 3;
+    let table = Table::parse(&data).unwrap();
+    let mut sets = table.mark_glyph_sets();
+    assert!(sets.next().is_none());
+    // Coverage [1] is valid, but must not be returned after an error.
+    assert!(sets.next().is_none());
+}
+
+#[test]
+fn face_without_gdef() {
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+    assert_eq!(face.glyph_classes().count(), 0);
+    assert_eq!(face.mark_glyph_sets().count(), 0);
+}
+
+#[test]
+fn face_is_mark() {
+    use ttf_parser::Face;
+
+    let data = gdef_data();
+    let face = face_with_tables(|t| t.gdef = Some(&data));
+
+    assert!(face.is_mark(GlyphId(5)));
+    assert!(!face.is_mark(GlyphId(1)));
+    // In a mark glyph set, but without the `Mark` class.
+    assert!(!face.is_mark(GlyphId(6)));
+    assert!(!face.is_mark(GlyphId(10)));
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+    assert!(!face.is_mark(GlyphId(1)));
+}
+
+#[test]
+fn attachment_points() {
+    let data = convert(&[
+        UInt32(0x00010000), // version
+        UInt16(0), // glyph class def offset
+        UInt16(12), // attach list offset
+        UInt16(0), // lig caret list offset
+        UInt16(0), // mark attach class def offset
+        // Attach list
+        UInt16(8), // coverage offset
+        UInt16(2), // glyph count
+        UInt16(16), // attach point offset [0]
+        UInt16(22), // attach point offset [1]
+        // Coverage
+        UInt16(1), // format
+        UInt16(2), // count
+        UInt16(4), // glyph [0]
+        UInt16(9), // glyph [1]
+        // Attach point [0]
+        UInt16(2), // point count
+        UInt16(3), // point index [0]
+        UInt16(17), // point index [1]
+        // Attach point [1]
+        UInt16(1), // point count
+        UInt16(8), // point index [0]
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let points: Vec<_> = table.attachment_points(GlyphId(4)).collect();
+    assert_eq!(points, [3, 17]);
+    let points: Vec<_> = table.attachment_points(GlyphId(9)).collect();
+    assert_eq!(points, [8]);
+    assert_eq!(table.attachment_points(GlyphId(5)).count(), 0);
+
+    let data = gdef_data();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.attachment_points(GlyphId(4)).count(), 0);
+}
@@ -45,3 +45,655 @@ fn endless_loop() {
     let face = ttf_parser::Face::parse(data, 0).unwrap();
     let _ = face.outline_glyph(ttf_parser::GlyphId(0), &mut Builder(String::new()));
 }
+
+mod simple_glyph_flags {
+    use std::num::NonZeroU16;
+    use ttf_parser::{glyf, loca, GlyphId, Rect};
+    use ttf_parser::head::IndexToLocationFormat;
+    use crate::{convert, Unit::*};
+    use super::Builder;
+
+    const ON_CURVE_POINT: u8 = 0x01;
+    const X_SHORT_VECTOR: u8 = 0x02;
+    const Y_SHORT_VECTOR: u8 = 0x04;
+    const REPEAT_FLAG: u8 = 0x08;
+    const X_IS_SAME_OR_POSITIVE: u8 = 0x10;
+    const Y_IS_SAME_OR_POSITIVE: u8 = 0x20;
+
+    fn outline(glyph: &[u8]) -> (Option<Rect>, String) {
+        let loca_data = convert(&[
+            UInt32(0), // offset [0]
+            UInt32(glyph.len() as u32), // offset [1]
+        ]);
+        let loca = loca::Table::parse(
+            NonZeroU16::new(1).unwrap(),
+            IndexToLocationFormat::Long,
+            &loca_data,
+        ).unwrap();
+        let table = glyf::Table::parse(loca, glyph).unwrap();
+
+        let mut builder = Builder(String::new());
+        let bbox = table.outline(GlyphId(0), &mut builder);
+        (bbox, builder.0)
+    }
+
+    fn glyph_header() -> Vec<u8> {
+        convert(&[
+            Int16(1), // number of contours
+            Int16(0), // x min
+            Int16(0), // y min
+            Int16(0), // x max
+            Int16(0), // y max
+            UInt16(2), // end point [0]
+            UInt16(0), // instructions length
+        ])
+    }
+
+    #[test]
+    fn repeated_same_flags() {
+        let mut data = glyph_header();
+        data.extend_from_slice(&[
+            // Flags
+            ON_CURVE_POINT | REPEAT_FLAG | X_IS_SAME_OR_POSITIVE
+                | Y_SHORT_VECTOR | Y_IS_SAME_OR_POSITIVE,
+            2, // repeat count
+            // X coordinates are all the same, so no data.
+            // Y coordinates
+            10, 20, 30,
+        ]);
+
+        let (bbox, path) = outline(&data);
+        assert_eq!(bbox, Some(Rect { x_min: 0, y_min: 10, x_max: 0, y_max: 60 }));
+        assert_eq!(path, "M 0 10 L 0 30 L 0 60 L 0 10 Z ");
+    }
+
+    #[test]
+    fn repeat_count_exceeds_points() {
+        let mut data = glyph_header();
+        data.extend_from_slice(&[
+            // Flags
+            ON_CURVE_POINT | REPEAT_FLAG | X_IS_SAME_OR_POSITIVE
+                | Y_SHORT_VECTOR | Y_IS_SAME_OR_POSITIVE,
+            5, // repeat count
+            // Y coordinates
+            10, 20, 30, 40, 50, 60,
+        ]);
+
+        assert_eq!(outline(&data), (None, String::new()));
+    }
+
+    #[test]
+    fn missing_repeat_count() {
+        let mut data = glyph_header();
+        data.extend_from_slice(&[
+            // Flags
+            ON_CURVE_POINT | X_SHORT_VECTOR | Y_SHORT_VECTOR,
+            ON_CURVE_POINT | REPEAT_FLAG | X_IS_SAME_OR_POSITIVE | Y_IS_SAME_OR_POSITIVE,
+        ]);
+
+        assert_eq!(outline(&data), (None, String::new()));
+    }
+
+    #[test]
+    fn missing_flags() {
+        let mut data = glyph_header();
+        data.extend_from_slice(&[
+            // Flags
+            ON_CURVE_POINT | X_IS_SAME_OR_POSITIVE | Y_IS_SAME_OR_POSITIVE,
+            ON_CURVE_POINT | X_IS_SAME_OR_POSITIVE | Y_IS_SAME_OR_POSITIVE,
+        ]);
+
+        assert_eq!(outline(&data), (None, String::new()));
+    }
+
+    #[test]
+    fn missing_coordinates() {
+        let mut data = glyph_header();
+        data.extend_from_slice(&[
+            // Flags
+            ON_CURVE_POINT | REPEAT_FLAG,
+            2, // repeat count
+            // X coordinates, 2 bytes each
+            0, 10, 0, 20, 0, 30,
+            // Y coordinates, 2 bytes each, but one is missing
+            0, 10, 0, 20,
+        ]);
+
+        assert_eq!(outline(&data), (None, String::new()));
+    }
+
+    #[test]
+    fn missing_same_coordinates() {
+        let mut data = glyph_header();
+        data.extend_from_slice(&[
+            // Flags
+            ON_CURVE_POINT | X_SHORT_VECTOR | Y_IS_SAME_OR_POSITIVE,
+            ON_CURVE_POINT | X_IS_SAME_OR_POSITIVE | Y_IS_SAME_OR_POSITIVE,
+            ON_CURVE_POINT | X_SHORT_VECTOR | Y_IS_SAME_OR_POSITIVE,
+            // X coordinates, the second one is missing
+            10,
+        ]);
+
+        assert_eq!(outline(&data), (None, String::new()));
+    }
+}
+
+mod precise_bbox {
+    use ttf_parser::{GlyphId, Rect};
+    use crate::{convert, face_with_tables, Unit::*};
+
+    #[test]
+    fn quad_extrema() {
+        let glyph = convert(&[
+            Int16(1), // number of contours
+            Int16(0), // x min
+            Int16(0), // y min
+            Int16(100), // x max
+            Int16(548), // y max
+            UInt16(3), // end point [0]
+            UInt16(0), // instructions length
+            // Flags
+            UInt8(0x01), // on curve
+            UInt8(0x00), // off curve
+            UInt8(0x01), // on curve
+            UInt8(0x01), // on curve
+            // X coordinates
+            Int16(0),
+            Int16(50),
+            Int16(50),
+            Int16(-50),
+            // Y coordinates
+            Int16(330),
+            Int16(218),
+            Int16(-218),
+            Int16(-330),
+        ]);
+
+        // Short offsets. The first glyph is empty.
+        let loca = convert(&[
+            UInt16(0), // offset [0]
+            UInt16(0), // offset [1]
+            UInt16(glyph.len() as u16 / 2), // offset [2]
+        ]);
+
+        let face = face_with_tables(|t| {
+            t.loca = Some(&loca);
+            t.glyf = Some(&glyph);
+        });
+
+        assert_eq!(
+            face.glyph_bounding_box(GlyphId(1)),
+            Some(Rect { x_min: 0, y_min: 0, x_max: 100, y_max: 548 })
+        );
+        assert_eq!(
+            face.glyph_bounding_box_precise(GlyphId(1)),
+            Some(Rect { x_min: 0, y_min: 0, x_max: 100, y_max: 439 })
+        );
+        assert_eq!(face.glyph_bounding_box_precise(GlyphId(0)), None);
+    }
+}
+
+impl ttf_parser::ContourBuilder for Builder {
+    fn begin_contour(&mut self, index: u16) {
+        write!(&mut self.0, "[{} ", index).unwrap();
+    }
+
+    fn end_contour(&mut self, index: u16) {
+        write!(&mut self.0, "{}] ", index).unwrap();
+    }
+}
+
+#[test]
+fn outline_indexed() {
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+
+    let mut builder = Builder(String::new());
+    let bbox = face.outline_glyph_indexed(ttf_parser::GlyphId(1), &mut builder);
+    assert_eq!(bbox, Some(ttf_parser::Rect { x_min: 6, y_min: 0, x_max: 541, y_max: 656 }));
+    assert_eq!(builder.0, "[0 M 173 267 L 369 267 L 270 587 L 173 267 Z 0] [1 M 6 0 L 224 656 \
+                           L 320 656 L 541 0 L 452 0 L 390 200 L 151 200 L 85 0 L 6 0 Z 1] ");
+
+    let mut builder = Builder(String::new());
+    assert_eq!(face.outline_glyph_indexed(ttf_parser::GlyphId(u16::MAX), &mut builder), None);
+    assert_eq!(builder.0, "");
+}
+
+#[test]
+fn glyphs_bounding_box() {
+    use ttf_parser::{GlyphId, Rect};
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+
+    let bbox = face.glyphs_bounding_box([GlyphId(0), GlyphId(1)]).unwrap();
+    let a = face.glyph_bounding_box_precise(GlyphId(0)).unwrap();
+    let b = face.glyph_bounding_box_precise(GlyphId(1)).unwrap();
+    assert_eq!(bbox, Rect {
+        x_min: a.x_min.min(b.x_min),
+        y_min: a.y_min.min(b.y_min),
+        x_max: a.x_max.max(b.x_max),
+        y_max: a.y_max.max(b.y_max),
+    });
+
+    // Glyphs without an outline are skipped.
+    assert_eq!(face.glyphs_bounding_box([GlyphId(u16::MAX), GlyphId(1)]), Some(b));
+    assert_eq!(face.glyphs_bounding_box([GlyphId(u16::MAX)]), None);
+    assert_eq!(face.glyphs_bounding_box([]), None);
+}
+
+#[test]
+fn outline_segments_limit() {
+    use ttf_parser::{Face, GlyphId, OutlineLimits, Rect};
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+
+    let segments = |max_segments| OutlineLimits {
+        max_segments,
+        ..OutlineLimits::default()
+    };
+
+    let mut builder = Builder(String::new());
+    let bbox = face.outline_glyph_with_limits(GlyphId(1), &mut builder, segments(15));
+    assert_eq!(bbox, Some(Rect { x_min: 6, y_min: 0, x_max: 541, y_max: 656 }));
+    assert_eq!(builder.0, "M 173 267 L 369 267 L 270 587 L 173 267 Z M 6 0 L 224 656 \
+                           L 320 656 L 541 0 L 452 0 L 390 200 L 151 200 L 85 0 L 6 0 Z ");
+
+    let mut builder = Builder(String::new());
+    let bbox = face.outline_glyph_with_limits(GlyphId(1), &mut builder, segments(14));
+    assert_eq!(bbox, None);
+    assert_eq!(builder.0, "M 173 267 L 369 267 L 270 587 L 173 267 Z M 6 0 L 224 656 \
+                           L 320 656 L 541 0 L 452 0 L 390 200 L 151 200 L 85 0 L 6 0 ");
+}
+
+mod flattening {
+    use ttf_parser::{FlatteningBuilder, OutlineBuilder};
+    use super::Builder;
+
+    #[test]
+    fn lines_are_forwarded() {
+        let mut builder = Builder(String::new());
+        let mut flattener = FlatteningBuilder::new(&mut builder, 0.5);
+        flattener.move_to(0.0, 0.0);
+        flattener.line_to(10.0, 0.0);
+        flattener.close();
+        assert_eq!(builder.0, "M 0 0 L 10 0 Z ");
+    }
+
+    #[test]
+    fn flat_quad() {
+        let mut builder = Builder(String::new());
+        let mut flattener = FlatteningBuilder::new(&mut builder, 0.5);
+        flattener.move_to(0.0, 0.0);
+        flattener.quad_to(5.0, 0.0, 10.0, 0.0);
+        assert_eq!(builder.0, "M 0 0 L 10 0 ");
+    }
+
+    #[test]
+    fn quad() {
+        let mut builder = Builder(String::new());
+        let mut flattener = FlatteningBuilder::new(&mut builder, 2.0);
+        flattener.move_to(0.0, 0.0);
+        flattener.quad_to(16.0, 32.0, 32.0, 0.0);
+        assert_eq!(builder.0, "M 0 0 L 8 12 L 16 16 L 24 12 L 32 0 ");
+    }
+
+    #[test]
+    fn cubic() {
+        let mut builder = Builder(String::new());
+        let mut flattener = FlatteningBuilder::new(&mut builder, 8.0);
+        flattener.move_to(0.0, 0.0);
+        flattener.curve_to(0.0, 32.0, 32.0, 32.0, 32.0, 0.0);
+        assert_eq!(builder.0, "M 0 0 L 5 18 L 16 24 L 27 18 L 32 0 ");
+    }
+
+    #[test]
+    fn zero_tolerance() {
+        let mut builder = Builder(String::new());
+        let mut flattener = FlatteningBuilder::new(&mut builder, 0.0);
+        flattener.move_to(0.0, 0.0);
+        flattener.curve_to(0.0, 32.0, 32.0, 32.0, 32.0, 0.0);
+        assert!(builder.0.matches('L').count() <= 1 << 16);
+    }
+}
+
+mod quad_to_cubic {
+    use ttf_parser::{OutlineBuilder, QuadToCubicBuilder};
+    use super::Builder;
+
+    #[test]
+    fn quad() {
+        let mut builder = Builder(String::new());
+        let mut converter = QuadToCubicBuilder::new(&mut builder);
+        converter.move_to(0.0, 0.0);
+        converter.quad_to(15.0, 30.0, 30.0, 0.0);
+        converter.line_to(0.0, 0.0);
+        converter.quad_to(-15.0, -30.0, 30.0, 0.0);
+        converter.close();
+        assert_eq!(builder.0, "M 0 0 C 10 20 20 20 30 0 L 0 0 C -10 -20 0 -20 30 0 Z ");
+    }
+
+    #[test]
+    fn cubic_is_forwarded() {
+        let mut builder = Builder(String::new());
+        let mut converter = QuadToCubicBuilder::new(&mut builder);
+        converter.move_to(0.0, 0.0);
+        converter.curve_to(0.0, 32.0, 32.0, 32.0, 32.0, 0.0);
+        converter.quad_to(32.0, 48.0, 8.0, 0.0);
+        assert_eq!(builder.0, "M 0 0 C 0 32 32 32 32 0 C 32 32 24 32 8 0 ");
+    }
+}
+
+#[test]
+fn glyph_outline_segments() {
+    use ttf_parser::{PathSegment, PointF};
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+
+    let p = |x, y| PointF { x, y };
+    let segments = face.glyph_outline(ttf_parser::GlyphId(1)).unwrap();
+    assert_eq!(&segments[..5], &[
+        PathSegment::MoveTo(p(173.0, 267.0)),
+        PathSegment::LineTo(p(369.0, 267.0)),
+        PathSegment::LineTo(p(270.0, 587.0)),
+        PathSegment::LineTo(p(173.0, 267.0)),
+        PathSegment::Close,
+    ]);
+    assert_eq!(segments.len(), 15);
+    assert_eq!(segments.last(), Some(&PathSegment::Close));
+
+    assert_eq!(face.glyph_outline(ttf_parser::GlyphId(2)), None);
+}
+
+mod path_stats {
+    use ttf_parser::{Face, GlyphId, OutlineBuilder, PathStats};
+
+    #[test]
+    fn demo_glyph() {
+        let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+        let face = Face::parse(&data, 0).unwrap();
+
+        let stats = face.glyph_path_stats(GlyphId(1)).unwrap();
+        assert_eq!(stats.contours, 2);
+        // The outer contour is clockwise and the hole is counter-clockwise.
+        assert_eq!(stats.clockwise_contours, 1);
+        assert_eq!(stats.counter_clockwise_contours, 1);
+        assert_eq!(stats.area, -146368.0 + 31360.0);
+        assert!((stats.length - 3171.84).abs() < 0.01);
+
+        assert!(face.glyph_path_stats(GlyphId(2)).is_none());
+    }
+
+    #[test]
+    fn curves() {
+        // A circle approximation with a radius of 100.
+        const K: f32 = 55.228475;
+        let mut stats = PathStats::new();
+        stats.move_to(100.0, 0.0);
+        stats.curve_to(100.0, K, K, 100.0, 0.0, 100.0);
+        stats.curve_to(-K, 100.0, -100.0, K, -100.0, 0.0);
+        stats.curve_to(-100.0, -K, -K, -100.0, 0.0, -100.0);
+        stats.curve_to(K, -100.0, 100.0, -K, 100.0, 0.0);
+        stats.close();
+
+        assert_eq!(stats.contours, 1);
+        assert_eq!(stats.counter_clockwise_contours, 1);
+        assert!((stats.length - 2.0 * std::f32::consts::PI * 100.0).abs() < 0.5);
+        assert!((stats.area - std::f32::consts::PI * 100.0 * 100.0).abs() < 50.0);
+    }
+
+    #[test]
+    fn unclosed_contour() {
+        let mut stats = PathStats::new();
+        stats.move_to(0.0, 0.0);
+        stats.line_to(10.0, 0.0);
+        stats.line_to(10.0, 10.0);
+        assert_eq!(stats.contours, 0);
+
+        stats.finish();
+        assert_eq!(stats.contours, 1);
+        assert_eq!(stats.counter_clockwise_contours, 1);
+        assert_eq!(stats.area, 50.0);
+        assert_eq!(stats.length, 20.0 + 200f32.sqrt());
+
+        // Already finished.
+        stats.finish();
+        assert_eq!(stats.contours, 1);
+    }
+}
+
+#[test]
+fn face_glyph_is_empty() {
+    use ttf_parser::{GlyphId, RawFace, Tag};
+    use crate::face_with_tables;
+
+    let demo = RawFace::parse(include_bytes!("../fonts/demo.ttf"), 0).unwrap();
+    // Glyph 0 is empty, while glyph 1 is the demo's `A`.
+    let glyf = &demo.table(Tag::from_bytes(b"glyf")).unwrap()[40..];
+    let loca = [0u8, 0, 0, 0, 0, 26];
+    let face = face_with_tables(|t| {
+        t.glyf = Some(glyf);
+        t.loca = Some(&loca);
+    });
+
+    assert!(face.glyph_is_empty(GlyphId(0)));
+    assert!(face.outline_glyph(GlyphId(0), &mut Builder(String::new())).is_none());
+    assert!(!face.glyph_is_empty(GlyphId(1)));
+    assert!(face.outline_glyph(GlyphId(1), &mut Builder(String::new())).is_some());
+    assert!(!face.glyph_is_empty(GlyphId(2)));
+    assert_eq!(face.number_of_glyphs(), 2);
+    assert_eq!(face.outlined_glyph_count(), 1);
+}
+
+#[test]
+fn outline_limits() {
+    use ttf_parser::{GlyphId, OutlineLimits, RawFace, Tag};
+    use crate::face_with_tables;
+
+    let demo = RawFace::parse(include_bytes!("../fonts/demo.ttf"), 0).unwrap();
+    let mut glyf = vec![
+        0xFF, 0xFF, // number of contours: composite
+        0, 0, 0, 0, 0, 0, 0, 0, // bbox
+        0x00, 0x02, // flags: ARGS_ARE_XY_VALUES
+        0x00, 0x01, // glyph index
+        0, 0, // offsets
+    ];
+    // Glyph 1 is the demo's `A`.
+    glyf.extend_from_slice(&demo.table(Tag::from_bytes(b"glyf")).unwrap()[40..]);
+    let loca = [0u8, 0, 0, 8, 0, 34];
+    let face = face_with_tables(|t| {
+        t.glyf = Some(&glyf);
+        t.loca = Some(&loca);
+    });
+
+    let outline = |glyph_id, limits| {
+        let mut builder = Builder(String::new());
+        face.outline_glyph_with_limits(GlyphId(glyph_id), &mut builder, limits)
+            .map(|_| builder.0)
+    };
+
+    let mut expected = Builder(String::new());
+    face.outline_glyph(GlyphId(0), &mut expected).unwrap();
+    assert_eq!(outline(0, OutlineLimits::default()), Some(expected.0));
+
+    let depth = |max_depth| OutlineLimits {
+        max_depth,
+        ..OutlineLimits::default()
+    };
+    assert!(outline(0, depth(1)).is_some());
+    assert!(outline(0, depth(0)).is_none());
+    assert!(outline(1, depth(0)).is_some());
+
+    // The `A` glyph has 13 points: 2 `move_to` and 11 `line_to`.
+    let points = |max_points| OutlineLimits {
+        max_points,
+        ..OutlineLimits::default()
+    };
+    assert!(outline(1, points(13)).is_some());
+    assert!(outline(1, points(12)).is_none());
+}
+
+mod instructions {
+    use std::num::NonZeroU16;
+    use ttf_parser::{glyf, loca, GlyphId};
+    use ttf_parser::head::IndexToLocationFormat;
+    use crate::{convert, Unit::*};
+
+    fn instructions(glyph: &[u8]) -> Option<Vec<u8>> {
+        let loca_data = convert(&[
+            UInt32(0), // offset [0]
+            UInt32(glyph.len() as u32), // offset [1]
+        ]);
+        let loca = loca::Table::parse(
+            NonZeroU16::new(1).unwrap(),
+            IndexToLocationFormat::Long,
+            &loca_data,
+        ).unwrap();
+        let table = glyf::Table::parse(loca, glyph).unwrap();
+        table.glyph_instructions(GlyphId(0)).map(|v| v.to_vec())
+    }
+
+    #[test]
+    fn simple_glyph() {
+        let data = convert(&[
+            Int16(2), // number of contours
+            Int16(0), // x min
+            Int16(0), // y min
+            Int16(0), // x max
+            Int16(0), // y max
+            UInt16(2), // end point [0]
+            UInt16(5), // end point [1]
+            UInt16(3), // instructions length
+            Raw(&[0xB0, 0x01, 0x2C]), // instructions
+        ]);
+
+        assert_eq!(instructions(&data), Some(vec![0xB0, 0x01, 0x2C]));
+    }
+
+    #[test]
+    fn simple_glyph_without_instructions() {
+        let data = convert(&[
+            Int16(1), // number of contours
+            Int16(0), // x min
+            Int16(0), // y min
+            Int16(0), // x max
+            Int16(0), // y max
+            UInt16(2), // end point [0]
+            UInt16(0), // instructions length
+        ]);
+
+        assert_eq!(instructions(&data), None);
+    }
+
+    #[test]
+    fn composite_glyph() {
+        let data = convert(&[
+            Int16(-1), // number of contours
+            Int16(0), // x min
+            Int16(0), // y min
+            Int16(0), // x max
+            Int16(0), // y max
+            // Component [0]
+            UInt16(0x0001 | 0x0002 | 0x0008 | 0x0020), // flags
+            UInt16(1), // glyph ID
+            Int16(10), // x offset
+            Int16(20), // y offset
+            UInt16(0x4000), // scale
+            // Component [1]
+            UInt16(0x0080 | 0x0100), // flags
+            UInt16(2), // glyph ID
+            UInt8(1), // point index [0]
+            UInt8(2), // point index [1]
+            UInt16(0x4000), // xx
+            UInt16(0), // xy
+            UInt16(0), // yx
+            UInt16(0x4000), // yy
+            UInt16(2), // instructions length
+            Raw(&[0x4B, 0x53]), // instructions
+        ]);
+
+        assert_eq!(instructions(&data), Some(vec![0x4B, 0x53]));
+
+        // Without the WE_HAVE_INSTRUCTIONS flag.
+        let mut data = data;
+        data[20] = 0;
+        assert_eq!(instructions(&data), None);
+    }
+
+    #[test]
+    fn face_hinting_tables() {
+        let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+        let face = ttf_parser::Face::parse(&data, 0).unwrap();
+        assert!(face.cvt().is_none());
+        assert!(face.font_program().is_none());
+        assert!(face.prep_program().is_none());
+        assert_eq!(face.glyph_instructions(face.glyph_index('A').unwrap()), None);
+    }
+}
+
+#[test]
+fn point_counts() {
+    use ttf_parser::{Face, GlyphId, GlyphPointCounts, OutlineBuilder};
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+    assert_eq!(
+        face.glyph_point_counts(GlyphId(1)),
+        Some(GlyphPointCounts { contours: 2, on_curve_points: 13, off_curve_points: 0 })
+    );
+    assert_eq!(face.glyph_point_counts(GlyphId(2)), None);
+
+    let mut counts = GlyphPointCounts::default();
+    counts.move_to(0.0, 0.0);
+    counts.quad_to(1.0, 1.0, 2.0, 0.0);
+    counts.curve_to(1.0, 1.0, 2.0, 2.0, 3.0, 0.0);
+    counts.close();
+    assert_eq!(counts, GlyphPointCounts { contours: 1, on_curve_points: 3, off_curve_points: 3 });
+}
+
+#[test]
+fn data_range() {
+    use ttf_parser::{Face, GlyphId, Tag};
+    use crate::face_with_tables;
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+    let glyf = face.raw_face().table(Tag::from_bytes(b"glyf")).unwrap();
+
+    let range = face.glyph_data_range(GlyphId(1)).unwrap();
+    assert_eq!(range.start, 40);
+    assert_eq!(range.end, glyf.len());
+    // The `A` glyph has two contours.
+    assert_eq!(&glyf[range][..2], &[0, 2]);
+    assert_eq!(face.glyph_data_range(GlyphId(2)), None);
+
+    // An empty glyph.
+    let loca = [0u8, 0, 0, 0, 0, 0];
+    let face = face_with_tables(|t| {
+        t.glyf = Some(glyf);
+        t.loca = Some(&loca);
+    });
+    assert_eq!(face.glyph_data_range(GlyphId(0)), Some(0..0));
+}
+
+#[test]
+fn number_of_contours() {
+    use ttf_parser::{Face, GlyphId, Tag};
+    use crate::face_with_tables;
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+    assert_eq!(face.glyph_number_of_contours(GlyphId(1)), Some(2));
+    assert_eq!(face.glyph_number_of_contours(GlyphId(2)), None);
+
+    // An empty glyph.
+    let loca = [0u8, 0, 0, 0, 0, 0];
+    let raw_face = face.raw_face();
+    let face = face_with_tables(|t| {
+        t.glyf = raw_face.table(Tag::from_bytes(b"glyf"));
+        t.loca = Some(&loca);
+    });
+    assert_eq!(face.glyph_number_of_contours(GlyphId(0)), Some(0));
+}
@@ -0,0 +1,201 @@
+use ttf_parser::{GlyphId, Tag};
+use crate::{convert, face_with_tables, Unit::*};
+
+#[test]
+fn cursive_anchors() {
+    let data = convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(10), // script list offset
+        UInt16(12), // feature list offset
+        UInt16(14), // lookup list offset
+        // Script list
+        UInt16(0), // count
+        // Feature list
+        UInt16(0), // count
+        // Lookup list
+        UInt16(1), // count
+        UInt16(4), // lookup offset [0]
+        // Lookup [0]
+        UInt16(3), // lookup type
+        UInt16(0), // lookup flags
+        UInt16(1), // subtable count
+        UInt16(8), // subtable offset [0]
+        // Cursive attachment
+        UInt16(1), // format
+        UInt16(14), // coverage offset
+        UInt16(2), // entry/exit count
+        UInt16(22), // entry anchor offset [0]
+        UInt16(0), // exit anchor offset [0]
+        UInt16(0), // entry anchor offset [1]
+        UInt16(28), // exit anchor offset [1]
+        // Coverage
+        UInt16(1), // format
+        UInt16(2), // count
+        UInt16(1), // glyph [0]
+        UInt16(2), // glyph [1]
+        // Anchor [0]
+        UInt16(1), // format
+        Int16(10), // x
+        Int16(20), // y
+        // Anchor [1]
+        UInt16(1), // format
+        Int16(30), // x
+        Int16(-40), // y
+    ]);
+
+    let face = face_with_tables(|t| t.gpos = Some(&data));
+
+    let (entry, exit) = face.cursive_anchors(GlyphId(1)).unwrap();
+    let entry = entry.unwrap();
+    assert_eq!((entry.x, entry.y), (10, 20));
+    assert!(exit.is_none());
+
+    let (entry, exit) = face.cursive_anchors(GlyphId(2)).unwrap();
+    assert!(entry.is_none());
+    let exit = exit.unwrap();
+    assert_eq!((exit.x, exit.y), (30, -40));
+
+    assert!(face.cursive_anchors(GlyphId(3)).is_none());
+}
+
+#[test]
+fn single_adjustment() {
+    let data = convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(10), // script list offset
+        UInt16(12), // feature list offset
+        UInt16(26), // lookup list offset
+        // Script list
+        UInt16(0), // count
+        // Feature list
+        UInt16(1), // count
+        Raw(b"smcp"), // feature tag [0]
+        UInt16(8), // feature offset [0]
+        // Feature [0]
+        UInt16(0), // feature params offset
+        UInt16(1), // lookup count
+        UInt16(0), // lookup index [0]
+        // Lookup list
+        UInt16(1), // count
+        UInt16(4), // lookup offset [0]
+        // Lookup [0]
+        UInt16(1), // lookup type
+        UInt16(0), // lookup flags
+        UInt16(2), // subtable count
+        UInt16(10), // subtable offset [0]
+        UInt16(24), // subtable offset [1]
+        // Single adjustment, format 1
+        UInt16(1), // format
+        UInt16(8), // coverage offset
+        UInt16(0x0004), // value format: x advance
+        Int16(50), // x advance
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // count
+        UInt16(1), // glyph [0]
+        // Single adjustment, format 2
+        UInt16(2), // format
+        UInt16(16), // coverage offset
+        UInt16(0x0003), // value format: x and y placement
+        UInt16(2), // value count
+        Int16(1), // x placement [0]
+        Int16(2), // y placement [0]
+        Int16(3), // x placement [1]
+        Int16(-4), // y placement [1]
+        // Coverage
+        UInt16(1), // format
+        UInt16(2), // count
+        UInt16(2), // glyph [0]
+        UInt16(3), // glyph [1]
+    ]);
+
+    let face = face_with_tables(|t| t.gpos = Some(&data));
+    let smcp = 0;
+
+    let value = face.single_adjustment(GlyphId(1), smcp).unwrap();
+    assert_eq!((value.x_placement, value.y_placement, value.x_advance), (0, 0, 50));
+
+    let value = face.single_adjustment(GlyphId(3), smcp).unwrap();
+    assert_eq!((value.x_placement, value.y_placement, value.x_advance), (3, -4, 0));
+
+    assert!(face.single_adjustment(GlyphId(4), smcp).is_none());
+    assert!(face.single_adjustment(GlyphId(1), smcp + 1).is_none());
+}
+
+#[cfg(feature = "variable-fonts")]
+#[test]
+fn resolve_value_record() {
+    use ttf_parser::gpos::{Device, ValueRecord, VariationDevice};
+
+    let gdef_data = convert(&[
+        UInt32(0x00010003), // version
+        UInt16(0), // glyph class def offset
+        UInt16(0), // attach list offset
+        UInt16(0), // ligature caret list offset
+        UInt16(0), // mark attach class def offset
+        UInt16(0), // mark glyph sets def offset
+        UInt32(18), // item variation store offset
+        // Item variation store
+        UInt16(1), // format
+        UInt32(12), // region list offset
+        UInt16(1), // item variation data count
+        UInt32(22), // item variation data offset [0]
+        // Region list
+        UInt16(1), // axis count
+        UInt16(1), // region count
+        Int16(0), // start coord
+        Int16(0x4000), // peak coord
+        Int16(0x4000), // end coord
+        // Item variation data
+        UInt16(1), // item count
+        UInt16(1), // word delta count
+        UInt16(1), // region index count
+        UInt16(0), // region index [0]
+        Int16(100), // delta [0][0]
+    ]);
+
+    let fvar_data = convert(&[
+        UInt32(0x00010000), // version
+        UInt16(16), // axes array offset
+        UInt16(2), // reserved
+        UInt16(1), // axis count
+        UInt16(20), // axis size
+        UInt16(0), // instance count
+        UInt16(8), // instance size
+        // Axis
+        Raw(b"wght"), // tag
+        Fixed(100.0), // min value
+        Fixed(400.0), // default value
+        Fixed(900.0), // max value
+        UInt16(0), // flags
+        UInt16(256), // name ID
+    ]);
+
+    let mut face = face_with_tables(|t| {
+        t.gdef = Some(&gdef_data);
+        t.fvar = Some(&fvar_data);
+    });
+
+    let device = Device::Variation(VariationDevice { outer_index: 0, inner_index: 0 });
+    let record = ValueRecord {
+        x_placement: 5,
+        x_advance: 10,
+        x_advance_device: Some(device),
+        ..ValueRecord::default()
+    };
+
+    let resolved = face.resolve_value_record(record);
+    assert_eq!(resolved.x_advance, 10);
+    assert!(resolved.x_advance_device.is_none());
+
+    face.set_variation(Tag::from_bytes(b"wght"), 900.0).unwrap();
+    let resolved = face.resolve_value_record(record);
+    assert_eq!(resolved.x_placement, 5);
+    assert_eq!(resolved.x_advance, 110);
+    assert!(resolved.x_advance_device.is_none());
+
+    face.set_variation(Tag::from_bytes(b"wght"), 650.0).unwrap();
+    assert_eq!(face.resolve_value_record(record).x_advance, 60);
+}
@@ -0,0 +1,141 @@
+use ttf_parser::gpos::PositioningSubtable;
+use ttf_parser::gsub::{SingleSubstitution, SubstitutionSubtable};
+use ttf_parser::opentype_layout::LookupSubtable;
+use ttf_parser::GlyphId;
+use crate::{convert, face_with_tables, Unit::*};
+
+#[test]
+fn extension() {
+    let data = convert(&[
+        // Extension
+        UInt16(1), // format
+        UInt16(1), // lookup type
+        UInt32(8), // extension offset
+        // Single substitution
+        UInt16(1), // format
+        UInt16(6), // coverage offset
+        Int16(5), // delta glyph ID
+        // Coverage
+        UInt16(1), // format
+        UInt16(1), // count
+        UInt16(3), // glyph
+    ]);
+
+    let subtable = SubstitutionSubtable::parse(&data, 7).unwrap();
+    assert_eq!(subtable.kind(), 1);
+    assert!(subtable.coverage().contains(GlyphId(3)));
+    assert!(matches!(
+        subtable,
+        SubstitutionSubtable::Single(SingleSubstitution::Format1 { delta: 5, .. })
+    ));
+
+    // Lookup type 7 is contextual positioning and not an extension in GPOS.
+    assert!(PositioningSubtable::parse(&data, 7).is_none());
+}
+
+#[test]
+fn nested_extension() {
+    let data = convert(&[
+        UInt16(1), // format
+        UInt16(7), // lookup type
+        UInt32(0), // extension offset
+    ]);
+
+    assert!(SubstitutionSubtable::parse(&data, 7).is_none());
+
+    let data = convert(&[
+        UInt16(1), // format
+        UInt16(9), // lookup type
+        UInt32(0), // extension offset
+    ]);
+
+    assert!(PositioningSubtable::parse(&data, 9).is_none());
+}
+
+#[cfg(feature = "variable-fonts")]
+#[test]
+fn feature_variations() {
+    use ttf_parser::Tag;
+
+    let gsub_data = convert(&[
+        UInt16(1), // major version
+        UInt16(1), // minor version
+        UInt16(14), // script list offset
+        UInt16(16), // feature list offset
+        UInt16(30), // lookup list offset
+        UInt32(32), // feature variations offset
+        // Script list
+        UInt16(0), // count
+        // Feature list
+        UInt16(1), // count
+        Raw(b"rvrn"), // feature tag [0]
+        UInt16(8), // feature offset [0]
+        // Feature [0]
+        UInt16(0), // feature params offset
+        UInt16(1), // lookup count
+        UInt16(0), // lookup index [0]
+        // Lookup list
+        UInt16(0), // count
+        // Feature variations
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt32(1), // count
+        UInt32(16), // condition set offset [0]
+        UInt32(30), // feature table substitution offset [0]
+        // Condition set
+        UInt16(1), // count
+        UInt32(6), // condition offset [0]
+        // Condition
+        UInt16(1), // format
+        UInt16(0), // axis index
+        Int16(0x2000), // filter range min value
+        Int16(0x4000), // filter range max value
+        // Feature table substitution
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        UInt16(1), // count
+        UInt16(0), // feature index [0]
+        UInt32(12), // alternate feature offset [0]
+        // Alternate feature
+        UInt16(0), // feature params offset
+        UInt16(2), // lookup count
+        UInt16(1), // lookup index [0]
+        UInt16(2), // lookup index [1]
+    ]);
+
+    let fvar_data = convert(&[
+        UInt32(0x00010000), // version
+        UInt16(16), // axes array offset
+        UInt16(2), // reserved
+        UInt16(1), // axis count
+        UInt16(20), // axis size
+        UInt16(0), // instance count
+        UInt16(8), // instance size
+        // Axis
+        Raw(b"wght"), // tag
+        Fixed(100.0), // min value
+        Fixed(400.0), // default value
+        Fixed(900.0), // max value
+        UInt16(0), // flags
+        UInt16(256), // name ID
+    ]);
+
+    let mut face = face_with_tables(|t| {
+        t.gsub = Some(&gsub_data);
+        t.fvar = Some(&fvar_data);
+    });
+
+    let rvrn = face.tables().gsub.unwrap().features.index(Tag::from_bytes(b"rvrn")).unwrap();
+    let lookups: Vec<_> = face.active_feature_variation_lookups(rvrn).collect();
+    assert_eq!(lookups, [0]);
+
+    face.set_variation(Tag::from_bytes(b"wght"), 900.0).unwrap();
+    let lookups: Vec<_> = face.active_feature_variation_lookups(rvrn).collect();
+    assert_eq!(lookups, [1, 2]);
+
+    face.set_variation(Tag::from_bytes(b"wght"), 450.0).unwrap();
+    let lookups: Vec<_> = face.active_feature_variation_lookups(rvrn).collect();
+    assert_eq!(lookups, [0]);
+
+    assert_eq!(face.active_feature_variation_lookups(rvrn + 1).count(), 0);
+}
@@ -112,3 +112,221 @@ fn glyph_out_of_bounds_1() {
     assert_eq!(table.advance(GlyphId(2)), None);
     assert_eq!(table.side_bearing(GlyphId(2)), None);
 }
+
+#[test]
+fn monospaced() {
+    let data = convert(&[
+        UInt16(600), // advance width [0]
+        Int16(0), // side bearing [0]
+        UInt16(0), // advance width [1]
+        Int16(0), // side bearing [1]
+        UInt16(600), // advance width [2]
+        Int16(0), // side bearing [2]
+    ]);
+
+    let table = Table::parse(3, nzu16!(3), &data).unwrap();
+    assert!(table.is_monospaced());
+}
+
+#[test]
+fn not_monospaced() {
+    let data = convert(&[
+        UInt16(600), // advance width [0]
+        Int16(0), // side bearing [0]
+        UInt16(500), // advance width [1]
+        Int16(0), // side bearing [1]
+    ]);
+
+    let table = Table::parse(2, nzu16!(2), &data).unwrap();
+    assert!(!table.is_monospaced());
+}
+
+#[test]
+fn monospaced_zero_advances() {
+    let data = convert(&[
+        UInt16(0), // advance width [0]
+        Int16(0), // side bearing [0]
+    ]);
+
+    let table = Table::parse(1, nzu16!(1), &data).unwrap();
+    assert!(!table.is_monospaced());
+}
+
+#[test]
+fn face_advances_f32() {
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+
+    let glyphs = [GlyphId(1), GlyphId(0), GlyphId(1)];
+    let mut advances = [0.0; 3];
+    assert_eq!(face.glyph_hor_advances_f32(&glyphs, &mut advances), 3);
+    for (glyph_id, advance) in glyphs.iter().zip(advances) {
+        assert_eq!(face.glyph_hor_advance(*glyph_id).map(f32::from), Some(advance));
+    }
+
+    // Output is shorter.
+    let mut advances = [0.0; 2];
+    assert_eq!(face.glyph_hor_advances_f32(&glyphs, &mut advances), 2);
+
+    // Stops at an out of bounds glyph.
+    let mut advances = [0.0; 3];
+    assert_eq!(face.glyph_hor_advances_f32(&[GlyphId(0), GlyphId(5), GlyphId(1)], &mut advances), 1);
+}
+
+#[test]
+fn face_advance_cache() {
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+
+    let mut advances = vec![0; usize::from(face.number_of_glyphs())];
+    assert_eq!(face.build_advance_cache(&mut advances), advances.len());
+    for glyph_id in face.glyphs() {
+        assert_eq!(face.glyph_hor_advance(glyph_id), Some(advances[usize::from(glyph_id.0)]));
+    }
+
+    // Output is longer.
+    let mut advances = vec![7; usize::from(face.number_of_glyphs()) + 1];
+    assert_eq!(face.build_advance_cache(&mut advances), advances.len() - 1);
+    assert_eq!(advances.last(), Some(&7));
+
+    // Output is shorter.
+    let mut advances = [0; 1];
+    assert_eq!(face.build_advance_cache(&mut advances), 1);
+}
+
+#[cfg(feature = "variable-fonts")]
+#[test]
+fn face_advances_f32_variable() {
+    let data = std::fs::read("tests/fonts/colr_1_variable.ttf").unwrap();
+    let mut face = ttf_parser::Face::parse(&data, 0).unwrap();
+    for axis in face.variation_axes() {
+        face.set_variation(axis.tag, axis.max_value);
+    }
+
+    let glyphs: Vec<_> = (0..face.number_of_glyphs()).map(GlyphId).collect();
+    let mut advances = vec![0.0; glyphs.len()];
+    assert_eq!(face.glyph_hor_advances_f32(&glyphs, &mut advances), glyphs.len());
+    for (glyph_id, advance) in glyphs.iter().zip(advances) {
+        assert_eq!(face.glyph_hor_advance(*glyph_id), Some((advance + 0.5) as u16));
+    }
+}
+
+#[test]
+fn face_advance_f32() {
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+    for glyph_id in face.glyphs() {
+        assert_eq!(
+            face.glyph_hor_advance_f32(glyph_id),
+            face.glyph_hor_advance(glyph_id).map(f32::from)
+        );
+        // No `vmtx`.
+        assert_eq!(face.glyph_ver_advance_f32(glyph_id), None);
+    }
+
+    assert_eq!(face.glyph_hor_advance_f32(GlyphId(5)), None);
+}
+
+#[cfg(feature = "variable-fonts")]
+#[test]
+fn face_advance_f32_variable() {
+    let data = std::fs::read("tests/fonts/colr_1_variable.ttf").unwrap();
+    let mut face = ttf_parser::Face::parse(&data, 0).unwrap();
+    for axis in face.variation_axes() {
+        face.set_variation(axis.tag, axis.max_value);
+    }
+
+    for glyph_id in face.glyphs() {
+        let advance = face.glyph_hor_advance_f32(glyph_id).unwrap();
+        assert_eq!(face.glyph_hor_advance(glyph_id), Some((advance + 0.5) as u16));
+    }
+}
+
+#[test]
+fn face_number_of_metrics() {
+    use ttf_parser::{Face, Tag};
+
+    let data = std::fs::read("tests/fonts/colr_1_variable.ttf").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+    let hhea = face.raw_face().table(Tag::from_bytes(b"hhea")).unwrap();
+    assert_eq!(
+        face.number_of_h_metrics(),
+        u16::from_be_bytes([hhea[34], hhea[35]])
+    );
+    assert!(face.number_of_h_metrics() > 0);
+    assert_eq!(face.number_of_v_metrics(), None);
+}
+
+#[cfg(feature = "variable-fonts")]
+#[test]
+fn hvar_advance_index() {
+    use ttf_parser::hvar;
+
+    let data = std::fs::read("tests/fonts/colr_1_variable.ttf").unwrap();
+    let face = ttf_parser::Face::parse(&data, 0).unwrap();
+    let table = face.tables().hvar.unwrap();
+    assert!(table.has_advance_map());
+    // A single map entry applies to all glyphs.
+    assert_eq!(table.advance_index(GlyphId(0)), Some((0, 0)));
+    assert_eq!(table.advance_index(GlyphId(100)), Some((0, 0)));
+
+    // No advance width mapping.
+    let mut hvar_data = face.raw_face().table(ttf_parser::Tag::from_bytes(b"HVAR")).unwrap().to_vec();
+    hvar_data[8..12].copy_from_slice(&[0; 4]);
+    let table = hvar::Table::parse(&hvar_data).unwrap();
+    assert!(!table.has_advance_map());
+    assert_eq!(table.advance_index(GlyphId(7)), Some((0, 7)));
+}
+
+#[test]
+fn face_vertical_metrics() {
+    use ttf_parser::{Face, RawFace, Tag};
+    use crate::face_with_tables;
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+    assert!(!face.has_vertical_metrics());
+    assert!(!face.is_vertical_only());
+
+    // `vhea` and `vmtx` have the same layout as `hhea` and `hmtx`.
+    let raw_face = RawFace::parse(&data, 0).unwrap();
+    let table = |tag: &[u8; 4]| raw_face.table(Tag::from_bytes(tag)).unwrap();
+    let face = face_with_tables(|t| {
+        t.vhea = Some(table(b"hhea"));
+        t.vmtx = Some(table(b"hmtx"));
+    });
+    assert!(face.has_vertical_metrics());
+    assert!(face.is_vertical_only());
+
+    let face = face_with_tables(|t| {
+        t.hmtx = Some(table(b"hmtx"));
+        t.vhea = Some(table(b"hhea"));
+        t.vmtx = Some(table(b"hmtx"));
+    });
+    assert!(face.has_vertical_metrics());
+    assert!(!face.is_vertical_only());
+
+    // `vmtx` requires `vhea`.
+    let face = face_with_tables(|t| t.vmtx = Some(table(b"hmtx")));
+    assert!(!face.has_vertical_metrics());
+}
+
+#[test]
+fn face_glyph_metrics() {
+    use ttf_parser::{Face, GlyphMetrics, Rect};
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+    assert_eq!(
+        face.glyph_metrics(GlyphId(1)),
+        Some(GlyphMetrics {
+            hor_advance: face.glyph_hor_advance(GlyphId(1)),
+            hor_side_bearing: face.glyph_hor_side_bearing(GlyphId(1)),
+            ver_advance: None,
+            ver_side_bearing: None,
+            bounding_box: Some(Rect { x_min: 6, y_min: 0, x_max: 541, y_max: 656 }),
+        })
+    );
+    assert!(face.glyph_metrics(GlyphId(1)).unwrap().hor_advance.is_some());
+    assert_eq!(face.glyph_metrics(GlyphId(2)), None);
+}
@@ -0,0 +1,185 @@
+use ttf_parser::kern::Table;
+use ttf_parser::GlyphId;
+use crate::{convert, face_with_tables, Unit::*};
+
+#[test]
+fn format0_pairs() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(1), // number of subtables
+        // Subtable
+        UInt16(0), // version
+        UInt16(26), // length
+        UInt8(0), // format
+        UInt8(1), // coverage
+        UInt16(2), // number of pairs
+        UInt16(12), // search range
+        UInt16(1), // entry selector
+        UInt16(0), // range shift
+        UInt16(1), // left [0]
+        UInt16(2), // right [0]
+        Int16(-50), // value [0]
+        UInt16(3), // left [1]
+        UInt16(1), // right [1]
+        Int16(20), // value [1]
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let subtable = table.subtables.into_iter().next().unwrap();
+    assert!(subtable.horizontal);
+
+    let pairs: Vec<_> = subtable.pairs().collect();
+    assert_eq!(
+        pairs,
+        [
+            (GlyphId(1), GlyphId(2), -50),
+            (GlyphId(3), GlyphId(1), 20),
+        ]
+    );
+
+    for (left, right, value) in pairs {
+        assert_eq!(subtable.glyphs_kerning(left, right), Some(value));
+    }
+}
+
+#[test]
+fn ot_coverage() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(2), // number of subtables
+        // Subtable [0]
+        UInt16(0), // version
+        UInt16(14), // length
+        UInt8(0), // format
+        UInt8(5), // coverage, horizontal and cross-stream
+        UInt16(0), // number of pairs
+        UInt16(0), // search range
+        UInt16(0), // entry selector
+        UInt16(0), // range shift
+        // Subtable [1]
+        UInt16(0), // version
+        UInt16(14), // length
+        UInt8(0), // format
+        UInt8(0), // coverage, vertical
+        UInt16(0), // number of pairs
+        UInt16(0), // search range
+        UInt16(0), // entry selector
+        UInt16(0), // range shift
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let subtables: Vec<_> = table.subtables.into_iter().collect();
+    assert_eq!(subtables.len(), 2);
+    assert!(subtables[0].horizontal);
+    assert!(subtables[0].has_cross_stream);
+    assert!(!subtables[0].variable);
+    assert!(!subtables[1].horizontal);
+    assert!(!subtables[1].has_cross_stream);
+}
+
+#[test]
+fn aat_coverage() {
+    let data = convert(&[
+        UInt32(0x00010000), // version
+        UInt32(2), // number of subtables
+        // Subtable [0]
+        UInt32(22), // length
+        UInt8(0xA0), // coverage, vertical and variable
+        UInt8(0), // format
+        UInt16(0), // tuple index
+        UInt16(1), // number of pairs
+        UInt16(6), // search range
+        UInt16(0), // entry selector
+        UInt16(0), // range shift
+        UInt16(1), // left [0]
+        UInt16(2), // right [0]
+        Int16(-30), // value [0]
+        // Subtable [1]
+        UInt32(16), // length
+        UInt8(0x40), // coverage, horizontal and cross-stream
+        UInt8(0), // format
+        UInt16(0), // tuple index
+        UInt16(0), // number of pairs
+        UInt16(0), // search range
+        UInt16(0), // entry selector
+        UInt16(0), // range shift
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let subtables: Vec<_> = table.subtables.into_iter().collect();
+    assert_eq!(subtables.len(), 2);
+    assert!(!subtables[0].horizontal);
+    assert!(subtables[0].variable);
+    assert!(!subtables[0].has_cross_stream);
+    assert_eq!(subtables[0].glyphs_kerning(GlyphId(1), GlyphId(2)), Some(-30));
+    assert!(subtables[1].horizontal);
+    assert!(!subtables[1].variable);
+    assert!(subtables[1].has_cross_stream);
+}
+
+#[test]
+fn aat_format3() {
+    let data = convert(&[
+        UInt32(0x00010000), // version
+        UInt32(1), // number of subtables
+        // Subtable
+        UInt32(26), // length
+        UInt8(0), // coverage, horizontal
+        UInt8(3), // format
+        UInt16(0), // tuple index
+        UInt16(2), // glyph count
+        UInt8(2), // kerning values count
+        UInt8(2), // left-hand classes count
+        UInt8(2), // right-hand classes count
+        UInt8(0), // flags
+        Int16(0), // kerning value [0]
+        Int16(-20), // kerning value [1]
+        UInt8(0), // left-hand class [0]
+        UInt8(0), // left-hand class [1]
+        UInt8(0), // right-hand class [0]
+        UInt8(2), // right-hand class [1], out of range
+        UInt8(1), // index [0]
+        UInt8(0), // index [1]
+        UInt8(0), // index [2]
+        UInt8(0), // index [3]
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let subtable = table.subtables.into_iter().next().unwrap();
+    assert!(subtable.horizontal);
+    assert_eq!(subtable.glyphs_kerning(GlyphId(0), GlyphId(0)), Some(-20));
+    assert_eq!(subtable.glyphs_kerning(GlyphId(0), GlyphId(1)), None);
+}
+
+#[test]
+fn face_vertical_kerning() {
+    let format0 = |coverage, value| convert(&[
+        UInt16(0), // version
+        UInt16(20), // length
+        UInt8(0), // format
+        UInt8(coverage), // coverage
+        UInt16(1), // number of pairs
+        UInt16(6), // search range
+        UInt16(0), // entry selector
+        UInt16(0), // range shift
+        UInt16(1), // left [0]
+        UInt16(2), // right [0]
+        Int16(value), // value [0]
+    ]);
+
+    let mut data = convert(&[
+        UInt16(0), // version
+        UInt16(4), // number of subtables
+    ]);
+    data.extend(format0(1, -50)); // horizontal
+    data.extend(format0(0, -30)); // vertical
+    data.extend(format0(0, -10)); // vertical
+    data.extend(format0(4, 100)); // vertical, cross-stream
+
+    let face = face_with_tables(|t| t.kern = Some(&data));
+    assert_eq!(face.glyphs_vertical_kerning(GlyphId(1), GlyphId(2)), Some(-40));
+    assert_eq!(face.glyphs_vertical_kerning(GlyphId(2), GlyphId(1)), None);
+
+    let face = face_with_tables(|_| {});
+    assert_eq!(face.glyphs_vertical_kerning(GlyphId(1), GlyphId(2)), None);
+}
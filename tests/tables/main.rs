@@ -1,16 +1,33 @@
 #[rustfmt::skip] mod aat;
 #[rustfmt::skip] mod ankr;
+#[rustfmt::skip] mod base;
 #[rustfmt::skip] mod cff1;
 #[rustfmt::skip] mod cmap;
 #[rustfmt::skip] mod colr;
+#[rustfmt::skip] mod cpal;
+#[rustfmt::skip] mod dsig;
 #[rustfmt::skip] mod feat;
+#[rustfmt::skip] mod fvar;
+#[rustfmt::skip] mod gasp;
+#[rustfmt::skip] mod gdef;
 #[rustfmt::skip] mod glyf;
+#[rustfmt::skip] mod gpos;
+#[rustfmt::skip] mod gsub;
 #[rustfmt::skip] mod hmtx;
+#[rustfmt::skip] mod kern;
 #[rustfmt::skip] mod maxp;
+#[rustfmt::skip] mod meta;
+#[rustfmt::skip] mod morx;
+#[rustfmt::skip] mod name;
+#[rustfmt::skip] mod os2;
+#[rustfmt::skip] mod post;
 #[rustfmt::skip] mod sbix;
+#[rustfmt::skip] mod stat;
+#[rustfmt::skip] mod svg;
 #[rustfmt::skip] mod trak;
+#[rustfmt::skip] mod vorg;
 
-use ttf_parser::{fonts_in_collection, Face, FaceParsingError};
+use ttf_parser::{fonts_in_collection, Face, FaceParsingError, RawFace, RawFaceTables, Tag};
 
 #[allow(dead_code)]
 #[derive(Clone, Copy)]
@@ -63,6 +80,19 @@ fn convert_unit(unit: Unit, data: &mut Vec<u8>) {
     }
 }
 
+/// Creates a face from the demo font required tables and the tables set by `f`.
+pub fn face_with_tables<'a>(f: impl FnOnce(&mut RawFaceTables<'a>)) -> Face<'a> {
+    let demo = RawFace::parse(include_bytes!("../fonts/demo.ttf"), 0).unwrap();
+    let mut tables = RawFaceTables {
+        head: demo.table(Tag::from_bytes(b"head")).unwrap(),
+        hhea: demo.table(Tag::from_bytes(b"hhea")).unwrap(),
+        maxp: demo.table(Tag::from_bytes(b"maxp")).unwrap(),
+        ..RawFaceTables::default()
+    };
+    f(&mut tables);
+    Face::from_raw_tables(tables).unwrap()
+}
+
 #[test]
 fn empty_font() {
     assert_eq!(
@@ -136,7 +166,6 @@ fn font_collection_num_fonts_overflow_1() {
 }
 
 #[test]
-#[should_panic]
 fn font_collection_num_fonts_overflow_2() {
     use Unit::*;
     let data = convert(&[
@@ -187,3 +216,264 @@ fn font_index_overflow_on_regular_font() {
         FaceParsingError::FaceIndexOutOfBounds
     );
 }
+
+#[cfg(feature = "fuzzing")]
+#[test]
+fn exercise_all() {
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+    let summary = face.exercise_all();
+    assert_eq!(summary.outlines, 2);
+    assert_eq!(summary.code_points, 1);
+    assert_eq!(summary.images, 0);
+
+    for path in [
+        "tests/fonts/bitmap.otb",
+        "tests/fonts/colr_1.ttf",
+        "tests/fonts/colr_1_variable.ttf",
+    ] {
+        let data = std::fs::read(path).unwrap();
+        let _ = Face::parse(&data, 0).unwrap().exercise_all();
+    }
+}
+
+#[test]
+fn parse_with_warnings() {
+    use ttf_parser::{FaceWarning, FaceWarningKind, Tag};
+
+    let mut data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let (face, warnings) = Face::parse_with_warnings(&data, 0);
+    assert!(face.is_ok());
+    assert!(warnings.is_empty());
+
+    // Table records are: cmap, glyf, head, hhea, hmtx, loca and maxp.
+    let record = |index: usize| 12 + index * 16;
+    // Move cmap out of bounds.
+    data[record(0) + 8..record(0) + 12].copy_from_slice(&0xFFFF0000u32.to_be_bytes());
+    // Truncate hmtx.
+    data[record(4) + 12..record(4) + 16].copy_from_slice(&2u32.to_be_bytes());
+    // Rename loca.
+    data[record(5)..record(5) + 4].copy_from_slice(b"locb");
+
+    let (face, warnings) = Face::parse_with_warnings(&data, 0);
+    let face = face.unwrap();
+    assert!(face.tables().cmap.is_none());
+    assert_eq!(
+        warnings,
+        [
+            FaceWarning {
+                tag: Tag::from_bytes(b"cmap"),
+                kind: FaceWarningKind::OutOfBounds,
+            },
+            FaceWarning {
+                tag: Tag::from_bytes(b"glyf"),
+                kind: FaceWarningKind::MissingDependency(Tag::from_bytes(b"loca")),
+            },
+            FaceWarning {
+                tag: Tag::from_bytes(b"hmtx"),
+                kind: FaceWarningKind::Malformed,
+            },
+        ]
+    );
+    assert_eq!(
+        warnings[1].to_string(),
+        "the glyf table is ignored because the loca table is missing"
+    );
+}
+
+#[test]
+fn outline_format() {
+    use ttf_parser::OutlineFormat;
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+    assert_eq!(face.outline_format(), Some(OutlineFormat::TrueType));
+    assert!(!face.is_collection_member());
+
+    let data = std::fs::read("tests/fonts/bitmap.otb").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+    assert_eq!(face.outline_format(), None);
+}
+
+/// Creates a font collection with `number_of_faces` faces, all pointing to demo.ttf.
+fn demo_collection(number_of_faces: u32) -> Vec<u8> {
+    use Unit::*;
+
+    let demo = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let header_len = 12 + number_of_faces * 4;
+    let mut data = convert(&[
+        Raw(b"ttcf"),            // magic
+        UInt32(0x00010000),      // version
+        UInt32(number_of_faces), // number of faces
+    ]);
+    for _ in 0..number_of_faces {
+        data.extend_from_slice(&header_len.to_be_bytes());
+    }
+    data.extend_from_slice(&demo);
+
+    // Table offsets are from the start of the collection.
+    let num_tables = u16::from_be_bytes([demo[4], demo[5]]);
+    for i in 0..usize::from(num_tables) {
+        let offset = header_len as usize + 12 + i * 16 + 8;
+        let value = u32::from_be_bytes([
+            data[offset],
+            data[offset + 1],
+            data[offset + 2],
+            data[offset + 3],
+        ]);
+        data[offset..offset + 4].copy_from_slice(&(value + header_len).to_be_bytes());
+    }
+
+    data
+}
+
+#[test]
+fn valid_glyph() {
+    use ttf_parser::GlyphId;
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+    assert!(face.is_valid_glyph(GlyphId(0)));
+    assert!(face.is_valid_glyph(GlyphId(1)));
+    assert!(!face.is_valid_glyph(GlyphId(2)));
+    assert_eq!(face.glyph_hor_advance(GlyphId(2)), None);
+    assert!(!face.glyph_is_empty(GlyphId(2)));
+}
+
+#[test]
+fn collection_member() {
+    let data = demo_collection(1);
+    let face = Face::parse(&data, 0).unwrap();
+    assert!(face.is_collection_member());
+    assert_eq!(face.number_of_glyphs(), 2);
+}
+
+#[test]
+fn collection_iter() {
+    let data = demo_collection(3);
+    let faces = Face::collection(&data);
+    assert_eq!(faces.len(), 3);
+    for face in faces {
+        assert!(face.unwrap().is_collection_member());
+    }
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let faces: Vec<_> = Face::collection(&data).collect();
+    assert_eq!(faces.len(), 1);
+    assert!(!faces[0].as_ref().unwrap().is_collection_member());
+
+    let mut faces = Face::collection(&[]);
+    assert_eq!(
+        faces.next().unwrap().unwrap_err(),
+        FaceParsingError::UnknownMagic
+    );
+    assert!(faces.next().is_none());
+
+    // Face offset inside the collection header.
+    let mut data = demo_collection(1);
+    data[12..16].copy_from_slice(&4u32.to_be_bytes());
+    let mut faces = Face::collection(&data);
+    assert_eq!(
+        faces.next().unwrap().unwrap_err(),
+        FaceParsingError::MalformedFont
+    );
+    assert!(faces.next().is_none());
+}
+
+#[test]
+fn head_checksum_adjustment() {
+    for path in [
+        "tests/fonts/demo.ttf",
+        "tests/fonts/colr_1.ttf",
+        "tests/fonts/bitmap.otb",
+    ] {
+        let data = std::fs::read(path).unwrap();
+        let face = Face::parse(&data, 0).unwrap();
+        assert_eq!(face.verify_head_checksum_adjustment(), Some(true));
+    }
+
+    let mut data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let last = data.len() - 1;
+    data[last] ^= 1;
+    let face = Face::parse(&data, 0).unwrap();
+    assert_eq!(face.verify_head_checksum_adjustment(), Some(false));
+
+    let data = demo_collection(1);
+    let face = Face::parse(&data, 0).unwrap();
+    assert_eq!(face.verify_head_checksum_adjustment(), None);
+}
+
+#[test]
+fn graphite_tables() {
+    use ttf_parser::{RawFace, Tag};
+
+    let demo = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = Face::parse(&demo, 0).unwrap();
+    assert!(!face.has_graphite_tables());
+
+    let raw_face = RawFace::parse(&demo, 0).unwrap();
+    let tables: Vec<(&[u8; 4], &[u8])> = vec![
+        (b"Glat", &[0, 1, 0, 0]),
+        (b"Gloc", &[0, 1, 0, 0]),
+        (b"Silf", &[0, 5, 0, 0]),
+        (b"head", raw_face.table(Tag::from_bytes(b"head")).unwrap()),
+        (b"hhea", raw_face.table(Tag::from_bytes(b"hhea")).unwrap()),
+        (b"maxp", raw_face.table(Tag::from_bytes(b"maxp")).unwrap()),
+    ];
+
+    let mut data = convert(&[
+        Unit::Raw(&[0x00, 0x01, 0x00, 0x00]), // magic
+        Unit::UInt16(tables.len() as u16),    // numTables
+        Unit::UInt16(0),                      // searchRange
+        Unit::UInt16(0),                      // entrySelector
+        Unit::UInt16(0),                      // rangeShift
+    ]);
+    let mut offset = 12 + tables.len() * 16;
+    for (tag, table) in &tables {
+        data.extend_from_slice(*tag);
+        data.extend_from_slice(&0u32.to_be_bytes()); // checksum
+        data.extend_from_slice(&(offset as u32).to_be_bytes());
+        data.extend_from_slice(&(table.len() as u32).to_be_bytes());
+        offset += table.len();
+    }
+    for (_, table) in &tables {
+        data.extend_from_slice(table);
+    }
+
+    let face = Face::parse(&data, 0).unwrap();
+    assert!(face.has_graphite_tables());
+    assert_eq!(
+        face.raw_face().table(Tag::from_bytes(b"Silf")),
+        Some(&[0, 5, 0, 0][..])
+    );
+}
+
+#[cfg(feature = "variable-fonts")]
+#[test]
+fn variation_axis() {
+    let data = std::fs::read("tests/fonts/colr_1_variable.ttf").unwrap();
+    let mut face = Face::parse(&data, 0).unwrap();
+    let count = face.variation_axis_count();
+    assert_ne!(count, 0);
+    assert_eq!(usize::from(count), face.variation_coordinates().len());
+    assert!(face.variation_axis(count).is_none());
+
+    for index in 0..count {
+        let axis = face.variation_axis(index).unwrap();
+        assert_eq!(face.variation_axes().get(index).unwrap().tag, axis.tag);
+        if axis.max_value > axis.def_value {
+            face.set_variation(axis.tag, axis.max_value).unwrap();
+            assert!(face.variation_coordinates()[usize::from(index)].get() > 0);
+        }
+    }
+
+    assert!(face.has_non_default_variation_coordinates());
+    face.reset_variations();
+    assert!(!face.has_non_default_variation_coordinates());
+    assert_eq!(usize::from(count), face.variation_coordinates().len());
+
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+    assert_eq!(face.variation_axis_count(), 0);
+    assert!(face.variation_axis(0).is_none());
+}
@@ -63,3 +63,11 @@ fn zero_glyphs() {
 
 // TODO: what to do when the number of glyphs is 0xFFFF?
 //       we're actually checking this in loca
+
+#[test]
+fn face_glyphs() {
+    let face = ttf_parser::Face::parse(include_bytes!("../fonts/demo.ttf"), 0).unwrap();
+    let glyphs: Vec<_> = face.glyphs().collect();
+    assert_eq!(glyphs.len(), usize::from(face.number_of_glyphs()));
+    assert_eq!(glyphs, [ttf_parser::GlyphId(0), ttf_parser::GlyphId(1)]);
+}
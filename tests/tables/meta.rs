@@ -0,0 +1,63 @@
+use ttf_parser::meta::Table;
+use ttf_parser::{Face, Tag};
+use crate::{convert, face_with_tables, Unit::*};
+
+fn languages_table() -> Vec<u8> {
+    convert(&[
+        UInt32(1), // version
+        UInt32(0), // flags
+        UInt32(0), // reserved
+        UInt32(3), // number of data maps
+        // Data map [0]
+        Raw(b"dlng"), // tag
+        UInt32(52), // offset
+        UInt32(4), // length
+        // Data map [1]
+        Raw(b"slng"), // tag
+        UInt32(56), // offset
+        UInt32(9), // length
+        // Data map [2], out of bounds
+        Raw(b"appl"), // tag
+        UInt32(56), // offset
+        UInt32(100), // length
+        // Data
+        Raw(b"Jpan"),
+        Raw(b"Jpan,Latn"),
+    ])
+}
+
+#[test]
+fn languages() {
+    let data = languages_table();
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.design_languages(), Some("Jpan"));
+    assert_eq!(table.supported_languages(), Some("Jpan,Latn"));
+    assert_eq!(table.get(Tag::from_bytes(b"appl")), None);
+    assert_eq!(table.get(Tag::from_bytes(b"test")), None);
+}
+
+#[test]
+fn unsupported_version() {
+    let data = convert(&[
+        UInt32(2), // version
+        UInt32(0), // flags
+        UInt32(0), // reserved
+        UInt32(0), // number of data maps
+    ]);
+
+    assert!(Table::parse(&data).is_none());
+}
+
+#[test]
+fn face() {
+    let font_data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+
+    let face = Face::parse(&font_data, 0).unwrap();
+    assert_eq!(face.design_languages(), None);
+    assert_eq!(face.supported_languages(), None);
+
+    let data = languages_table();
+    let face = face_with_tables(|t| t.meta = Some(&data));
+    assert_eq!(face.design_languages(), Some("Jpan"));
+    assert_eq!(face.supported_languages(), Some("Jpan,Latn"));
+}
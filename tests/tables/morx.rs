@@ -0,0 +1,44 @@
+use ttf_parser::GlyphId;
+use crate::{convert, face_with_tables, Unit::*};
+
+// A single chain with a single non-contextual subtable,
+// enabled by default and disabled by feature 1, setting 1.
+fn non_contextual_morx() -> Vec<u8> {
+    convert(&[
+        UInt16(2), // version
+        UInt16(0), // unused
+        UInt32(1), // number of chains
+        // Chain [0]
+        UInt32(1), // default flags
+        UInt32(48), // chain length
+        UInt32(1), // number of features
+        UInt32(1), // number of subtables
+        // Feature [0]
+        UInt16(1), // feature type
+        UInt16(1), // feature setting
+        UInt32(0), // enable flags
+        UInt32(0xFFFFFFFE), // disable flags
+        // Subtable [0]
+        UInt32(20), // length
+        UInt8(0), // coverage
+        UInt16(0), // reserved
+        UInt8(4), // type: non-contextual
+        UInt32(1), // feature flags
+        // Lookup
+        UInt16(8), // format: trimmed array
+        UInt16(1), // first glyph
+        UInt16(1), // glyph count
+        UInt16(0), // value [0]
+    ])
+}
+
+#[test]
+fn non_contextual_substitution() {
+    let data = non_contextual_morx();
+    let face = face_with_tables(|t| t.morx = Some(&data));
+
+    assert_eq!(face.apple_substitution(GlyphId(1), &[]), Some(GlyphId(0)));
+    assert_eq!(face.apple_substitution(GlyphId(0), &[]), None);
+    assert_eq!(face.apple_substitution(GlyphId(1), &[(1, 1)]), None);
+    assert_eq!(face.apple_substitution(GlyphId(1), &[(1, 0)]), Some(GlyphId(0)));
+}
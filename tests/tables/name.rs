@@ -0,0 +1,97 @@
+use ttf_parser::name::{name_id, PlatformId, Table};
+use crate::{convert, Unit::*};
+
+fn names_table() -> Vec<u8> {
+    convert(&[
+        UInt16(0), // version
+        UInt16(4), // count
+        UInt16(54), // storage offset
+        // Record [0]
+        UInt16(1), // platform ID, Macintosh
+        UInt16(0), // encoding ID, Roman
+        UInt16(0), // language ID, English
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(3), // length
+        UInt16(0), // offset
+        // Record [1]
+        UInt16(3), // platform ID, Windows
+        UInt16(1), // encoding ID, Unicode BMP
+        UInt16(0x0407), // language ID, German
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(2), // length
+        UInt16(3), // offset
+        // Record [2]
+        UInt16(3), // platform ID, Windows
+        UInt16(1), // encoding ID, Unicode BMP
+        UInt16(0x0409), // language ID, English (United States)
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(2), // length
+        UInt16(5), // offset
+        // Record [3]
+        UInt16(1), // platform ID, Macintosh
+        UInt16(0), // encoding ID, Roman
+        UInt16(0), // language ID, English
+        UInt16(name_id::SUBFAMILY), // name ID
+        UInt16(1), // length
+        UInt16(7), // offset
+        // Storage
+        Raw(b"Mac"),
+        Raw(b"\0D"),
+        Raw(b"\0E"),
+        Raw(b"R"),
+    ])
+}
+
+#[test]
+fn get_by_id() {
+    let data = names_table();
+    let table = Table::parse(&data).unwrap();
+
+    let name = table.names.get_by_id(name_id::FAMILY, PlatformId::Windows, 0x0407).unwrap();
+    assert_eq!(name.name, b"\0D");
+    let name = table.names.get_by_id(name_id::FAMILY, PlatformId::Macintosh, 0).unwrap();
+    assert_eq!(name.name, b"Mac");
+    assert!(table.names.get_by_id(name_id::FAMILY, PlatformId::Windows, 0x0410).is_none());
+    assert!(table.names.get_by_id(name_id::SUBFAMILY, PlatformId::Windows, 0x0409).is_none());
+}
+
+#[test]
+fn find() {
+    let data = names_table();
+    let table = Table::parse(&data).unwrap();
+
+    // Windows English is preferred.
+    assert_eq!(table.names.find(name_id::FAMILY).unwrap().name, b"\0E");
+    // A non-Unicode name is used as a fallback.
+    assert_eq!(table.names.find(name_id::SUBFAMILY).unwrap().name, b"R");
+    assert!(table.names.find(name_id::VERSION).is_none());
+}
+
+#[test]
+fn find_unicode_fallback() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt16(2), // count
+        UInt16(30), // storage offset
+        // Record [0]
+        UInt16(1), // platform ID, Macintosh
+        UInt16(0), // encoding ID, Roman
+        UInt16(0), // language ID, English
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(3), // length
+        UInt16(0), // offset
+        // Record [1]
+        UInt16(3), // platform ID, Windows
+        UInt16(1), // encoding ID, Unicode BMP
+        UInt16(0x0407), // language ID, German
+        UInt16(name_id::FAMILY), // name ID
+        UInt16(2), // length
+        UInt16(3), // offset
+        // Storage
+        Raw(b"Mac"),
+        Raw(b"\0D"),
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.names.find(name_id::FAMILY).unwrap().name, b"\0D");
+}
@@ -0,0 +1,148 @@
+use std::str::FromStr;
+use ttf_parser::{ParseClassError, Weight, Width};
+
+#[test]
+fn weight_display() {
+    assert_eq!(Weight::Bold.to_string(), "Bold");
+    assert_eq!(Weight::Normal.to_string(), "Regular");
+    assert_eq!(Weight::Other(450).to_string(), "450");
+}
+
+#[test]
+fn weight_from_str() {
+    assert_eq!(Weight::from_str("Bold"), Ok(Weight::Bold));
+    assert_eq!(Weight::from_str("semi-bold"), Ok(Weight::SemiBold));
+    assert_eq!(Weight::from_str("Extra Light"), Ok(Weight::ExtraLight));
+    assert_eq!(Weight::from_str("normal"), Ok(Weight::Normal));
+    assert_eq!(Weight::from_str("700"), Ok(Weight::Bold));
+    assert_eq!(Weight::from_str("450"), Ok(Weight::Other(450)));
+    assert_eq!(Weight::from_str("bolder"), Err(ParseClassError));
+    assert_eq!(Weight::from_str(""), Err(ParseClassError));
+}
+
+#[test]
+fn weight_round_trip() {
+    for n in (100..=900).step_by(100) {
+        let weight = Weight::from(n);
+        assert_eq!(Weight::from_str(&weight.to_string()), Ok(weight));
+    }
+}
+
+#[test]
+fn weight_number_round_trip() {
+    for n in [437, 850] {
+        let weight = Weight::from(n);
+        assert_eq!(weight, Weight::Other(n));
+        assert_eq!(weight.to_number(), n);
+    }
+
+    assert_eq!(Weight::from(700), Weight::Bold);
+    assert_eq!(Weight::Bold.to_number(), 700);
+}
+
+#[test]
+fn width_display() {
+    assert_eq!(Width::Condensed.to_string(), "Condensed");
+    assert_eq!(Width::UltraExpanded.to_string(), "UltraExpanded");
+}
+
+#[test]
+fn width_from_str() {
+    assert_eq!(Width::from_str("Condensed"), Ok(Width::Condensed));
+    assert_eq!(Width::from_str("semi-expanded"), Ok(Width::SemiExpanded));
+    assert_eq!(Width::from_str("1"), Ok(Width::UltraCondensed));
+    assert_eq!(Width::from_str("9"), Ok(Width::UltraExpanded));
+    assert_eq!(Width::from_str("0"), Err(ParseClassError));
+    assert_eq!(Width::from_str("10"), Err(ParseClassError));
+    assert_eq!(Width::from_str("wide"), Err(ParseClassError));
+}
+
+#[test]
+fn width_round_trip() {
+    for n in 1..=9 {
+        let width = Width::from_str(&n.to_string()).unwrap();
+        assert_eq!(width.to_number(), n);
+        assert_eq!(Width::from_str(&width.to_string()), Ok(width));
+    }
+}
+
+#[test]
+fn script_transforms() {
+    use ttf_parser::{Face, Transform};
+    use crate::face_with_tables;
+
+    let demo_data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+
+    // A version 0 OS/2 table.
+    let mut os2 = vec![0; 78];
+    let mut set = |offset: usize, value: i16| {
+        os2[offset..offset + 2].copy_from_slice(&value.to_be_bytes());
+    };
+    set(10, 650); // subscript x size
+    set(12, 600); // subscript y size
+    set(14, 10); // subscript x offset
+    set(16, 75); // subscript y offset
+    set(18, 650); // superscript x size
+    set(20, 600); // superscript y size
+    set(22, -10); // superscript x offset
+    set(24, 350); // superscript y offset
+
+    let face = face_with_tables(|t| t.os2 = Some(&os2));
+
+    // units per em is 1000
+    assert_eq!(
+        face.subscript_transform(),
+        Some(Transform::new(0.65, 0.0, 0.0, 0.6, 10.0, -75.0))
+    );
+    assert_eq!(
+        face.superscript_transform(),
+        Some(Transform::new(0.65, 0.0, 0.0, 0.6, -10.0, 350.0))
+    );
+
+    let face = Face::parse(&demo_data, 0).unwrap();
+    assert_eq!(face.subscript_transform(), None);
+}
+
+#[test]
+fn use_typographic_metrics() {
+    use crate::face_with_tables;
+
+    // A version 4 OS/2 table.
+    let os2_table = |fs_selection: u16| {
+        let mut os2 = vec![0; 96];
+        os2[0..2].copy_from_slice(&4u16.to_be_bytes()); // version
+        os2[62..64].copy_from_slice(&fs_selection.to_be_bytes());
+        os2[68..70].copy_from_slice(&900i16.to_be_bytes()); // typographic ascender
+        os2
+    };
+
+    let os2 = os2_table(1 << 7);
+    let face = face_with_tables(|t| t.os2 = Some(&os2));
+    assert!(face.use_typographic_metrics());
+    assert_eq!(face.ascender(), 900);
+
+    let os2 = os2_table(0);
+    let face = face_with_tables(|t| t.os2 = Some(&os2));
+    assert!(!face.use_typographic_metrics());
+    assert_ne!(face.ascender(), 900);
+}
+
+#[test]
+fn line_metrics_or_default() {
+    use ttf_parser::{Face, LineMetrics};
+
+    // No `post` and OS/2 tables.
+    let data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let face = Face::parse(&data, 0).unwrap();
+    assert_eq!(face.underline_metrics(), None);
+    assert_eq!(face.strikeout_metrics(), None);
+    // units per em is 1000
+    assert_eq!(
+        face.underline_metrics_or_default(),
+        LineMetrics { position: face.descender() / 2, thickness: 71 }
+    );
+    assert_eq!(
+        face.strikeout_metrics_or_default(),
+        LineMetrics { position: face.ascender() / 3, thickness: 71 }
+    );
+}
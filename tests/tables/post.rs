@@ -0,0 +1,63 @@
+use ttf_parser::GlyphId;
+use crate::{convert, face_with_tables, Unit::*};
+
+#[test]
+fn glyph_name_to_unicode() {
+    let post = convert(&[
+        Fixed(2.0), // version
+        Fixed(0.0), // italic angle
+        Int16(0), // underline position
+        Int16(0), // underline thickness
+        UInt32(0), // is fixed pitch
+        UInt32(0), // min memory type 42
+        UInt32(0), // max memory type 42
+        UInt32(0), // min memory type 1
+        UInt32(0), // max memory type 1
+        UInt16(10), // number of glyphs
+        UInt16(0), // .notdef
+        UInt16(36), // A
+        UInt16(201), // Aacute
+        UInt16(258), // custom name [0]
+        UInt16(259), // custom name [1]
+        UInt16(260), // custom name [2]
+        UInt16(261), // custom name [3]
+        UInt16(262), // custom name [4]
+        UInt16(263), // custom name [5]
+        UInt16(264), // custom name [6]
+        UInt8(7), Raw(b"uni0416"),
+        UInt8(6), Raw(b"u1F600"),
+        UInt8(4), Raw(b"a.sc"),
+        UInt8(3), Raw(b"f_i"),
+        UInt8(11), Raw(b"uni00660069"),
+        UInt8(7), Raw(b"uni00e9"),
+        UInt8(7), Raw(b"uniD800"),
+    ]);
+
+    let face = face_with_tables(|t| t.post = Some(&post));
+
+    assert_eq!(face.glyph_name_to_unicode(GlyphId(0)), None);
+    assert_eq!(face.glyph_name_to_unicode(GlyphId(1)), Some('A'));
+    assert_eq!(face.glyph_name_to_unicode(GlyphId(2)), Some('\u{00C1}'));
+    assert_eq!(face.glyph_name_to_unicode(GlyphId(3)), Some('\u{0416}'));
+    assert_eq!(face.glyph_name_to_unicode(GlyphId(4)), Some('\u{1F600}'));
+    assert_eq!(face.glyph_name_to_unicode(GlyphId(5)), Some('a'));
+    // Ligatures.
+    assert_eq!(face.glyph_name_to_unicode(GlyphId(6)), None);
+    assert_eq!(face.glyph_name_to_unicode(GlyphId(7)), None);
+    // Lowercase hex digits.
+    assert_eq!(face.glyph_name_to_unicode(GlyphId(8)), None);
+    // A surrogate.
+    assert_eq!(face.glyph_name_to_unicode(GlyphId(9)), None);
+    assert_eq!(face.glyph_name_to_unicode(GlyphId(10)), None);
+}
+
+#[cfg(feature = "glyph-names")]
+#[test]
+fn standard_mac_glyph_names() {
+    use ttf_parser::post::STANDARD_MAC_GLYPH_NAMES;
+
+    assert_eq!(STANDARD_MAC_GLYPH_NAMES.len(), 258);
+    assert_eq!(STANDARD_MAC_GLYPH_NAMES[0], ".notdef");
+    assert_eq!(STANDARD_MAC_GLYPH_NAMES[36], "A");
+    assert_eq!(STANDARD_MAC_GLYPH_NAMES[257], "dcroat");
+}
@@ -46,6 +46,8 @@ fn single_glyph() {
     assert_eq!(glyph_data.pixels_per_em, 20);
     assert_eq!(glyph_data.format, RasterImageFormat::PNG);
     assert_eq!(glyph_data.data.len(), 24);
+    assert_eq!(glyph_data.data_offset, 32);
+    assert_eq!(&data[glyph_data.data_range()], glyph_data.data);
 }
 
 #[test]
@@ -0,0 +1,101 @@
+use ttf_parser::stat::Table;
+use ttf_parser::Tag;
+use crate::{convert, face_with_tables, Unit::*};
+
+fn stat_data() -> Vec<u8> {
+    convert(&[
+        UInt32(0x00010002), // version
+        UInt16(8), // axis size
+        UInt16(2), // axis count
+        UInt32(20), // axis offset
+        UInt16(4), // value count
+        UInt32(36), // value offsets offset
+        UInt16(2), // elided fallback name ID
+        // Axis records
+        Raw(b"wght"), // tag [0]
+        UInt16(256), // name ID [0]
+        UInt16(0), // ordering [0]
+        Raw(b"ital"), // tag [1]
+        UInt16(257), // name ID [1]
+        UInt16(1), // ordering [1]
+        // Value offsets
+        UInt16(8), // offset [0]
+        UInt16(20), // offset [1]
+        UInt16(40), // offset [2]
+        UInt16(56), // offset [3]
+        // Format 1
+        UInt16(1), // format
+        UInt16(0), // axis index
+        UInt16(2), // flags
+        UInt16(258), // value name ID
+        Fixed(400.0), // value
+        // Format 2
+        UInt16(2), // format
+        UInt16(0), // axis index
+        UInt16(0), // flags
+        UInt16(259), // value name ID
+        Fixed(300.0), // nominal value
+        Fixed(250.0), // range min value
+        Fixed(350.0), // range max value
+        // Format 3
+        UInt16(3), // format
+        UInt16(1), // axis index
+        UInt16(1), // flags
+        UInt16(260), // value name ID
+        Fixed(0.0), // value
+        Fixed(1.0), // linked value
+        // Format 4
+        UInt16(4), // format
+        UInt16(2), // axis count
+        UInt16(0), // flags
+        UInt16(261), // value name ID
+        UInt16(0), // axis index [0]
+        Fixed(700.0), // value [0]
+        UInt16(5), // axis index [1], invalid
+        Fixed(1.0), // value [1]
+    ])
+}
+
+#[test]
+fn style_attributes() {
+    let data = stat_data();
+    let table = Table::parse(&data).unwrap();
+    let attrs: Vec<_> = table.style_attributes().collect();
+    assert_eq!(attrs.len(), 4);
+
+    let wght = Tag::from_bytes(b"wght");
+    assert_eq!(attrs[0].axis, wght);
+    assert_eq!(attrs[0].value.0, 400.0);
+    assert!(attrs[0].linked_value.is_none());
+    assert!(attrs[0].flags.elidable());
+    assert!(!attrs[0].flags.older_sibling_attribute());
+    assert_eq!(attrs[0].name_id, 258);
+
+    assert_eq!(attrs[1].axis, wght);
+    assert_eq!(attrs[1].value.0, 300.0);
+    assert_eq!(attrs[1].name_id, 259);
+
+    assert_eq!(attrs[2].axis, Tag::from_bytes(b"ital"));
+    assert_eq!(attrs[2].value.0, 0.0);
+    assert_eq!(attrs[2].linked_value.map(|v| v.0), Some(1.0));
+    assert!(attrs[2].flags.older_sibling_attribute());
+    assert_eq!(attrs[2].name_id, 260);
+
+    assert_eq!(attrs[3].axis, wght);
+    assert_eq!(attrs[3].value.0, 700.0);
+    assert!(!attrs[3].flags.elidable());
+    assert_eq!(attrs[3].name_id, 261);
+}
+
+#[test]
+fn face_style_attributes() {
+    use ttf_parser::Face;
+
+    let demo_data = std::fs::read("tests/fonts/demo.ttf").unwrap();
+    let data = stat_data();
+    let face = face_with_tables(|t| t.stat = Some(&data));
+    assert_eq!(face.style_attributes().count(), 4);
+
+    let face = Face::parse(&demo_data, 0).unwrap();
+    assert_eq!(face.style_attributes().count(), 0);
+}
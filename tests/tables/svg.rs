@@ -0,0 +1,39 @@
+use ttf_parser::svg::Table;
+use ttf_parser::GlyphId;
+use crate::{convert, Unit::*};
+
+#[test]
+fn documents() {
+    let data = convert(&[
+        UInt16(0), // version
+        UInt32(10), // document list offset
+        UInt32(0), // reserved
+        // Document list
+        UInt16(2), // count
+        // Record [0]
+        UInt16(1), // start glyph ID
+        UInt16(3), // end glyph ID
+        UInt32(26), // document offset
+        UInt32(5), // document length
+        // Record [1]
+        UInt16(4), // start glyph ID
+        UInt16(4), // end glyph ID
+        UInt32(31), // document offset
+        UInt32(3), // document length
+        // Documents
+        Raw(b"<svg>"),
+        Raw(&[0x1F, 0x8B, 0x08]),
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    let document = table.documents.find(GlyphId(2)).unwrap();
+    assert_eq!(document.data, b"<svg>");
+    assert_eq!(document.glyphs_range(), GlyphId(1)..=GlyphId(3));
+    assert!(!document.is_gzip());
+
+    let document = table.documents.find(GlyphId(4)).unwrap();
+    assert_eq!(document.glyphs_range(), GlyphId(4)..=GlyphId(4));
+    assert!(document.is_gzip());
+
+    assert!(table.documents.find(GlyphId(0)).is_none());
+}
@@ -85,4 +85,16 @@ fn basic() {
 
     assert_eq!(table.vertical.tracks.len(), 0);
     assert_eq!(table.vertical.sizes.len(), 0);
+
+    // Clamped.
+    assert_eq!(table.horizontal.tracking(6.0, -1.0), Some(-15.0));
+    assert_eq!(table.horizontal.tracking(12.0, -1.0), Some(-15.0));
+    assert_eq!(table.horizontal.tracking(48.0, -1.0), Some(-7.0));
+    // Interpolated.
+    assert_eq!(table.horizontal.tracking(18.0, -1.0), Some(-11.0));
+    assert_eq!(table.horizontal.tracking(15.0, 1.0), Some(42.5));
+    assert_eq!(table.horizontal.tracking(15.0, 0.0), Some(0.0));
+    // Unknown track.
+    assert_eq!(table.horizontal.tracking(15.0, 0.5), None);
+    assert_eq!(table.vertical.tracking(15.0, 0.0), None);
 }
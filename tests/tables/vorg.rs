@@ -0,0 +1,26 @@
+use ttf_parser::GlyphId;
+use ttf_parser::vorg::Table;
+use crate::{convert, Unit::*};
+
+#[test]
+fn entries() {
+    let data = convert(&[
+        UInt16(1), // major version
+        UInt16(0), // minor version
+        Int16(880), // default vertical origin
+        UInt16(2), // number of metrics
+        UInt16(3), // glyph ID [0]
+        Int16(900), // vertical origin [0]
+        UInt16(7), // glyph ID [1]
+        Int16(-10), // vertical origin [1]
+    ]);
+
+    let table = Table::parse(&data).unwrap();
+    assert_eq!(table.default_y(), 880);
+    assert_eq!(
+        table.entries().collect::<Vec<_>>(),
+        [(GlyphId(3), 900), (GlyphId(7), -10)]
+    );
+    assert_eq!(table.glyph_y_origin(GlyphId(3)), 900);
+    assert_eq!(table.glyph_y_origin(GlyphId(4)), 880);
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "woff")]
+
+use ttf_parser::{Face, FaceParsingError, GlyphId};
+
+// NOTE: demo.woff is demo.ttf converted to WOFF. Some tables are compressed and some are not.
+static WOFF_DATA: &[u8] = include_bytes!("fonts/demo.woff");
+static TTF_DATA: &[u8] = include_bytes!("fonts/demo.ttf");
+
+#[test]
+fn decode() {
+    let mut buffer = Vec::new();
+    let face = Face::from_woff(WOFF_DATA, &mut buffer).unwrap();
+    let expected = Face::parse(TTF_DATA, 0).unwrap();
+
+    assert_eq!(face.number_of_glyphs(), expected.number_of_glyphs());
+    assert_eq!(face.units_per_em(), expected.units_per_em());
+    assert_eq!(face.ascender(), expected.ascender());
+    assert_eq!(face.glyph_index('A'), expected.glyph_index('A'));
+    assert_eq!(
+        face.glyph_bounding_box(GlyphId(1)),
+        expected.glyph_bounding_box(GlyphId(1))
+    );
+}
+
+#[test]
+fn not_woff() {
+    assert_eq!(
+        ttf_parser::woff::decode(TTF_DATA).unwrap_err(),
+        FaceParsingError::UnknownMagic
+    );
+}
+
+#[test]
+fn truncated() {
+    for len in [4, 44, 100, WOFF_DATA.len() - 2] {
+        assert_eq!(
+            ttf_parser::woff::decode(&WOFF_DATA[..len]).unwrap_err(),
+            FaceParsingError::MalformedFont
+        );
+    }
+}
+
+#[test]
+fn decompression_bomb() {
+    let read_u32 = |data: &[u8], offset: usize| {
+        u32::from_be_bytes([
+            data[offset],
+            data[offset + 1],
+            data[offset + 2],
+            data[offset + 3],
+        ])
+    };
+
+    // A compressed table claiming to be larger than the whole font.
+    let mut data = WOFF_DATA.to_vec();
+    let num_tables = usize::from(u16::from_be_bytes([data[12], data[13]]));
+    let record = (0..num_tables)
+        .map(|i| 44 + i * 20)
+        .find(|&offset| read_u32(&data, offset + 8) < read_u32(&data, offset + 12))
+        .unwrap();
+    data[record + 12..record + 16].copy_from_slice(&u32::MAX.to_be_bytes());
+    assert_eq!(
+        ttf_parser::woff::decode(&data).unwrap_err(),
+        FaceParsingError::MalformedFont
+    );
+
+    // A font size that cannot be produced from the input.
+    let mut data = WOFF_DATA.to_vec();
+    data[16..20].copy_from_slice(&u32::MAX.to_be_bytes());
+    assert_eq!(
+        ttf_parser::woff::decode(&data).unwrap_err(),
+        FaceParsingError::MalformedFont
+    );
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "woff2")]
+
+use ttf_parser::{Face, FaceParsingError, GlyphId};
+
+// NOTE: demo.woff2 is demo.ttf converted to WOFF2 with transformed `glyf`, `loca` and `hmtx`.
+static WOFF2_DATA: &[u8] = include_bytes!("fonts/demo.woff2");
+static TTF_DATA: &[u8] = include_bytes!("fonts/demo.ttf");
+
+#[test]
+fn decode() {
+    let mut buffer = Vec::new();
+    let face = Face::from_woff2(WOFF2_DATA, 0, &mut buffer).unwrap();
+    let expected = Face::parse(TTF_DATA, 0).unwrap();
+
+    assert_eq!(face.number_of_glyphs(), expected.number_of_glyphs());
+    assert_eq!(face.units_per_em(), expected.units_per_em());
+    assert_eq!(face.glyph_index('A'), expected.glyph_index('A'));
+    for id in 0..face.number_of_glyphs() {
+        let id = GlyphId(id);
+        assert_eq!(face.glyph_bounding_box(id), expected.glyph_bounding_box(id));
+        assert_eq!(face.glyph_hor_advance(id), expected.glyph_hor_advance(id));
+        assert_eq!(
+            face.glyph_hor_side_bearing(id),
+            expected.glyph_hor_side_bearing(id)
+        );
+        assert_eq!(face.glyph_outline(id), expected.glyph_outline(id));
+    }
+}
+
+#[test]
+fn not_woff2() {
+    assert_eq!(
+        ttf_parser::woff2::decode(TTF_DATA, 0).unwrap_err(),
+        FaceParsingError::UnknownMagic
+    );
+}
+
+#[test]
+fn face_index_out_of_bounds() {
+    assert_eq!(
+        ttf_parser::woff2::decode(WOFF2_DATA, 1).unwrap_err(),
+        FaceParsingError::FaceIndexOutOfBounds
+    );
+}
+
+#[test]
+fn truncated() {
+    for len in [4, 48, 60, WOFF2_DATA.len() - 8] {
+        assert_eq!(
+            ttf_parser::woff2::decode(&WOFF2_DATA[..len], 0).unwrap_err(),
+            FaceParsingError::MalformedFont
+        );
+    }
+}
+
+#[test]
+fn decompression_bomb() {
+    // A font size smaller than the decompressed tables.
+    let mut data = WOFF2_DATA.to_vec();
+    data[16..20].copy_from_slice(&1u32.to_be_bytes());
+    assert_eq!(
+        ttf_parser::woff2::decode(&data, 0).unwrap_err(),
+        FaceParsingError::MalformedFont
+    );
+
+    // A font size that cannot be produced from the input.
+    let mut data = WOFF2_DATA.to_vec();
+    data[16..20].copy_from_slice(&u32::MAX.to_be_bytes());
+    assert_eq!(
+        ttf_parser::woff2::decode(&data, 0).unwrap_err(),
+        FaceParsingError::MalformedFont
+    );
+}